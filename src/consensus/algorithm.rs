@@ -1,14 +1,23 @@
-use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::blockchain::LocalLedger;
 use crate::consensus::config::ConsensusConfig;
 use crate::ledger_data::block::Block;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
 use crate::simulator::Simulator;
 use std::collections::HashSet;
 
+pub mod gasper_consensus;
+pub mod ghostdag_consensus;
+pub mod hotstuff_consensus;
 pub mod nakamoto_consensus;
+pub mod pbft_consensus;
+pub mod praos_consensus;
+pub mod tendermint_consensus;
 
 pub trait DAGBasedConsensus {
     type B: Block;
     type G: ConsensusConfig;
+    /// The local view of the ledger each node keeps of its received blocks.
+    type L: LocalLedger;
 
     fn new(config: &Self::G) -> Self;
     fn initial_configuration(&mut self, config: &Self::G, node_index: usize);
@@ -17,7 +26,7 @@ pub trait DAGBasedConsensus {
         block_index: usize,
         blocks: &[Self::B],
         config: &Self::G,
-        local_block_trees: &LocalBlockTree,
+        local_block_trees: &Self::L,
         simulator: &mut Simulator,
     );
 
@@ -31,7 +40,7 @@ pub trait ChainBasedConsensus: DAGBasedConsensus {
         &mut self,
         blocks: &[Self::B],
         config: &Self::G,
-        local_block_trees: &LocalBlockTree,
+        local_block_trees: &Self::L,
         simulator: &mut Simulator,
     );
 
@@ -41,3 +50,57 @@ pub trait ChainBasedConsensus: DAGBasedConsensus {
     fn set_longest_chain_len(&mut self, len: i32);
     fn set_canonical_chain_head_index(&mut self, index: usize);
 }
+
+/// An output of a [`BFTConsensus`] which the network carries out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BFTAction {
+    /// Create a new block on `parent` and broadcast it with the proposal vote.
+    Propose { vote: BFTVote, parent: usize },
+    /// Send the vote to all replicas.
+    Broadcast(BFTVote),
+    /// Send the vote to a single replica.
+    SendTo { vote: BFTVote, to: usize },
+    /// Call [`BFTConsensus::timeout`] of this replica after `delay` seconds.
+    /// `step` is the phase whose votes the replica waits for.
+    ScheduleTimeout {
+        view: u64,
+        height: u64,
+        step: BFTVoteType,
+        delay: f64,
+    },
+}
+
+/// A vote-based consensus run by a fixed set of replicas. The consensus only
+/// updates its state and returns the messages and timers the network has to
+/// carry out for it.
+pub trait BFTConsensus: DAGBasedConsensus {
+    fn protocol_name() -> &'static str;
+
+    /// Called once when the simulation starts.
+    fn start(&mut self, config: &Self::G) -> Vec<BFTAction>;
+
+    /// When a new vote (or proposal) is received, this function should be
+    /// called. The block of a proposal is added to the local block tree before.
+    fn new_incoming_vote(
+        &mut self,
+        vote: &BFTVote,
+        blocks: &[Self::B],
+        config: &Self::G,
+        local_block_trees: &Self::L,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction>;
+
+    /// Called when a timeout scheduled with [`BFTAction::ScheduleTimeout`]
+    /// expires.
+    #[allow(clippy::too_many_arguments)]
+    fn timeout(
+        &mut self,
+        view: u64,
+        height: u64,
+        step: BFTVoteType,
+        blocks: &[Self::B],
+        config: &Self::G,
+        local_block_trees: &Self::L,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction>;
+}
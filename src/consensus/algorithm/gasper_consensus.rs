@@ -0,0 +1,309 @@
+//! Gasper: LMD-GHOST fork choice with Casper FFG justification and
+//! finalization, as used by Ethereum Proof-of-Stake.
+
+use crate::consensus::algorithm::{ChainBasedConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::gasper_consensus_config::GasperConsensusConfig;
+use crate::ledger_data::ethereum_block::EthereumBlock;
+use crate::ledger_data::single_parent::SingleParent;
+use crate::ledger_data::vote::{Checkpoint, Vote};
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[derive(Default, Debug, Clone)]
+pub struct GasperConsensus {
+    pub longest_chain_len: i32,
+    pub current_main_chain_head_index: usize,
+    pub justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    pub confirmed_blocks: HashSet<usize>,
+    pub node_index: usize,
+    /// The latest head vote of each validator: <validator, (slot, block_index)>
+    latest_messages: HashMap<usize, (u64, usize)>,
+    justified_checkpoints: HashSet<Checkpoint>,
+    /// Stake voted for each FFG link: <(source, target), stake>
+    ffg_links: BTreeMap<(Checkpoint, Checkpoint), f64>,
+    /// Validators that already voted for a target epoch: <(validator, epoch)>
+    ffg_voters: HashSet<(usize, u64)>,
+}
+
+impl DAGBasedConsensus for GasperConsensus {
+    type B = EthereumBlock;
+    type G = GasperConsensusConfig;
+    type L = LocalBlockTree;
+
+    fn new(config: &GasperConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        let genesis_checkpoint = Checkpoint::new(0, config.genesis_block_index);
+        self.longest_chain_len = 0;
+        self.current_main_chain_head_index = config.genesis_block_index;
+        self.justified_checkpoint = genesis_checkpoint;
+        self.finalized_checkpoint = genesis_checkpoint;
+        // genesis is finalized from the start.
+        self.confirmed_blocks = HashSet::from([config.genesis_block_index]);
+        self.node_index = node_index;
+        self.latest_messages = HashMap::new();
+        self.justified_checkpoints = HashSet::from([genesis_checkpoint]);
+        self.ffg_links = BTreeMap::new();
+        self.ffg_voters = HashSet::new();
+    }
+
+    /// Updates the head of the node with the LMD-GHOST fork choice when a new
+    /// block is connected to its local block tree.
+    fn new_incoming_block(
+        &mut self,
+        _block_index: usize,
+        blocks: &[EthereumBlock],
+        config: &GasperConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        self.update_chain(blocks, config, local_block_trees, simulator);
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl ChainBasedConsensus for GasperConsensus {
+    /// Runs the LMD-GHOST fork choice. Head votes do not update the head by
+    /// themselves, so call this before using the head for a new block or vote.
+    fn update_chain(
+        &mut self,
+        blocks: &[Self::B],
+        config: &Self::G,
+        local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) {
+        let head = self.lmd_ghost_head(blocks, config, local_block_trees);
+        self.current_main_chain_head_index = head;
+        self.longest_chain_len = blocks[head].height;
+    }
+
+    fn get_longest_chain_len(&self) -> i32 {
+        self.longest_chain_len
+    }
+
+    fn get_canonical_chain_head_index(&self) -> usize {
+        self.current_main_chain_head_index
+    }
+
+    fn set_longest_chain_len(&mut self, len: i32) {
+        self.longest_chain_len = len;
+    }
+
+    fn set_canonical_chain_head_index(&mut self, index: usize) {
+        self.current_main_chain_head_index = index;
+    }
+}
+
+impl GasperConsensus {
+    /// When a new vote is received, this function should be called. It records
+    /// the head vote of the validator and counts its FFG vote, which may
+    /// justify and finalize checkpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `vote`: the received vote
+    /// * `blocks`: immutable reference to `Block`
+    /// * `config`: consensus algorithm config
+    /// * `local_block_trees`: immutable reference to `LocalBlockTree`
+    /// * `simulator`: mutable reference to `Simulator`
+    ///
+    pub fn new_incoming_vote(
+        &mut self,
+        vote: &Vote,
+        blocks: &[EthereumBlock],
+        config: &GasperConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        let latest_message = self
+            .latest_messages
+            .entry(vote.voter)
+            .or_insert((vote.slot, vote.head));
+        if vote.slot > latest_message.0 {
+            *latest_message = (vote.slot, vote.head);
+        }
+
+        if vote.target.epoch > vote.source.epoch
+            && self.ffg_voters.insert((vote.voter, vote.target.epoch))
+        {
+            *self
+                .ffg_links
+                .entry((vote.source, vote.target))
+                .or_default() += config.validator_stakes[vote.voter];
+            self.process_ffg_link(
+                vote.source,
+                vote.target,
+                blocks,
+                config,
+                local_block_trees,
+                simulator,
+            );
+        }
+    }
+
+    /// Returns the checkpoint of `epoch` on the chain of `block_index`, i.e.
+    /// its latest ancestor (or itself) not after the first slot of the epoch.
+    pub fn epoch_boundary_checkpoint(
+        block_index: usize,
+        epoch: u64,
+        blocks: &[EthereumBlock],
+        config: &GasperConsensusConfig,
+    ) -> Checkpoint {
+        let first_slot = config.first_slot_of_epoch(epoch);
+        let mut checkpoint_block = block_index;
+        while blocks[checkpoint_block].slot > first_slot {
+            match blocks[checkpoint_block].get_single_parent() {
+                Some(parent) => checkpoint_block = parent,
+                None => break,
+            }
+        }
+        Checkpoint::new(epoch, checkpoint_block)
+    }
+
+    /// Justifies the target of a link from a justified source with a
+    /// supermajority of stake, and finalizes the source if the two are in
+    /// consecutive epochs.
+    fn process_ffg_link(
+        &mut self,
+        source: Checkpoint,
+        target: Checkpoint,
+        blocks: &[EthereumBlock],
+        config: &GasperConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        if !self.justified_checkpoints.contains(&source)
+            || self.justified_checkpoints.contains(&target)
+            || !config.is_supermajority(self.ffg_links[&(source, target)])
+        {
+            return;
+        }
+
+        self.justified_checkpoints.insert(target);
+        if target.epoch > self.justified_checkpoint.epoch {
+            self.justified_checkpoint = target;
+        }
+        if target.epoch == source.epoch + 1 && source.epoch > self.finalized_checkpoint.epoch {
+            self.finalize(source, blocks, local_block_trees, simulator);
+        }
+
+        // links from the new justified checkpoint may already have enough votes.
+        let next_targets: Vec<Checkpoint> = self
+            .ffg_links
+            .keys()
+            .filter(|(link_source, _)| *link_source == target)
+            .map(|(_, link_target)| *link_target)
+            .collect();
+        for next_target in next_targets {
+            self.process_ffg_link(
+                target,
+                next_target,
+                blocks,
+                config,
+                local_block_trees,
+                simulator,
+            );
+        }
+    }
+
+    /// Finalizes the checkpoint and its ancestors, with a confirmation event
+    /// for each newly finalized block, from the lowest to the highest.
+    fn finalize(
+        &mut self,
+        checkpoint: Checkpoint,
+        blocks: &[EthereumBlock],
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        self.finalized_checkpoint = checkpoint;
+        let mut finalized_blocks =
+            local_block_trees.get_all_single_ancestors(checkpoint.block_index, blocks);
+        finalized_blocks.insert(checkpoint.block_index);
+
+        let mut newly_finalized_blocks: Vec<usize> = finalized_blocks
+            .difference(&self.confirmed_blocks)
+            .copied()
+            .collect();
+        newly_finalized_blocks.sort_by_key(|&block_index| blocks[block_index].height);
+        for block_index in newly_finalized_blocks {
+            let block_confirmation_event =
+                Box::new(BlockConfirmationEvent::new(block_index, self.node_index));
+            simulator.put_event(block_confirmation_event, 0.0);
+        }
+        self.confirmed_blocks = finalized_blocks;
+    }
+
+    /// Starting from the latest justified checkpoint, repeatedly selects the
+    /// child with the most stake of latest head votes in its subtree, until a
+    /// leaf is reached. Ties are broken in favor of the earlier block.
+    fn lmd_ghost_head(
+        &self,
+        blocks: &[EthereumBlock],
+        config: &GasperConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+    ) -> usize {
+        let is_known = |block_index: &usize| {
+            local_block_trees
+                .local_block_dag
+                .get(block_index)
+                .is_some_and(|local_block| local_block.is_connected_to_genesis)
+        };
+
+        let root = [
+            self.justified_checkpoint.block_index,
+            self.finalized_checkpoint.block_index,
+        ]
+        .into_iter()
+        .find(is_known)
+        .unwrap_or(config.genesis_block_index);
+        let root_height = blocks[root].height;
+
+        let mut weights: HashMap<usize, f64> = HashMap::new();
+        for (&validator, &(_, head_vote)) in &self.latest_messages {
+            if !is_known(&head_vote) {
+                continue;
+            }
+            let stake = config.validator_stakes[validator];
+            let mut block_index = head_vote;
+            while blocks[block_index].height > root_height {
+                *weights.entry(block_index).or_default() += stake;
+                match blocks[block_index].get_single_parent() {
+                    Some(parent) => block_index = parent,
+                    None => break,
+                }
+            }
+        }
+        let weight = |block_index: &usize| weights.get(block_index).copied().unwrap_or(0.0);
+
+        let mut head = root;
+        loop {
+            let best_child = local_block_trees.local_block_dag[&head]
+                .children_index
+                .iter()
+                .filter(|child| is_known(child))
+                .max_by(|a, b| weight(a).total_cmp(&weight(b)).then(b.cmp(a)));
+            match best_child {
+                Some(&child) => head = child,
+                None => return head,
+            }
+        }
+    }
+}
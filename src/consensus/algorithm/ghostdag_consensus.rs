@@ -0,0 +1,288 @@
+//! GHOSTDAG, the greedy variant of PHANTOM: a block DAG protocol where miners
+//! reference all the tips they know. Each block colors its past blue or red so
+//! that a blue block has at most `k` blue blocks in its anticone, and the DAG
+//! is ordered along the chain of selected parents (the parents with the
+//! highest blue score).
+//!
+//! The coloring of a block only depends on its past, so it is computed once by
+//! its miner with [`GhostDAGConsensus::ghostdag_data`] and carried in the
+//! block. Each node only follows its virtual selected parent and confirms the
+//! blocks merged deep enough below it.
+
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::consensus::blockchain::local_block_dag::LocalBlockDAG;
+use crate::consensus::config::ghostdag_consensus_config::GhostDAGConsensusConfig;
+use crate::ledger_data::ghostdag_block::{GhostDAGBlock, GhostDAGData};
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[derive(Default, Debug, Clone)]
+pub struct GhostDAGConsensus {
+    /// The connected block with the highest blue score, the head of the
+    /// selected chain of this node.
+    pub virtual_selected_parent: usize,
+    pub confirmed_blocks: HashSet<usize>,
+    /// The color of each confirmed block in the ordering of this node, `true`
+    /// for blue.
+    pub colors: HashMap<usize, bool>,
+    pub node_index: usize,
+}
+
+impl DAGBasedConsensus for GhostDAGConsensus {
+    type B = GhostDAGBlock;
+    type G = GhostDAGConsensusConfig;
+    type L = LocalBlockDAG;
+
+    fn new(config: &GhostDAGConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        let genesis = config.genesis_block_index;
+        self.virtual_selected_parent = genesis;
+        self.confirmed_blocks = HashSet::from([genesis]);
+        self.colors = HashMap::from([(genesis, true)]);
+        self.node_index = node_index;
+    }
+
+    /// Called for each block connected to the local block DAG, in topological
+    /// order. Moves the virtual selected parent and confirms the blocks merged
+    /// by the selected chain at `confirmation_depth` below it.
+    fn new_incoming_block(
+        &mut self,
+        block_index: usize,
+        blocks: &[GhostDAGBlock],
+        config: &GhostDAGConsensusConfig,
+        _local_block_dag: &LocalBlockDAG,
+        simulator: &mut Simulator,
+    ) {
+        let preferred = Self::select([block_index, self.virtual_selected_parent], blocks);
+        if preferred != self.virtual_selected_parent {
+            self.virtual_selected_parent = preferred;
+            self.confirm_blocks(blocks, config, simulator);
+        }
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl GhostDAGConsensus {
+    /// Computes the GHOSTDAG data of a new block on `parents`. The blocks of
+    /// the mergeset are colored blue in topological order as long as the blue
+    /// set stays a k-cluster and has at most `k + 1` new blues.
+    pub fn ghostdag_data(parents: &[usize], blocks: &[GhostDAGBlock], k: usize) -> GhostDAGData {
+        let selected_parent = Self::select(parents.iter().copied(), blocks);
+        let mut data = GhostDAGData {
+            selected_parent: Some(selected_parent),
+            mergeset_blues: vec![selected_parent],
+            blues_anticone_sizes: BTreeMap::from([(selected_parent, 0)]),
+            ..GhostDAGData::default()
+        };
+
+        for candidate in Self::sorted_mergeset(selected_parent, parents, blocks) {
+            if data.mergeset_blues.len() <= k {
+                if let Some(anticone_blues) =
+                    Self::anticone_blues_if_blue(candidate, &data, blocks, k)
+                {
+                    data.mergeset_blues.push(candidate);
+                    data.blues_anticone_sizes
+                        .insert(candidate, anticone_blues.len());
+                    for (blue, anticone_size) in anticone_blues {
+                        data.blues_anticone_sizes.insert(blue, anticone_size + 1);
+                    }
+                    continue;
+                }
+            }
+            data.mergeset_reds.push(candidate);
+        }
+
+        data.blue_score = blocks[selected_parent].blue_score() + data.mergeset_blues.len() as u64;
+        data
+    }
+
+    /// Returns the block with the highest blue score, the smallest index
+    /// breaking ties.
+    pub fn select(candidates: impl IntoIterator<Item = usize>, blocks: &[GhostDAGBlock]) -> usize {
+        candidates
+            .into_iter()
+            .max_by(|&a, &b| {
+                blocks[a]
+                    .blue_score()
+                    .cmp(&blocks[b].blue_score())
+                    .then(b.cmp(&a))
+            })
+            .expect("a block has at least one parent")
+    }
+
+    /// Returns the past of the new block which is not in the past of its
+    /// selected parent, sorted by blue score, which is a topological order.
+    fn sorted_mergeset(
+        selected_parent: usize,
+        parents: &[usize],
+        blocks: &[GhostDAGBlock],
+    ) -> Vec<usize> {
+        let mut mergeset = HashSet::new();
+        let mut to_visit: Vec<usize> = parents
+            .iter()
+            .copied()
+            .filter(|&parent| parent != selected_parent)
+            .collect();
+        while let Some(block) = to_visit.pop() {
+            if block == selected_parent
+                || mergeset.contains(&block)
+                || Self::is_in_past(block, selected_parent, blocks)
+            {
+                continue;
+            }
+            mergeset.insert(block);
+            to_visit.extend(blocks[block].parents.iter());
+        }
+
+        let mut sorted_mergeset: Vec<usize> = mergeset.into_iter().collect();
+        sorted_mergeset.sort_by_key(|&block| (blocks[block].blue_score(), block));
+        sorted_mergeset
+    }
+
+    /// Returns the blues in the anticone of the candidate, with their own
+    /// blue anticone sizes, if the candidate can be colored blue. Walks down
+    /// the selected chain until a chain block in the past of the candidate, as
+    /// the blues below it are in its past too.
+    fn anticone_blues_if_blue(
+        candidate: usize,
+        data: &GhostDAGData,
+        blocks: &[GhostDAGBlock],
+        k: usize,
+    ) -> Option<Vec<(usize, usize)>> {
+        let mut anticone_blues = Vec::new();
+        let mut chain_blues = &data.mergeset_blues;
+        let mut chain_block = data.selected_parent;
+        loop {
+            for &blue in chain_blues {
+                if Self::is_in_past(blue, candidate, blocks) {
+                    continue;
+                }
+                let blue_anticone_size = Self::blue_anticone_size(blue, data, blocks);
+                if anticone_blues.len() == k || blue_anticone_size == k {
+                    return None;
+                }
+                anticone_blues.push((blue, blue_anticone_size));
+            }
+
+            match chain_block {
+                Some(block) if !Self::is_in_past(block, candidate, blocks) => {
+                    chain_blues = &blocks[block].ghostdag_data.mergeset_blues;
+                    chain_block = blocks[block].ghostdag_data.selected_parent;
+                }
+                _ => return Some(anticone_blues),
+            }
+        }
+    }
+
+    /// Returns the size of the anticone of a blue block within the blue set
+    /// of the new block, stored by the closest chain block which set it.
+    fn blue_anticone_size(blue: usize, data: &GhostDAGData, blocks: &[GhostDAGBlock]) -> usize {
+        if let Some(&anticone_size) = data.blues_anticone_sizes.get(&blue) {
+            return anticone_size;
+        }
+        let mut chain_block = data.selected_parent;
+        while let Some(block) = chain_block {
+            let block_data = &blocks[block].ghostdag_data;
+            if let Some(&anticone_size) = block_data.blues_anticone_sizes.get(&blue) {
+                return anticone_size;
+            }
+            chain_block = block_data.selected_parent;
+        }
+        unreachable!("a blue block has its anticone size set by the chain block merging it")
+    }
+
+    /// Checks if `ancestor` is in the past of `block`. The height strictly
+    /// decreases towards genesis, which bounds the search.
+    pub fn is_in_past(ancestor: usize, block: usize, blocks: &[GhostDAGBlock]) -> bool {
+        let ancestor_height = blocks[ancestor].height;
+        if ancestor_height >= blocks[block].height {
+            return false;
+        }
+
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![block];
+        while let Some(descendant) = to_visit.pop() {
+            for &parent in &blocks[descendant].parents {
+                if parent == ancestor {
+                    return true;
+                }
+                if blocks[parent].height > ancestor_height && visited.insert(parent) {
+                    to_visit.push(parent);
+                }
+            }
+        }
+        false
+    }
+
+    /// Confirms, in the GHOSTDAG order, the unconfirmed chain blocks with at
+    /// least `confirmation_depth` less blue score than the virtual selected
+    /// parent, each after the blocks it merges.
+    fn confirm_blocks(
+        &mut self,
+        blocks: &[GhostDAGBlock],
+        config: &GhostDAGConsensusConfig,
+        simulator: &mut Simulator,
+    ) {
+        let Some(confirmed_blue_score) = blocks[self.virtual_selected_parent]
+            .blue_score()
+            .checked_sub(config.confirmation_depth)
+        else {
+            return;
+        };
+
+        let mut chain_block = self.virtual_selected_parent;
+        while blocks[chain_block].blue_score() > confirmed_blue_score {
+            chain_block = Self::selected_parent(chain_block, blocks);
+        }
+        let mut new_chain_blocks = Vec::new();
+        while !self.confirmed_blocks.contains(&chain_block) {
+            new_chain_blocks.push(chain_block);
+            chain_block = Self::selected_parent(chain_block, blocks);
+        }
+
+        for &chain_block in new_chain_blocks.iter().rev() {
+            let data = &blocks[chain_block].ghostdag_data;
+            let mut merged: Vec<(usize, bool)> = data.mergeset_blues[1..]
+                .iter()
+                .map(|&blue| (blue, true))
+                .chain(data.mergeset_reds.iter().map(|&red| (red, false)))
+                .collect();
+            merged.sort_by_key(|&(block, _)| (blocks[block].blue_score(), block));
+            merged.push((chain_block, true));
+
+            for (block, is_blue) in merged {
+                if self.confirmed_blocks.insert(block) {
+                    self.colors.insert(block, is_blue);
+                    simulator.put_event(
+                        Box::new(BlockConfirmationEvent::new(block, self.node_index)),
+                        0.0,
+                    );
+                }
+            }
+        }
+    }
+
+    fn selected_parent(block: usize, blocks: &[GhostDAGBlock]) -> usize {
+        blocks[block]
+            .ghostdag_data
+            .selected_parent
+            .expect("only genesis has no selected parent, and it is confirmed")
+    }
+}
@@ -0,0 +1,332 @@
+//! Chained HotStuff: each view's leader extends the block of the highest
+//! quorum certificate (QC), and a block is committed when it heads a chain of
+//! three blocks of consecutive views. Votes are sent to the next leader, so a
+//! view needs a single round of messages.
+//!
+//! The QC of a proposed block is implied by its parent, as a leader always
+//! extends the block of its highest QC.
+
+use crate::consensus::algorithm::{BFTAction, BFTConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::hotstuff_consensus_config::HotStuffConsensusConfig;
+use crate::consensus::config::BFTConsensusConfig;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::single_parent::SingleParent;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default, Debug, Clone)]
+pub struct HotStuffConsensus {
+    pub view: u64,
+    /// The latest committed block.
+    pub current_main_chain_head_index: usize,
+    pub confirmed_blocks: HashSet<usize>,
+    pub node_index: usize,
+    /// The block of the highest QC known to this replica.
+    high_qc: usize,
+    locked_block: usize,
+    last_voted_view: u64,
+    committed_height: i32,
+    /// Voters for each (view, block).
+    votes: HashMap<(u64, usize), HashSet<usize>>,
+    /// Senders of new-view messages for each view.
+    new_views: HashMap<u64, HashSet<usize>>,
+    proposed_views: HashSet<u64>,
+    consecutive_timeouts: i32,
+}
+
+impl DAGBasedConsensus for HotStuffConsensus {
+    type B = BFTBlock;
+    type G = HotStuffConsensusConfig;
+    type L = LocalBlockTree;
+
+    fn new(config: &HotStuffConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        *self = Self {
+            view: 1,
+            current_main_chain_head_index: config.genesis_block_index,
+            node_index,
+            high_qc: config.genesis_block_index,
+            locked_block: config.genesis_block_index,
+            ..Self::default()
+        };
+    }
+
+    /// Blocks are processed with their proposal in `new_incoming_vote`.
+    fn new_incoming_block(
+        &mut self,
+        _block_index: usize,
+        _blocks: &[BFTBlock],
+        _config: &HotStuffConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) {
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl BFTConsensus for HotStuffConsensus {
+    fn protocol_name() -> &'static str {
+        "Chained HotStuff"
+    }
+
+    fn start(&mut self, config: &Self::G) -> Vec<BFTAction> {
+        let mut actions = vec![self.view_timeout(config)];
+        if config.get_leader(self.view) == self.node_index {
+            self.propose(1, config, &mut actions);
+        }
+        actions
+    }
+
+    fn new_incoming_vote(
+        &mut self,
+        vote: &BFTVote,
+        blocks: &[BFTBlock],
+        config: &HotStuffConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        match (vote.vote_type, vote.block_index) {
+            (BFTVoteType::Proposal, Some(block)) if vote.voter == config.get_leader(vote.view) => {
+                self.process_proposal(vote.view, block, blocks, config, simulator, &mut actions);
+            }
+            (BFTVoteType::Vote, Some(block)) => {
+                let voters = self.votes.entry((vote.view, block)).or_default();
+                voters.insert(vote.voter);
+                if voters.len() >= config.get_quorum() {
+                    self.update_high_qc(block, blocks);
+                    self.try_propose(vote.view + 1, blocks, config, &mut actions);
+                }
+            }
+            (BFTVoteType::NewView, Some(block)) => {
+                let senders = self.new_views.entry(vote.view).or_default();
+                senders.insert(vote.voter);
+                let num_of_senders = senders.len();
+                self.update_high_qc(block, blocks);
+                if num_of_senders >= config.get_quorum() {
+                    self.try_propose(vote.view, blocks, config, &mut actions);
+                }
+            }
+            _ => (),
+        }
+        actions
+    }
+
+    fn timeout(
+        &mut self,
+        view: u64,
+        _height: u64,
+        _step: BFTVoteType,
+        blocks: &[BFTBlock],
+        config: &HotStuffConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        if view != self.view {
+            return actions;
+        }
+
+        self.consecutive_timeouts += 1;
+        self.view = view + 1;
+        actions.push(self.view_timeout(config));
+        actions.push(BFTAction::SendTo {
+            vote: BFTVote::new(
+                self.node_index,
+                self.view,
+                blocks[self.high_qc].height as u64,
+                BFTVoteType::NewView,
+                Some(self.high_qc),
+            ),
+            to: config.get_leader(self.view),
+        });
+        actions
+    }
+}
+
+impl HotStuffConsensus {
+    fn process_proposal(
+        &mut self,
+        view: u64,
+        block: usize,
+        blocks: &[BFTBlock],
+        config: &HotStuffConsensusConfig,
+        simulator: &mut Simulator,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        self.update(block, blocks, simulator);
+        if view < self.view {
+            return;
+        }
+
+        if view > self.last_voted_view && self.is_safe(block, blocks) {
+            self.last_voted_view = view;
+            actions.push(BFTAction::SendTo {
+                vote: BFTVote::new(
+                    self.node_index,
+                    view,
+                    blocks[block].height as u64,
+                    BFTVoteType::Vote,
+                    Some(block),
+                ),
+                to: config.get_leader(view + 1),
+            });
+        }
+
+        self.view = view + 1;
+        actions.push(self.view_timeout(config));
+        // as the next leader, the votes for the block may have arrived before it.
+        let has_quorum = self
+            .votes
+            .get(&(view, block))
+            .is_some_and(|voters| voters.len() >= config.get_quorum());
+        if has_quorum {
+            self.update_high_qc(block, blocks);
+            self.try_propose(view + 1, blocks, config, actions);
+        }
+    }
+
+    /// Proposes in `view` if this replica is its leader and has not proposed
+    /// in it yet, moving to the view if it is behind.
+    fn try_propose(
+        &mut self,
+        view: u64,
+        blocks: &[BFTBlock],
+        config: &HotStuffConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        if config.get_leader(view) != self.node_index || view < self.view {
+            return;
+        }
+        if view > self.view {
+            self.view = view;
+            actions.push(self.view_timeout(config));
+        }
+        self.propose(blocks[self.high_qc].height as u64 + 1, config, actions);
+    }
+
+    fn propose(
+        &mut self,
+        height: u64,
+        config: &HotStuffConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        if !self.proposed_views.insert(self.view) {
+            return;
+        }
+        actions.push(BFTAction::Propose {
+            vote: BFTVote::new(
+                config.get_leader(self.view),
+                self.view,
+                height,
+                BFTVoteType::Proposal,
+                None,
+            ),
+            parent: self.high_qc,
+        });
+    }
+
+    /// Updates the highest QC, the locked block and the committed chain with
+    /// the QCs implied by the ancestors of a proposed block.
+    fn update(&mut self, block: usize, blocks: &[BFTBlock], simulator: &mut Simulator) {
+        let Some(b2) = blocks[block].get_single_parent() else {
+            return;
+        };
+        self.update_high_qc(b2, blocks);
+        let Some(b1) = blocks[b2].get_single_parent() else {
+            return;
+        };
+        if blocks[b1].view > blocks[self.locked_block].view {
+            self.locked_block = b1;
+        }
+        let Some(b0) = blocks[b1].get_single_parent() else {
+            return;
+        };
+        if blocks[b2].view == blocks[b1].view + 1 && blocks[b1].view == blocks[b0].view + 1 {
+            self.commit(b0, blocks, simulator);
+        }
+    }
+
+    fn update_high_qc(&mut self, block: usize, blocks: &[BFTBlock]) {
+        if blocks[block].view > blocks[self.high_qc].view {
+            self.high_qc = block;
+            self.consecutive_timeouts = 0;
+        }
+    }
+
+    /// A block is safe to vote for if it extends the locked block, or if its
+    /// QC is newer than the lock.
+    fn is_safe(&self, block: usize, blocks: &[BFTBlock]) -> bool {
+        let locked = &blocks[self.locked_block];
+        let extends_locked = {
+            let mut ancestor = block;
+            while blocks[ancestor].height > locked.height {
+                match blocks[ancestor].get_single_parent() {
+                    Some(parent) => ancestor = parent,
+                    None => break,
+                }
+            }
+            ancestor == self.locked_block
+        };
+        extends_locked
+            || blocks[block]
+                .get_single_parent()
+                .is_some_and(|parent| blocks[parent].view > locked.view)
+    }
+
+    /// Commits a block and all its uncommitted ancestors.
+    fn commit(&mut self, block: usize, blocks: &[BFTBlock], simulator: &mut Simulator) {
+        let mut chain = Vec::new();
+        let mut ancestor = block;
+        while blocks[ancestor].height > self.committed_height {
+            chain.push(ancestor);
+            match blocks[ancestor].get_single_parent() {
+                Some(parent) => ancestor = parent,
+                None => break,
+            }
+        }
+        for &committed in chain.iter().rev() {
+            self.confirmed_blocks.insert(committed);
+            simulator.put_event(
+                Box::new(BlockConfirmationEvent::new(committed, self.node_index)),
+                0.0,
+            );
+        }
+        if !chain.is_empty() {
+            self.current_main_chain_head_index = block;
+            self.committed_height = blocks[block].height;
+            let committed_view = blocks[block].view;
+            self.votes.retain(|(view, _), _| *view >= committed_view);
+            self.new_views.retain(|view, _| *view >= committed_view);
+            self.proposed_views.retain(|view| *view >= committed_view);
+        }
+    }
+
+    fn view_timeout(&self, config: &HotStuffConsensusConfig) -> BFTAction {
+        BFTAction::ScheduleTimeout {
+            view: self.view,
+            height: 0,
+            step: BFTVoteType::Proposal,
+            delay: config.view_timeout * 2_f64.powi(self.consecutive_timeouts),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::consensus::algorithm::{ChainBasedConsensus, DAGBasedConsensus};
 use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
 use crate::consensus::config::nakamoto_consensus_config::NakamotoConsensusConfig;
 use crate::ledger_data::bitcoin_block::BitcoinBlock;
+use crate::ledger_data::single_parent::SingleParent;
 use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
 use crate::simulator::Simulator;
 use std::collections::HashSet;
@@ -17,6 +18,7 @@ pub struct NakamotoConsensus {
 impl DAGBasedConsensus for NakamotoConsensus {
     type B = BitcoinBlock;
     type G = NakamotoConsensusConfig;
+    type L = LocalBlockTree;
 
     fn new(config: &NakamotoConsensusConfig) -> Self {
         Self {
@@ -53,9 +55,12 @@ impl DAGBasedConsensus for NakamotoConsensus {
         local_block_trees: &LocalBlockTree,
         simulator: &mut Simulator,
     ) {
-        let block_height = blocks[block_index].height;
-        if block_height > self.longest_chain_len {
-            self.longest_chain_len = block_height;
+        // the chain with the most proof-of-work wins; Bitcoin-NG microblocks
+        // only break ties between chains of the same key height.
+        let block = &blocks[block_index];
+        let head = &blocks[self.current_main_chain_head_index];
+        if (block.key_height, block.height) > (head.key_height, head.height) {
+            self.longest_chain_len = block.height;
             self.current_main_chain_head_index = block_index;
             self.update_chain(blocks, config, local_block_trees, simulator);
         }
@@ -82,25 +87,19 @@ impl ChainBasedConsensus for NakamotoConsensus {
         local_block_trees: &LocalBlockTree,
         simulator: &mut Simulator,
     ) {
-        let current_main_head_height = blocks[self.current_main_chain_head_index].height;
+        let current_main_head_key_height = blocks[self.current_main_chain_head_index].key_height;
         let confirmation_depth = config.confirmation_depth;
-        if current_main_head_height > confirmation_depth {
-            let height_of_confirmed_blocks = current_main_head_height - confirmation_depth;
-            if let Some(highest_confirmed_block_index) = local_block_trees
-                .get_single_ancestor_of_height(
-                    self.current_main_chain_head_index,
-                    height_of_confirmed_blocks,
-                    blocks,
-                )
-            {
-                self.confirmed_blocks = local_block_trees
-                    .get_all_single_ancestors(highest_confirmed_block_index, blocks);
-                let block_confirmation_event = Box::new(BlockConfirmationEvent::new(
-                    highest_confirmed_block_index,
-                    self.node_index,
-                ));
-                simulator.put_event(block_confirmation_event, 0.0);
-            }
+        if current_main_head_key_height > confirmation_depth {
+            let key_height_of_confirmed_blocks = current_main_head_key_height - confirmation_depth;
+            let highest_confirmed_block_index =
+                self.highest_ancestor_of_key_height(key_height_of_confirmed_blocks, blocks);
+            self.confirmed_blocks =
+                local_block_trees.get_all_single_ancestors(highest_confirmed_block_index, blocks);
+            let block_confirmation_event = Box::new(BlockConfirmationEvent::new(
+                highest_confirmed_block_index,
+                self.node_index,
+            ));
+            simulator.put_event(block_confirmation_event, 0.0);
         }
     }
 
@@ -120,3 +119,18 @@ impl ChainBasedConsensus for NakamotoConsensus {
         self.current_main_chain_head_index = index;
     }
 }
+
+impl NakamotoConsensus {
+    /// Returns the highest block of the canonical chain with the given key
+    /// height. Without microblocks, it is the ancestor of that height.
+    fn highest_ancestor_of_key_height(&self, key_height: i32, blocks: &[BitcoinBlock]) -> usize {
+        let mut ancestor = self.current_main_chain_head_index;
+        while blocks[ancestor].key_height > key_height {
+            match blocks[ancestor].get_single_parent() {
+                Some(parent) => ancestor = parent,
+                None => break,
+            }
+        }
+        ancestor
+    }
+}
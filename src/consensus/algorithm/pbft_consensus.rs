@@ -0,0 +1,397 @@
+//! Practical Byzantine Fault Tolerance (PBFT) with one block per sequence
+//! number: pre-prepare (proposal), prepare and commit phases, and view change
+//! when the leader does not get a block committed in time. View change votes
+//! carry the highest prepared certificate of the replica, and the leader of
+//! the new view proposes the block of the highest one again.
+
+use crate::consensus::algorithm::{BFTAction, BFTConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::pbft_consensus_config::PBFTConsensusConfig;
+use crate::consensus::config::BFTConsensusConfig;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default, Debug, Clone)]
+pub struct PBFTConsensus {
+    pub view: u64,
+    /// Sequence number of the next block to commit.
+    pub height: u64,
+    pub current_main_chain_head_index: usize,
+    pub confirmed_blocks: HashSet<usize>,
+    pub node_index: usize,
+    /// Proposals received for each (view, height), with the view in which a
+    /// proposed again block was prepared.
+    proposals: HashMap<(u64, u64), (usize, Option<u64>)>,
+    /// The proposal accepted (and prepared for) in each (view, height).
+    accepted: HashMap<(u64, u64), usize>,
+    prepares: HashMap<(u64, u64, usize), HashSet<usize>>,
+    commits: HashMap<(u64, u64, usize), HashSet<usize>>,
+    sent_commits: HashSet<(u64, u64)>,
+    /// The highest view in which a block of the current height was prepared,
+    /// and the block. Another block of this height is only accepted if it was
+    /// prepared in a higher view.
+    prepared: Option<(u64, usize)>,
+    view_changes: HashMap<u64, HashSet<usize>>,
+    /// The highest prepared certificate of the view change votes to each
+    /// (view, height): <(view, height), (prepared view, block)>
+    view_change_certificates: HashMap<(u64, u64), (u64, usize)>,
+    /// The highest view this replica requested a change to.
+    requested_view: u64,
+    consecutive_view_changes: i32,
+}
+
+impl DAGBasedConsensus for PBFTConsensus {
+    type B = BFTBlock;
+    type G = PBFTConsensusConfig;
+    type L = LocalBlockTree;
+
+    fn new(config: &PBFTConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        *self = Self {
+            height: 1,
+            current_main_chain_head_index: config.genesis_block_index,
+            node_index,
+            ..Self::default()
+        };
+    }
+
+    /// Blocks are processed with their proposal in `new_incoming_vote`.
+    fn new_incoming_block(
+        &mut self,
+        _block_index: usize,
+        _blocks: &[BFTBlock],
+        _config: &PBFTConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) {
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl BFTConsensus for PBFTConsensus {
+    fn protocol_name() -> &'static str {
+        "PBFT"
+    }
+
+    fn start(&mut self, config: &Self::G) -> Vec<BFTAction> {
+        let mut actions = vec![self.commit_timeout(config)];
+        if config.get_leader(self.view) == self.node_index {
+            actions.push(self.proposal(config));
+        }
+        actions
+    }
+
+    fn new_incoming_vote(
+        &mut self,
+        vote: &BFTVote,
+        _blocks: &[BFTBlock],
+        config: &PBFTConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        match (vote.vote_type, vote.block_index) {
+            (BFTVoteType::Proposal, Some(block)) if vote.voter == config.get_leader(vote.view) => {
+                self.proposals
+                    .entry((vote.view, vote.height))
+                    .or_insert((block, vote.valid_view));
+                self.try_accept_proposal(config, &mut actions);
+                // the commits of the block may arrive before the block.
+                self.try_execute_pending(config, local_block_trees, simulator, &mut actions);
+            }
+            (BFTVoteType::Prepare, Some(block)) => {
+                self.prepares
+                    .entry((vote.view, vote.height, block))
+                    .or_default()
+                    .insert(vote.voter);
+                self.try_commit(vote.view, vote.height, block, config, &mut actions);
+            }
+            (BFTVoteType::Commit, Some(block)) => {
+                self.commits
+                    .entry((vote.view, vote.height, block))
+                    .or_default()
+                    .insert(vote.voter);
+                self.try_execute(
+                    vote.view,
+                    vote.height,
+                    block,
+                    config,
+                    local_block_trees,
+                    simulator,
+                    &mut actions,
+                );
+            }
+            (BFTVoteType::ViewChange, _) => {
+                if let (Some(block), Some(prepared_view)) = (vote.block_index, vote.valid_view) {
+                    let certificate = self
+                        .view_change_certificates
+                        .entry((vote.view, vote.height))
+                        .or_insert((prepared_view, block));
+                    if prepared_view > certificate.0 {
+                        *certificate = (prepared_view, block);
+                    }
+                }
+                let requested_by = self.view_changes.entry(vote.view).or_default();
+                requested_by.insert(vote.voter);
+                let num_of_requests = requested_by.len();
+                // join a view change requested by at least one correct replica.
+                if num_of_requests > config.get_max_faulty() {
+                    self.request_view_change(vote.view, config, &mut actions);
+                }
+                if num_of_requests >= config.get_quorum() && vote.view > self.view {
+                    self.enter_view(vote.view, config, &mut actions);
+                }
+            }
+            _ => (),
+        }
+        actions
+    }
+
+    fn timeout(
+        &mut self,
+        view: u64,
+        height: u64,
+        _step: BFTVoteType,
+        _blocks: &[BFTBlock],
+        config: &PBFTConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        if height == self.height && view >= self.view {
+            self.request_view_change(view + 1, config, &mut actions);
+        }
+        actions
+    }
+}
+
+impl PBFTConsensus {
+    /// Proposes a new block on the head of the leader, or again the block of
+    /// the highest prepared certificate of the view change votes to this view.
+    fn proposal(&self, config: &PBFTConsensusConfig) -> BFTAction {
+        let vote = BFTVote::new(
+            config.get_leader(self.view),
+            self.view,
+            self.height,
+            BFTVoteType::Proposal,
+            None,
+        );
+        match self.view_change_certificates.get(&(self.view, self.height)) {
+            Some(&(prepared_view, block)) => BFTAction::Broadcast(BFTVote {
+                block_index: Some(block),
+                valid_view: Some(prepared_view),
+                ..vote
+            }),
+            None => BFTAction::Propose {
+                vote,
+                parent: self.current_main_chain_head_index,
+            },
+        }
+    }
+
+    fn commit_timeout(&self, config: &PBFTConsensusConfig) -> BFTAction {
+        BFTAction::ScheduleTimeout {
+            view: self.view,
+            height: self.height,
+            step: BFTVoteType::Commit,
+            delay: config.view_change_timeout * 2_f64.powi(self.consecutive_view_changes),
+        }
+    }
+
+    /// Accepts the proposal of the current view and height, if received, and
+    /// broadcasts a prepare for it. After preparing a block, the replica only
+    /// accepts another block of the same height prepared in a higher view.
+    fn try_accept_proposal(&mut self, config: &PBFTConsensusConfig, actions: &mut Vec<BFTAction>) {
+        let key = (self.view, self.height);
+        let Some(&(block, valid_view)) = self.proposals.get(&key) else {
+            return;
+        };
+        if self.accepted.contains_key(&key) {
+            return;
+        }
+        if let Some((prepared_view, prepared_block)) = self.prepared {
+            if block != prepared_block && valid_view.is_none_or(|view| view <= prepared_view) {
+                return;
+            }
+        }
+        self.accepted.insert(key, block);
+        actions.push(BFTAction::Broadcast(BFTVote::new(
+            self.node_index,
+            self.view,
+            self.height,
+            BFTVoteType::Prepare,
+            Some(block),
+        )));
+        self.try_commit(self.view, self.height, block, config, actions);
+    }
+
+    /// Broadcasts a commit once the accepted proposal is prepared by a quorum.
+    fn try_commit(
+        &mut self,
+        view: u64,
+        height: u64,
+        block: usize,
+        config: &PBFTConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        let is_prepared = self.accepted.get(&(view, height)) == Some(&block)
+            && self
+                .prepares
+                .get(&(view, height, block))
+                .is_some_and(|voters| voters.len() >= config.get_quorum());
+        if !is_prepared {
+            return;
+        }
+        if height == self.height
+            && self
+                .prepared
+                .is_none_or(|(prepared_view, _)| view > prepared_view)
+        {
+            self.prepared = Some((view, block));
+        }
+        if self.sent_commits.insert((view, height)) {
+            actions.push(BFTAction::Broadcast(BFTVote::new(
+                self.node_index,
+                view,
+                height,
+                BFTVoteType::Commit,
+                Some(block),
+            )));
+        }
+    }
+
+    /// Commits the block of the current height once a quorum has committed it,
+    /// then moves to the next height.
+    #[allow(clippy::too_many_arguments)]
+    fn try_execute(
+        &mut self,
+        view: u64,
+        height: u64,
+        block: usize,
+        config: &PBFTConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        let is_committed = self
+            .commits
+            .get(&(view, height, block))
+            .is_some_and(|voters| voters.len() >= config.get_quorum());
+        if height != self.height || !is_committed || !local_block_trees.contains(block) {
+            return;
+        }
+
+        self.current_main_chain_head_index = block;
+        self.confirmed_blocks.insert(block);
+        simulator.put_event(
+            Box::new(BlockConfirmationEvent::new(block, self.node_index)),
+            0.0,
+        );
+
+        self.height += 1;
+        self.prepared = None;
+        self.consecutive_view_changes = 0;
+        actions.push(self.commit_timeout(config));
+        if config.get_leader(self.view) == self.node_index {
+            actions.push(self.proposal(config));
+        }
+        self.try_accept_proposal(config, actions);
+        // the next block may already be committed by a quorum.
+        self.try_execute_pending(config, local_block_trees, simulator, actions);
+    }
+
+    /// Commits a block of the current height committed by a quorum in any view,
+    /// once the block is received.
+    fn try_execute_pending(
+        &mut self,
+        config: &PBFTConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        let mut committed: Vec<(u64, usize)> = self
+            .commits
+            .iter()
+            .filter(|(&(_, height, _), voters)| {
+                height == self.height && voters.len() >= config.get_quorum()
+            })
+            .map(|(&(view, _, block), _)| (view, block))
+            .collect();
+        committed.sort_unstable();
+        for (view, block) in committed {
+            self.try_execute(
+                view,
+                self.height,
+                block,
+                config,
+                local_block_trees,
+                simulator,
+                actions,
+            );
+        }
+    }
+
+    fn request_view_change(
+        &mut self,
+        new_view: u64,
+        config: &PBFTConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        if new_view <= self.requested_view || new_view <= self.view {
+            return;
+        }
+        self.requested_view = new_view;
+        self.consecutive_view_changes += 1;
+        // the view change carries the prepared certificate of the replica.
+        actions.push(BFTAction::Broadcast(BFTVote {
+            block_index: self.prepared.map(|(_, block)| block),
+            valid_view: self.prepared.map(|(prepared_view, _)| prepared_view),
+            ..BFTVote::new(
+                self.node_index,
+                new_view,
+                self.height,
+                BFTVoteType::ViewChange,
+                None,
+            )
+        }));
+        // if the new view does not commit either, move on to the next one.
+        actions.push(BFTAction::ScheduleTimeout {
+            view: new_view,
+            height: self.height,
+            step: BFTVoteType::Commit,
+            delay: config.view_change_timeout * 2_f64.powi(self.consecutive_view_changes),
+        });
+    }
+
+    fn enter_view(
+        &mut self,
+        view: u64,
+        config: &PBFTConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        self.view = view;
+        if config.get_leader(view) == self.node_index {
+            actions.push(self.proposal(config));
+        }
+        self.try_accept_proposal(config, actions);
+    }
+}
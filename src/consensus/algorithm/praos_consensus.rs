@@ -0,0 +1,130 @@
+//! Ouroboros Praos: a longest-chain Proof-of-Stake protocol. The leaders of a
+//! slot are elected by a private stake-weighted lottery, so a slot may have
+//! no leader or several ones, the latter creating forks.
+//!
+//! Like Nakamoto consensus, a node follows the longest chain it knows.
+//! Unlike it, the node never switches to a chain forking more than `k` blocks
+//! below its head, and the blocks `k` deep are settled.
+
+use crate::consensus::algorithm::{ChainBasedConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::praos_consensus_config::PraosConsensusConfig;
+use crate::ledger_data::praos_block::PraosBlock;
+use crate::ledger_data::single_parent::SingleParent;
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::HashSet;
+
+#[derive(Default, Debug, Clone)]
+pub struct PraosConsensus {
+    pub longest_chain_len: i32,
+    pub current_main_chain_head_index: usize,
+    pub confirmed_blocks: HashSet<usize>,
+    pub node_index: usize,
+}
+
+impl DAGBasedConsensus for PraosConsensus {
+    type B = PraosBlock;
+    type G = PraosConsensusConfig;
+    type L = LocalBlockTree;
+
+    fn new(config: &PraosConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        self.longest_chain_len = 0;
+        self.current_main_chain_head_index = config.genesis_block_index;
+        self.confirmed_blocks = HashSet::new();
+        self.node_index = node_index;
+    }
+
+    /// Switches to the chain of the new block if it is strictly longer and
+    /// forks at most `settlement_depth` blocks below the current head. Between
+    /// chains of the same length, the first received one is kept.
+    fn new_incoming_block(
+        &mut self,
+        block_index: usize,
+        blocks: &[PraosBlock],
+        config: &PraosConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        let head = self.current_main_chain_head_index;
+        if blocks[block_index].height <= blocks[head].height {
+            return;
+        }
+
+        let common_ancestor = local_block_trees.get_common_ancestor(block_index, head, blocks);
+        if blocks[head].height - blocks[common_ancestor].height > config.settlement_depth {
+            return;
+        }
+
+        self.longest_chain_len = blocks[block_index].height;
+        self.current_main_chain_head_index = block_index;
+        self.update_chain(blocks, config, local_block_trees, simulator);
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl ChainBasedConsensus for PraosConsensus {
+    /// Settles the block of the canonical chain `settlement_depth` blocks
+    /// below the head, with all its ancestors.
+    fn update_chain(
+        &mut self,
+        blocks: &[Self::B],
+        config: &Self::G,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) {
+        let head = self.current_main_chain_head_index;
+        let settlement_depth = config.settlement_depth;
+        if blocks[head].height > settlement_depth {
+            let height_of_settled_block = blocks[head].height - settlement_depth;
+            let mut settled_block_index = head;
+            while blocks[settled_block_index].height > height_of_settled_block {
+                match blocks[settled_block_index].get_single_parent() {
+                    Some(parent) => settled_block_index = parent,
+                    None => break,
+                }
+            }
+            self.confirmed_blocks =
+                local_block_trees.get_all_single_ancestors(settled_block_index, blocks);
+            self.confirmed_blocks.insert(settled_block_index);
+            let block_confirmation_event = Box::new(BlockConfirmationEvent::new(
+                settled_block_index,
+                self.node_index,
+            ));
+            simulator.put_event(block_confirmation_event, 0.0);
+        }
+    }
+
+    fn get_longest_chain_len(&self) -> i32 {
+        self.longest_chain_len
+    }
+
+    fn get_canonical_chain_head_index(&self) -> usize {
+        self.current_main_chain_head_index
+    }
+
+    fn set_longest_chain_len(&mut self, len: i32) {
+        self.longest_chain_len = len;
+    }
+
+    fn set_canonical_chain_head_index(&mut self, index: usize) {
+        self.current_main_chain_head_index = index;
+    }
+}
@@ -0,0 +1,409 @@
+//! Tendermint: rounds of propose, prevote and precommit for each height, with
+//! locking on prevoted values and a new round when a round times out.
+
+use crate::consensus::algorithm::{BFTAction, BFTConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::tendermint_consensus_config::TendermintConsensusConfig;
+use crate::consensus::config::BFTConsensusConfig;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
+use crate::simulator::event::block_confirmation_event::BlockConfirmationEvent;
+use crate::simulator::Simulator;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+enum Step {
+    #[default]
+    Propose,
+    Prevote,
+    Precommit,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct TendermintConsensus {
+    pub height: u64,
+    pub round: u64,
+    pub current_main_chain_head_index: usize,
+    pub confirmed_blocks: HashSet<usize>,
+    pub node_index: usize,
+    step: Step,
+    /// The locked value and the round it is locked in.
+    locked: Option<(u64, usize)>,
+    /// The latest value with a quorum of prevotes and its round.
+    valid: Option<(u64, usize)>,
+    /// The proposal of each (height, round), with its valid round.
+    proposals: HashMap<(u64, u64), (usize, Option<u64>)>,
+    /// Voters of each (height, round, value), where `None` is nil.
+    prevotes: HashMap<(u64, u64, Option<usize>), HashSet<usize>>,
+    precommits: HashMap<(u64, u64, Option<usize>), HashSet<usize>>,
+    /// Voters of any value in each (height, round).
+    prevoters: HashMap<(u64, u64), HashSet<usize>>,
+    precommitters: HashMap<(u64, u64), HashSet<usize>>,
+    /// Senders of any message in each (height, round).
+    round_senders: HashMap<(u64, u64), HashSet<usize>>,
+    /// Rounds in which a quorum of prevotes for the proposal was seen.
+    polka_rounds: HashSet<(u64, u64)>,
+    scheduled_timeouts: HashSet<(u64, u64, BFTVoteType)>,
+}
+
+impl DAGBasedConsensus for TendermintConsensus {
+    type B = BFTBlock;
+    type G = TendermintConsensusConfig;
+    type L = LocalBlockTree;
+
+    fn new(config: &TendermintConsensusConfig) -> Self {
+        let mut consensus = Self::default();
+        consensus.initial_configuration(config, 0);
+        consensus
+    }
+
+    fn initial_configuration(&mut self, config: &Self::G, node_index: usize) {
+        *self = Self {
+            height: 1,
+            current_main_chain_head_index: config.genesis_block_index,
+            node_index,
+            ..Self::default()
+        };
+    }
+
+    /// Blocks are processed with their proposal in `new_incoming_vote`.
+    fn new_incoming_block(
+        &mut self,
+        _block_index: usize,
+        _blocks: &[BFTBlock],
+        _config: &TendermintConsensusConfig,
+        _local_block_trees: &LocalBlockTree,
+        _simulator: &mut Simulator,
+    ) {
+    }
+
+    fn get_mut_confirmed_blocks(&mut self) -> &mut HashSet<usize> {
+        &mut self.confirmed_blocks
+    }
+
+    fn get_node_index(&self) -> usize {
+        self.node_index
+    }
+
+    fn set_node_index(&mut self, node_index: usize) {
+        self.node_index = node_index;
+    }
+}
+
+impl BFTConsensus for TendermintConsensus {
+    fn protocol_name() -> &'static str {
+        "Tendermint"
+    }
+
+    fn start(&mut self, config: &Self::G) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        self.start_round(0, config, &mut actions);
+        actions
+    }
+
+    fn new_incoming_vote(
+        &mut self,
+        vote: &BFTVote,
+        _blocks: &[BFTBlock],
+        config: &TendermintConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        if vote.height < self.height {
+            return actions;
+        }
+
+        let key = (vote.height, vote.view);
+        match (vote.vote_type, vote.block_index) {
+            (BFTVoteType::Proposal, Some(block))
+                if vote.voter == config.get_proposer(vote.height, vote.view) =>
+            {
+                self.proposals
+                    .entry(key)
+                    .or_insert((block, vote.valid_view));
+            }
+            (BFTVoteType::Prevote, block) => {
+                self.prevotes
+                    .entry((vote.height, vote.view, block))
+                    .or_default()
+                    .insert(vote.voter);
+                self.prevoters.entry(key).or_default().insert(vote.voter);
+            }
+            (BFTVoteType::Precommit, block) => {
+                self.precommits
+                    .entry((vote.height, vote.view, block))
+                    .or_default()
+                    .insert(vote.voter);
+                self.precommitters
+                    .entry(key)
+                    .or_default()
+                    .insert(vote.voter);
+            }
+            _ => return actions,
+        }
+        self.round_senders
+            .entry(key)
+            .or_default()
+            .insert(vote.voter);
+
+        self.progress(config, local_block_trees, simulator, &mut actions);
+        actions
+    }
+
+    fn timeout(
+        &mut self,
+        view: u64,
+        height: u64,
+        step: BFTVoteType,
+        _blocks: &[BFTBlock],
+        config: &TendermintConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+    ) -> Vec<BFTAction> {
+        let mut actions = Vec::new();
+        if height != self.height || view != self.round {
+            return actions;
+        }
+
+        match (step, self.step) {
+            (BFTVoteType::Proposal, Step::Propose) => {
+                self.vote(BFTVoteType::Prevote, None, &mut actions);
+                self.step = Step::Prevote;
+            }
+            (BFTVoteType::Prevote, Step::Prevote) => {
+                self.vote(BFTVoteType::Precommit, None, &mut actions);
+                self.step = Step::Precommit;
+            }
+            (BFTVoteType::Precommit, _) => self.start_round(view + 1, config, &mut actions),
+            _ => return actions,
+        }
+        self.progress(config, local_block_trees, simulator, &mut actions);
+        actions
+    }
+}
+
+impl TendermintConsensus {
+    fn start_round(
+        &mut self,
+        round: u64,
+        config: &TendermintConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        self.round = round;
+        self.step = Step::Propose;
+        if config.get_proposer(self.height, round) == self.node_index {
+            let vote = BFTVote::new(
+                self.node_index,
+                round,
+                self.height,
+                BFTVoteType::Proposal,
+                None,
+            );
+            match self.valid {
+                Some((valid_round, valid_block)) => actions.push(BFTAction::Broadcast(BFTVote {
+                    block_index: Some(valid_block),
+                    valid_view: Some(valid_round),
+                    ..vote
+                })),
+                None => actions.push(BFTAction::Propose {
+                    vote,
+                    parent: self.current_main_chain_head_index,
+                }),
+            }
+        }
+        self.schedule_timeout(BFTVoteType::Proposal, config, actions);
+    }
+
+    /// Applies the rules of the current round and decides the height when a
+    /// value has a quorum of precommits in any round, checking the rounds in
+    /// order. Repeats for the next height or round, whose messages may already
+    /// be received.
+    fn progress(
+        &mut self,
+        config: &TendermintConsensusConfig,
+        local_block_trees: &LocalBlockTree,
+        simulator: &mut Simulator,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        loop {
+            self.progress_round(config, actions);
+
+            let height = self.height;
+            let mut proposals: Vec<(u64, usize)> = self
+                .proposals
+                .iter()
+                .filter(|((proposal_height, _), (block, _))| {
+                    *proposal_height == height && local_block_trees.contains(*block)
+                })
+                .map(|(&(_, round), &(block, _))| (round, block))
+                .collect();
+            proposals.sort_unstable();
+            let decided_block = proposals
+                .into_iter()
+                .find(|&(round, block)| {
+                    self.num_of_votes(&self.precommits, round, Some(block)) >= config.get_quorum()
+                })
+                .map(|(_, block)| block);
+            if let Some(block) = decided_block {
+                self.decide(block, config, simulator, actions);
+                continue;
+            }
+
+            // skip to a later round in which at least one correct replica is.
+            let later_round = self
+                .round_senders
+                .iter()
+                .filter(|((sender_height, round), senders)| {
+                    *sender_height == height
+                        && *round > self.round
+                        && senders.len() > config.get_max_faulty()
+                })
+                .map(|((_, round), _)| *round)
+                .max();
+            if let Some(round) = later_round {
+                self.start_round(round, config, actions);
+                continue;
+            }
+            return;
+        }
+    }
+
+    fn progress_round(&mut self, config: &TendermintConsensusConfig, actions: &mut Vec<BFTAction>) {
+        let (height, round) = (self.height, self.round);
+        let quorum = config.get_quorum();
+        let proposal = self.proposals.get(&(height, round)).copied();
+
+        if let (Step::Propose, Some((block, valid_round))) = (self.step, proposal) {
+            // a re-proposed valid value needs its quorum of prevotes from its
+            // valid round.
+            let prevote = match valid_round {
+                None => Some(self.locked.is_none_or(|(_, locked)| locked == block)),
+                Some(valid_round)
+                    if valid_round < round
+                        && self.num_of_votes(&self.prevotes, valid_round, Some(block))
+                            >= quorum =>
+                {
+                    Some(self.locked.is_none_or(|(locked_round, locked)| {
+                        locked_round <= valid_round || locked == block
+                    }))
+                }
+                Some(_) => None,
+            };
+            if let Some(is_acceptable) = prevote {
+                let value = is_acceptable.then_some(block);
+                self.vote(BFTVoteType::Prevote, value, actions);
+                self.step = Step::Prevote;
+            }
+        }
+
+        let num_of_prevoters = self.prevoters.get(&(height, round)).map_or(0, HashSet::len);
+        if self.step == Step::Prevote && num_of_prevoters >= quorum {
+            self.schedule_timeout(BFTVoteType::Prevote, config, actions);
+        }
+
+        if let Some((block, _)) = proposal {
+            if self.step != Step::Propose
+                && self.num_of_votes(&self.prevotes, round, Some(block)) >= quorum
+                && self.polka_rounds.insert((height, round))
+            {
+                if self.step == Step::Prevote {
+                    self.locked = Some((round, block));
+                    self.vote(BFTVoteType::Precommit, Some(block), actions);
+                    self.step = Step::Precommit;
+                }
+                self.valid = Some((round, block));
+            }
+        }
+
+        if self.step == Step::Prevote && self.num_of_votes(&self.prevotes, round, None) >= quorum {
+            self.vote(BFTVoteType::Precommit, None, actions);
+            self.step = Step::Precommit;
+        }
+
+        let num_of_precommitters = self
+            .precommitters
+            .get(&(height, round))
+            .map_or(0, HashSet::len);
+        if num_of_precommitters >= quorum {
+            self.schedule_timeout(BFTVoteType::Precommit, config, actions);
+        }
+    }
+
+    fn decide(
+        &mut self,
+        block: usize,
+        config: &TendermintConsensusConfig,
+        simulator: &mut Simulator,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        self.current_main_chain_head_index = block;
+        self.confirmed_blocks.insert(block);
+        simulator.put_event(
+            Box::new(BlockConfirmationEvent::new(block, self.node_index)),
+            0.0,
+        );
+
+        self.height += 1;
+        self.locked = None;
+        self.valid = None;
+        let height = self.height;
+        self.proposals.retain(|(h, _), _| *h >= height);
+        self.prevotes.retain(|(h, _, _), _| *h >= height);
+        self.precommits.retain(|(h, _, _), _| *h >= height);
+        self.prevoters.retain(|(h, _), _| *h >= height);
+        self.precommitters.retain(|(h, _), _| *h >= height);
+        self.round_senders.retain(|(h, _), _| *h >= height);
+        self.polka_rounds.retain(|(h, _)| *h >= height);
+        self.scheduled_timeouts.retain(|(h, _, _)| *h >= height);
+        self.start_round(0, config, actions);
+    }
+
+    fn num_of_votes(
+        &self,
+        votes: &HashMap<(u64, u64, Option<usize>), HashSet<usize>>,
+        round: u64,
+        value: Option<usize>,
+    ) -> usize {
+        votes
+            .get(&(self.height, round, value))
+            .map_or(0, HashSet::len)
+    }
+
+    fn vote(&self, vote_type: BFTVoteType, value: Option<usize>, actions: &mut Vec<BFTAction>) {
+        actions.push(BFTAction::Broadcast(BFTVote::new(
+            self.node_index,
+            self.round,
+            self.height,
+            vote_type,
+            value,
+        )));
+    }
+
+    /// Schedules the timeout of a step once per round. Timeouts grow linearly
+    /// with the round.
+    fn schedule_timeout(
+        &mut self,
+        step: BFTVoteType,
+        config: &TendermintConsensusConfig,
+        actions: &mut Vec<BFTAction>,
+    ) {
+        if !self
+            .scheduled_timeouts
+            .insert((self.height, self.round, step))
+        {
+            return;
+        }
+        let timeout = match step {
+            BFTVoteType::Proposal => config.timeout_propose,
+            BFTVoteType::Prevote => config.timeout_prevote,
+            _ => config.timeout_precommit,
+        };
+        actions.push(BFTAction::ScheduleTimeout {
+            view: self.round,
+            height: self.height,
+            step,
+            delay: timeout + config.timeout_delta * self.round as f64,
+        });
+    }
+}
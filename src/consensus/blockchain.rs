@@ -1,3 +1,16 @@
 pub mod local_block;
 pub mod local_block_dag;
 pub mod local_block_tree;
+
+use crate::ledger_data::block::Block;
+
+/// The local view of the ledger each node keeps of its received blocks.
+pub trait LocalLedger: Clone + Default {
+    /// Checks if the block is received.
+    fn contains(&self, block_index: usize) -> bool;
+
+    /// Adds a received block and returns the blocks which got connected to
+    /// genesis by it, parents before children. The list is empty if the block
+    /// is already received or still misses a parent.
+    fn add_block<B: Block>(&mut self, block_index: usize, blocks: &[B]) -> Vec<usize>;
+}
@@ -1,107 +1,133 @@
-// use crate::consensus::blockchain::local_block::LocalBlock;
-// use crate::ledger_data::block::Block;
-// use std::collections::{HashMap, HashSet};
-//
-// #[derive(Default, Clone)]
-// pub struct LocalBlockDAG {
-//     local_block_dag: HashMap<usize, LocalBlock>,
-//     genesis_block_index: usize,
-// }
-//
-// impl LocalBlockDAG {
-//     pub fn new(genesis_block_index: usize) -> Self {
-//         let mut genesis_local_block = LocalBlock::new(genesis_block_index);
-//         genesis_local_block.is_connected_to_genesis = true;
-//         let mut local_block_dag = HashMap::new();
-//         local_block_dag.insert(genesis_block_index, genesis_local_block);
-//         Self {
-//             local_block_dag,
-//             genesis_block_index,
-//         }
-//     }
-//
-//     pub fn add(&mut self, blocks: &[Block], block_index: usize) {
-//         if !self.local_block_dag.contains_key(&block_index) {
-//             // create a new local block for the received block index.
-//             let mut local_block = LocalBlock::new(block_index);
-//
-//             // find all children of previously received blocks and add them to
-//             // the children of this local block.
-//             let seen_children = self
-//                 .local_block_dag
-//                 .keys()
-//                 .filter(|&&block_index_in_local_dag| {
-//                     blocks[block_index_in_local_dag]
-//                         .parents
-//                         .contains(&block_index)
-//                 });
-//             local_block.children_index.extend(seen_children);
-//
-//             // find all parents of this block from received blocks:
-//             for parent in &blocks[block_index].parents {
-//                 // todo: benchmark here (untested):
-//                 // version 1.
-//                 // if self.local_block_dag.contains_key(parent) {
-//                 //     let local_parent = self.local_block_dag.get_mut(parent).unwrap();
-//                 //
-//                 // version 2.
-//                 if let Some(local_parent) = self.local_block_dag.get_mut(parent) {
-//                     // end of to do
-//                     local_parent.children_index.insert(block_index);
-//                     if local_parent.is_connected_to_genesis {
-//                         local_block.is_connected_to_genesis = true;
-//                         if let Some(successors) = self.get_all_successors(block_index) {
-//                             for successor in successors {
-//                                 self.local_block_dag
-//                                     .get_mut(&successor)
-//                                     .unwrap()
-//                                     .is_connected_to_genesis = true;
-//                             }
-//                         }
-//                     }
-//                 }
-//             }
-//
-//             self.local_block_dag.insert(block_index, local_block);
-//         }
-//     }
-//
-//     pub fn get_all_successors(&self, block_index: usize) -> Option<HashSet<usize>> {
-//         // if block is not seen, or has no children, returns None:
-//         if self.local_block_dag.contains_key(&block_index)
-//             || self
-//                 .local_block_dag
-//                 .get(&block_index)
-//                 .unwrap()
-//                 .children_index
-//                 .is_empty()
-//         {
-//             None
-//         } else {
-//             let mut in_current_height: HashSet<usize> = HashSet::new();
-//             let mut in_next_height: HashSet<usize> = HashSet::new();
-//             let mut all_successors: HashSet<usize> = HashSet::new();
-//             in_current_height.insert(block_index);
-//             'l: loop {
-//                 for successor in &in_current_height {
-//                     in_next_height.extend(
-//                         self.local_block_dag
-//                             .get(successor)
-//                             .unwrap()
-//                             .children_index
-//                             .iter(),
-//                     );
-//                 }
-//                 in_current_height.clear();
-//                 in_current_height.extend(in_next_height.iter());
-//                 all_successors.extend(in_current_height.iter());
-//                 in_next_height.clear();
-//
-//                 if in_current_height.is_empty() {
-//                     break 'l;
-//                 }
-//             }
-//             Some(all_successors)
-//         }
-//     }
-// }
+use crate::consensus::blockchain::local_block::LocalBlock;
+use crate::consensus::blockchain::LocalLedger;
+use crate::ledger_data::block::Block;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The blocks a node has received, for ledgers where a block may have several
+/// parents. Unlike [`LocalBlockTree`], a block is connected to genesis only
+/// when all its parents are connected.
+///
+/// [`LocalBlockTree`]: crate::consensus::blockchain::local_block_tree::LocalBlockTree
+#[derive(Clone, Debug)]
+pub struct LocalBlockDAG {
+    pub local_block_dag: HashMap<usize, LocalBlock>,
+    /// Connected blocks which have no connected children.
+    pub tips: HashSet<usize>,
+    /// Received blocks waiting for each missing parent: <parent, children>
+    waiting_for_parent: HashMap<usize, HashSet<usize>>,
+}
+
+impl Default for LocalBlockDAG {
+    fn default() -> Self {
+        LocalBlockDAG::new()
+    }
+}
+
+impl LocalBlockDAG {
+    pub fn new() -> Self {
+        let genesis_block_index: usize = 0;
+        let mut genesis_local_block = LocalBlock::new(genesis_block_index);
+        genesis_local_block.is_connected_to_genesis = true;
+        let mut local_block_dag = HashMap::new();
+        local_block_dag.insert(genesis_block_index, genesis_local_block);
+        Self {
+            local_block_dag,
+            tips: HashSet::from([genesis_block_index]),
+            waiting_for_parent: HashMap::new(),
+        }
+    }
+
+    /// Adds a received block and returns the blocks which got connected to
+    /// genesis by it, in topological order. The list is empty if the block
+    /// still misses a parent.
+    pub fn add<B>(&mut self, block_index: usize, blocks: &[B]) -> Vec<usize>
+    where
+        B: Block,
+    {
+        if self.contains(block_index) {
+            return Vec::new();
+        }
+
+        let mut local_block = LocalBlock::new(block_index);
+        if let Some(seen_children) = self.waiting_for_parent.remove(&block_index) {
+            local_block.children_index.extend(seen_children);
+        }
+        for &parent in blocks[block_index].get_parents() {
+            match self.local_block_dag.get_mut(&parent) {
+                Some(local_parent) => {
+                    local_parent.children_index.insert(block_index);
+                }
+                None => {
+                    self.waiting_for_parent
+                        .entry(parent)
+                        .or_default()
+                        .insert(block_index);
+                }
+            }
+        }
+        self.local_block_dag.insert(block_index, local_block);
+
+        let mut newly_connected = Vec::new();
+        let mut candidates = VecDeque::from([block_index]);
+        while let Some(candidate) = candidates.pop_front() {
+            if self.is_connected(candidate) || !self.all_parents_connected(candidate, blocks) {
+                continue;
+            }
+
+            let local_block = self.local_block_dag.get_mut(&candidate).unwrap();
+            local_block.is_connected_to_genesis = true;
+            candidates.extend(local_block.children_index.iter());
+            for parent in blocks[candidate].get_parents() {
+                self.tips.remove(parent);
+            }
+            self.tips.insert(candidate);
+            newly_connected.push(candidate);
+        }
+        newly_connected
+    }
+
+    fn all_parents_connected<B>(&self, block_index: usize, blocks: &[B]) -> bool
+    where
+        B: Block,
+    {
+        blocks[block_index]
+            .get_parents()
+            .iter()
+            .all(|&parent| self.is_connected(parent))
+    }
+
+    /// Checks if the `block_index` is among the keys in the
+    /// `self.local_block_dag` HashMap.
+    pub fn contains(&self, block_index: usize) -> bool {
+        self.local_block_dag.contains_key(&block_index)
+    }
+
+    /// Checks if the block and all its ancestors are received.
+    pub fn is_connected(&self, block_index: usize) -> bool {
+        self.local_block_dag
+            .get(&block_index)
+            .is_some_and(|local_block| local_block.is_connected_to_genesis)
+    }
+}
+
+impl LocalLedger for LocalBlockDAG {
+    fn contains(&self, block_index: usize) -> bool {
+        self.contains(block_index)
+    }
+
+    fn add_block<B: Block>(&mut self, block_index: usize, blocks: &[B]) -> Vec<usize> {
+        self.add(block_index, blocks)
+    }
+}
+
+pub fn assign_initial_local_block_dags(local_block_dag: &mut [LocalBlockDAG], num_of_nodes: usize) {
+    assert_eq!(
+        local_block_dag.len(),
+        num_of_nodes,
+        "Error: please initialize local_block_dag before calling assign_initial_local_block_dags"
+    );
+
+    for local_block_dag_initial in local_block_dag {
+        *local_block_dag_initial = LocalBlockDAG::new();
+    }
+}
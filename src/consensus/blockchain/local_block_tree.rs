@@ -1,4 +1,5 @@
 use crate::consensus::blockchain::local_block::LocalBlock;
+use crate::consensus::blockchain::LocalLedger;
 use crate::ledger_data::block::Block;
 use crate::ledger_data::single_parent::SingleParent;
 use std::cmp::Ordering;
@@ -242,6 +243,32 @@ impl LocalBlockTree {
     }
 }
 
+impl LocalLedger for LocalBlockTree {
+    fn contains(&self, block_index: usize) -> bool {
+        self.contains(block_index)
+    }
+
+    /// The successors connected by the block are ordered by height.
+    fn add_block<B: Block>(&mut self, block_index: usize, blocks: &[B]) -> Vec<usize> {
+        if self.contains(block_index) {
+            return Vec::new();
+        }
+        self.add(block_index, blocks);
+        if !self.local_block_dag[&block_index].is_connected_to_genesis {
+            return Vec::new();
+        }
+
+        let mut successors: Vec<usize> = self
+            .get_all_successors(block_index)
+            .map(|successors| successors.into_iter().collect())
+            .unwrap_or_default();
+        successors.sort_unstable_by_key(|&successor| (blocks[successor].get_height(), successor));
+        let mut connected_blocks = vec![block_index];
+        connected_blocks.extend(successors);
+        connected_blocks
+    }
+}
+
 pub fn assign_initial_local_block_trees(
     local_block_tree: &mut [LocalBlockTree],
     num_of_nodes: usize,
@@ -1,4 +1,11 @@
+pub mod bitcoin_ng_config;
+pub mod gasper_consensus_config;
+pub mod ghostdag_consensus_config;
+pub mod hotstuff_consensus_config;
 pub mod nakamoto_consensus_config;
+pub mod pbft_consensus_config;
+pub mod praos_consensus_config;
+pub mod tendermint_consensus_config;
 
 pub trait ConsensusConfig {}
 
@@ -11,3 +18,25 @@ pub trait ChainBasedConsensusConfig: ConsensusConfig {
     fn set_average_block_mining_interval(&mut self, interval: f64);
     fn set_genesis_index(&mut self, index: usize);
 }
+
+pub trait BFTConsensusConfig: ConsensusConfig {
+    fn get_num_of_replicas(&self) -> usize;
+    fn get_genesis_index(&self) -> usize {
+        0_usize
+    }
+
+    /// Maximum number of faulty replicas tolerated, `f = (n - 1) / 3`.
+    fn get_max_faulty(&self) -> usize {
+        self.get_num_of_replicas().saturating_sub(1) / 3
+    }
+
+    /// Number of votes of a quorum certificate, `n - f`.
+    fn get_quorum(&self) -> usize {
+        self.get_num_of_replicas() - self.get_max_faulty()
+    }
+
+    /// The leader of a view, in round-robin order.
+    fn get_leader(&self, view: u64) -> usize {
+        (view % self.get_num_of_replicas() as u64) as usize
+    }
+}
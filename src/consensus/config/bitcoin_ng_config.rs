@@ -0,0 +1,40 @@
+/// Bitcoin-NG: the miner of a key block becomes the leader and signs
+/// microblocks at a fixed rate until the next key block. The fees of a
+/// microblock are split between its leader and the next leader, so that a
+/// leader gains nothing by forking out the last microblocks of the previous
+/// one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitcoinNGConfig {
+    /// Time between two microblocks of a leader in seconds.
+    pub microblock_interval: f64,
+    /// Size of the transactions of a microblock in bytes.
+    pub microblock_size: u64,
+    /// Share of the fees of a microblock paid to its leader; the rest goes to
+    /// the leader of the next key block.
+    pub leader_fee_share: f64,
+}
+
+impl Default for BitcoinNGConfig {
+    /// 10 second microblocks with the transaction throughput of 1 MB Bitcoin
+    /// blocks, and the 40% fee share of the Bitcoin-NG paper.
+    fn default() -> Self {
+        Self::new(10.0, 16_667)
+    }
+}
+
+impl BitcoinNGConfig {
+    pub fn new(microblock_interval: f64, microblock_size: u64) -> Self {
+        Self {
+            microblock_interval,
+            microblock_size,
+            leader_fee_share: 0.4,
+        }
+    }
+
+    /// Splits the fees of a microblock into the shares of its leader and of
+    /// the next leader.
+    pub fn split_fee(&self, fee: f64) -> (f64, f64) {
+        let leader_fee = fee * self.leader_fee_share;
+        (leader_fee, fee - leader_fee)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::consensus::config::ConsensusConfig;
+
+#[derive(Default, Debug, Clone)]
+pub struct GasperConsensusConfig {
+    /// Duration of a slot in seconds.
+    pub slot_duration: f64,
+    pub slots_per_epoch: u64,
+    pub genesis_block_index: usize,
+    /// Stake of each validator, indexed by node.
+    pub validator_stakes: Vec<f64>,
+    pub total_stake: f64,
+}
+
+impl ConsensusConfig for GasperConsensusConfig {}
+
+impl GasperConsensusConfig {
+    pub fn new(slot_duration: f64, slots_per_epoch: u64, genesis_block_index: usize) -> Self {
+        Self {
+            slot_duration,
+            slots_per_epoch,
+            genesis_block_index,
+            validator_stakes: Vec::new(),
+            total_stake: 0.0,
+        }
+    }
+
+    pub fn set_validator_stakes(&mut self, validator_stakes: Vec<f64>) {
+        self.total_stake = validator_stakes.iter().sum();
+        self.validator_stakes = validator_stakes;
+    }
+
+    pub fn epoch_of_slot(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    pub fn first_slot_of_epoch(&self, epoch: u64) -> u64 {
+        epoch * self.slots_per_epoch
+    }
+
+    /// Returns true if `stake` is at least two thirds of the total stake.
+    pub fn is_supermajority(&self, stake: f64) -> bool {
+        3.0 * stake >= 2.0 * self.total_stake
+    }
+}
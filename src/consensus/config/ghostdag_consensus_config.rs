@@ -0,0 +1,33 @@
+use crate::consensus::config::ConsensusConfig;
+
+#[derive(Default, Debug, Clone)]
+pub struct GhostDAGConsensusConfig {
+    /// Maximum anticone size of a blue block within the blue set.
+    pub k: usize,
+    /// Blue score depth below the virtual selected parent after which the
+    /// merged blocks of a chain block are confirmed.
+    pub confirmation_depth: u64,
+    pub average_block_mining_interval: f64,
+    pub genesis_block_index: usize,
+    pub difficulty: f64,
+}
+
+impl ConsensusConfig for GhostDAGConsensusConfig {}
+
+impl GhostDAGConsensusConfig {
+    pub fn new(
+        k: usize,
+        confirmation_depth: u64,
+        average_block_mining_interval: f64,
+        genesis_block_index: usize,
+        difficulty: f64,
+    ) -> Self {
+        Self {
+            k,
+            confirmation_depth,
+            average_block_mining_interval,
+            genesis_block_index,
+            difficulty,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::consensus::config::{BFTConsensusConfig, ConsensusConfig};
+
+#[derive(Default, Debug, Clone)]
+pub struct HotStuffConsensusConfig {
+    pub num_of_replicas: usize,
+    /// Time in seconds a replica waits for the proposal of a view before it
+    /// moves to the next one. It doubles with each consecutive timeout.
+    pub view_timeout: f64,
+    pub genesis_block_index: usize,
+}
+
+impl ConsensusConfig for HotStuffConsensusConfig {}
+
+impl BFTConsensusConfig for HotStuffConsensusConfig {
+    fn get_num_of_replicas(&self) -> usize {
+        self.num_of_replicas
+    }
+
+    fn get_genesis_index(&self) -> usize {
+        self.genesis_block_index
+    }
+}
+
+impl HotStuffConsensusConfig {
+    pub fn new(num_of_replicas: usize, view_timeout: f64, genesis_block_index: usize) -> Self {
+        Self {
+            num_of_replicas,
+            view_timeout,
+            genesis_block_index,
+        }
+    }
+}
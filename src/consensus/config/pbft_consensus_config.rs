@@ -0,0 +1,36 @@
+use crate::consensus::config::{BFTConsensusConfig, ConsensusConfig};
+
+#[derive(Default, Debug, Clone)]
+pub struct PBFTConsensusConfig {
+    pub num_of_replicas: usize,
+    /// Time in seconds a replica waits for the commit of the next block before
+    /// it requests a view change. It doubles with each consecutive view change.
+    pub view_change_timeout: f64,
+    pub genesis_block_index: usize,
+}
+
+impl ConsensusConfig for PBFTConsensusConfig {}
+
+impl BFTConsensusConfig for PBFTConsensusConfig {
+    fn get_num_of_replicas(&self) -> usize {
+        self.num_of_replicas
+    }
+
+    fn get_genesis_index(&self) -> usize {
+        self.genesis_block_index
+    }
+}
+
+impl PBFTConsensusConfig {
+    pub fn new(
+        num_of_replicas: usize,
+        view_change_timeout: f64,
+        genesis_block_index: usize,
+    ) -> Self {
+        Self {
+            num_of_replicas,
+            view_change_timeout,
+            genesis_block_index,
+        }
+    }
+}
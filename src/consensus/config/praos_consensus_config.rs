@@ -0,0 +1,67 @@
+use crate::consensus::config::{ChainBasedConsensusConfig, ConsensusConfig};
+
+#[derive(Default, Debug, Clone)]
+pub struct PraosConsensusConfig {
+    /// Duration of a slot in seconds.
+    pub slot_duration: f64,
+    /// The active slot coefficient `f`: the probability that a slot has at
+    /// least one leader.
+    pub active_slot_coefficient: f64,
+    /// The settlement parameter `k`: a block with `k` blocks on top of it is
+    /// confirmed, and a node never rolls back more than `k` blocks.
+    pub settlement_depth: i32,
+    pub genesis_block_index: usize,
+    /// Stake of each stakeholder, indexed by node.
+    pub stakes: Vec<f64>,
+    pub total_stake: f64,
+}
+
+impl ConsensusConfig for PraosConsensusConfig {}
+
+impl ChainBasedConsensusConfig for PraosConsensusConfig {
+    /// Empty slots and slots with several leaders aside, a block is created
+    /// every `slot_duration / f` seconds.
+    fn get_average_block_mining_interval(&self) -> f64 {
+        self.slot_duration / self.active_slot_coefficient
+    }
+
+    fn set_average_block_mining_interval(&mut self, interval: f64) {
+        self.active_slot_coefficient = self.slot_duration / interval;
+    }
+
+    fn set_genesis_index(&mut self, index: usize) {
+        self.genesis_block_index = index;
+    }
+}
+
+impl PraosConsensusConfig {
+    pub fn new(
+        slot_duration: f64,
+        active_slot_coefficient: f64,
+        settlement_depth: i32,
+        genesis_block_index: usize,
+    ) -> Self {
+        Self {
+            slot_duration,
+            active_slot_coefficient,
+            settlement_depth,
+            genesis_block_index,
+            stakes: Vec::new(),
+            total_stake: 0.0,
+        }
+    }
+
+    pub fn set_stakes(&mut self, stakes: Vec<f64>) {
+        self.total_stake = stakes.iter().sum();
+        self.stakes = stakes;
+    }
+
+    /// Probability that a stakeholder wins the lottery of a slot,
+    /// `phi(alpha) = 1 - (1 - f)^alpha` with `alpha` its relative stake. The
+    /// lotteries of the stakeholders are independent, so the probability
+    /// that a slot has a leader does not depend on how the stake is split.
+    pub fn slot_leader_probability(&self, node: usize) -> f64 {
+        let relative_stake = self.stakes[node] / self.total_stake;
+        1.0 - (1.0 - self.active_slot_coefficient).powf(relative_stake)
+    }
+}
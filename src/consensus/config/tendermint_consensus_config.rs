@@ -0,0 +1,54 @@
+use crate::consensus::config::{BFTConsensusConfig, ConsensusConfig};
+
+#[derive(Default, Debug, Clone)]
+pub struct TendermintConsensusConfig {
+    pub num_of_replicas: usize,
+    /// Time in seconds a replica waits for the proposal of a round.
+    pub timeout_propose: f64,
+    /// Time in seconds a replica waits for more prevotes after receiving a
+    /// quorum of prevotes for different values.
+    pub timeout_prevote: f64,
+    /// Time in seconds a replica waits for more precommits after receiving a
+    /// quorum of precommits for different values, before the next round.
+    pub timeout_precommit: f64,
+    /// Increase of every timeout with each round of the same height.
+    pub timeout_delta: f64,
+    pub genesis_block_index: usize,
+}
+
+impl ConsensusConfig for TendermintConsensusConfig {}
+
+impl BFTConsensusConfig for TendermintConsensusConfig {
+    fn get_num_of_replicas(&self) -> usize {
+        self.num_of_replicas
+    }
+
+    fn get_genesis_index(&self) -> usize {
+        self.genesis_block_index
+    }
+}
+
+impl TendermintConsensusConfig {
+    pub fn new(
+        num_of_replicas: usize,
+        timeout_propose: f64,
+        timeout_prevote: f64,
+        timeout_precommit: f64,
+        timeout_delta: f64,
+        genesis_block_index: usize,
+    ) -> Self {
+        Self {
+            num_of_replicas,
+            timeout_propose,
+            timeout_prevote,
+            timeout_precommit,
+            timeout_delta,
+            genesis_block_index,
+        }
+    }
+
+    /// The proposer of a round, in round-robin order over heights and rounds.
+    pub fn get_proposer(&self, height: u64, round: u64) -> usize {
+        self.get_leader(height + round)
+    }
+}
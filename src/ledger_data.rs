@@ -1,5 +1,10 @@
+pub mod bft_block;
 pub mod bitcoin_block;
 pub mod block;
 pub mod block_factory;
+pub mod ethereum_block;
+pub mod ghostdag_block;
 pub mod pow;
+pub mod praos_block;
 pub mod single_parent;
+pub mod vote;
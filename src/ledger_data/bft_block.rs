@@ -0,0 +1,102 @@
+use crate::ledger_data::block::Block;
+use crate::ledger_data::single_parent::SingleParent;
+use std::cmp::Ordering;
+
+#[derive(Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct BFTBlock {
+    creation_time_int: u64, // to directly have Hash and Eq traits.
+    pub creator: Option<usize>,
+    pub height: i32,
+    pub parents: Vec<usize>,
+    pub size: u64,
+    /// The view (or round) in which the block is proposed.
+    pub view: u64,
+}
+
+impl PartialOrd for BFTBlock {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare the `height` of two blocks.
+impl Ord for BFTBlock {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.height.cmp(&other.height)
+    }
+}
+
+impl Block for BFTBlock {
+    /// returns the float value of creation time when needed.
+    fn get_creation_time(&self) -> f64 {
+        f64::from_bits(self.creation_time_int)
+    }
+    fn get_creator(&self) -> Option<usize> {
+        self.creator
+    }
+    fn get_height(&self) -> i32 {
+        self.height
+    }
+    fn get_size(&self) -> u64 {
+        self.size
+    }
+    fn get_parents(&self) -> &Vec<usize> {
+        &self.parents
+    }
+
+    fn set_creation_time(&mut self, creation_time: f64) {
+        self.creation_time_int = creation_time.to_bits();
+    }
+    fn set_creator(&mut self, creator: Option<usize>) {
+        self.creator = creator;
+    }
+    fn set_height(&mut self, height: i32) {
+        self.height = height;
+    }
+    fn set_size(&mut self, size: u64) {
+        self.size = size;
+    }
+    fn set_parents(&mut self, parents: Vec<usize>) {
+        self.parents = parents
+    }
+}
+
+impl SingleParent for BFTBlock {
+    fn get_single_parent(&self) -> Option<usize> {
+        if self.parents.is_empty() {
+            return None;
+        }
+        Some(self.parents[0])
+    }
+}
+
+impl BFTBlock {
+    pub(crate) fn generate_genesis_block() -> Self {
+        Self {
+            creation_time_int: 0.0_f64.to_bits(),
+            creator: None,
+            height: 0,
+            parents: Vec::new(),
+            size: 0,
+            view: 0,
+        }
+    }
+
+    pub fn new_with_parent(
+        creation_time: f64,
+        creator: Option<usize>,
+        height: i32,
+        parent: usize,
+        size: u64,
+        view: u64,
+    ) -> Self {
+        Self {
+            creation_time_int: creation_time.to_bits(),
+            creator,
+            height,
+            parents: vec![parent],
+            size,
+            view,
+        }
+    }
+}
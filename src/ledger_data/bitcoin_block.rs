@@ -9,9 +9,18 @@ pub struct BitcoinBlock {
     pub creator: Option<usize>,
     pub height: i32,
     pub parents: Vec<usize>,
+    /// The size the block is relayed with, which is the compact block size
+    /// for blocks of the block factory.
     pub size: u64,
+    /// The size of the block with all its transactions.
+    pub full_size: u64,
     difficulty_int: u64,
     weight_int: u64,
+    /// Number of key (proof-of-work) blocks in the chain up to this block.
+    /// Without Bitcoin-NG microblocks, it equals the height.
+    pub key_height: i32,
+    /// A Bitcoin-NG microblock, signed by the leader instead of mined.
+    pub is_microblock: bool,
 }
 
 impl PartialOrd for BitcoinBlock {
@@ -44,6 +53,9 @@ impl Block for BitcoinBlock {
     fn get_parents(&self) -> &Vec<usize> {
         &self.parents
     }
+    fn get_full_size(&self) -> u64 {
+        self.full_size
+    }
 
     fn set_creation_time(&mut self, creation_time: f64) {
         self.creation_time_int = creation_time.to_bits();
@@ -97,6 +109,9 @@ impl BitcoinBlock {
             height: 0,
             parents: Vec::new(),
             size: 0,
+            full_size: 0,
+            key_height: 0,
+            is_microblock: false,
         }
     }
 }
@@ -116,8 +131,11 @@ impl BitcoinBlock {
             height,
             parents: Vec::new(),
             size,
+            full_size: size,
             difficulty_int: difficulty.to_bits(),
             weight_int: weight.to_bits(),
+            key_height: height,
+            is_microblock: false,
         }
     }
 
@@ -135,4 +153,28 @@ impl BitcoinBlock {
 
         block
     }
+
+    /// Creates a Bitcoin-NG microblock of the leader on `parent`. It carries no
+    /// proof-of-work and keeps the key height of its parent.
+    pub fn new_microblock(
+        creation_time: f64,
+        leader: usize,
+        parent_index: usize,
+        parent: &BitcoinBlock,
+        size: u64,
+    ) -> Self {
+        let mut block = BitcoinBlock::new_with_parents(
+            creation_time,
+            Some(leader),
+            parent.height + 1,
+            vec![parent_index],
+            size,
+            0.0,
+            0.0,
+        );
+        block.key_height = parent.key_height;
+        block.is_microblock = true;
+
+        block
+    }
 }
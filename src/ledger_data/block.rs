@@ -8,6 +8,12 @@ pub trait Block: Eq + PartialEq + Ord + Hash {
     fn get_height(&self) -> i32;
     fn get_size(&self) -> u64;
     fn get_parents(&self) -> &Vec<usize>;
+    /// Returns the size of the block with all its transactions, which nodes
+    /// validate. It differs from [`Self::get_size`] for blocks relayed in a
+    /// compact form.
+    fn get_full_size(&self) -> u64 {
+        self.get_size()
+    }
 
     fn set_creation_time(&mut self, creation_time: f64);
     fn set_creator(&mut self, creator: Option<usize>);
@@ -1,19 +1,31 @@
 use crate::ledger_data::bitcoin_block::BitcoinBlock;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::BitcoinProfile;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
 
 pub const ETHEREUM_BLOCK_HEADER_SIZE: u64 = 543; // A header could have variable size but mostly its really close this value
 pub const ETHEREUM_BLOCK_HASH_SIZE: u64 = 36; // 32 byte hash + 4 byte network id
 pub const ETHEREUM_MIN_DIFFICULTY: f64 = 17_146_335_232.0;
+pub const ETHEREUM_ATTESTATION_SIZE: u64 = 229; // attestation data + aggregation bits + signature
+pub const ETHEREUM_AVERAGE_BLOCK_SIZE: u64 = 90_000;
+
+pub const BFT_VOTE_SIZE: u64 = 128; // view, height, type, block hash, voter and signature
+pub const BFT_AVERAGE_BLOCK_SIZE: u64 = 1_000_000; // comparable to a Bitcoin block
+
+pub const GHOSTDAG_AVERAGE_BLOCK_SIZE: u64 = 125_000; // 1 block per second, close to Kaspa
+pub const GHOSTDAG_PARENT_HASH_SIZE: u64 = 32;
+
+pub const PRAOS_AVERAGE_BLOCK_SIZE: u64 = 22_000; // close to Cardano
 
 pub const BITCOIN_BLOCK_HEADER_SIZE: u64 = 80;
 pub const BITCOIN_INV_SIZE: u64 = 36; // 4 byte type + 32 byte hash
 pub const GET_DATA_OVERHEAD: u64 = 4;
 pub const INV_MESSAGE_OVERHEAD: u64 = 1;
+pub const HEADERS_MESSAGE_OVERHEAD: u64 = 2; // 1 byte header count + 1 byte tx count
+pub const BITCOIN_NG_KEY_BLOCK_SIZE: u64 = 330; // header + leader public key + coinbase
+pub const BITCOIN_NG_MICROBLOCK_HEADER_SIZE: u64 = 152; // header + leader signature
 pub const COMPACT_REDUCTION_RATIO: f64 = 6.0 / 200.0;
 
-pub const FORK_LOGGER: bool = true;
-
 // pub const BITCOIN_COMPACT_BLOCK_SIZE_2020_BINS: [i64; 22] = [
 //     30, 3624, 7668, 11910, 16644, 21828, 27558, 30672, 31662, 32544, 33420, 34878, 35544, 36198,
 //     36840, 37476, 38130, 38838, 39630, 40674, 42732, 72714,
@@ -33,11 +45,8 @@ pub const BITCOIN_BLOCK_SIZE_2020: [f64; 23] = [
 pub struct BlockFactory;
 
 impl BlockFactory {
-    pub fn sample_bitcoin_block_size(rand: &mut RandomnessEngine) -> u64 {
-        rand.sample_from_distribution_with_bins(
-            &BITCOIN_BLOCK_SIZE_2020,
-            &BITCOIN_BLOCK_SIZE_2020_BINS,
-        ) as u64
+    pub fn sample_bitcoin_block_size(rand: &mut RandomnessEngine, profile: &BitcoinProfile) -> u64 {
+        profile.sample_block_size(rand)
     }
 
     pub fn sample_bitcoin_block(
@@ -46,31 +55,22 @@ impl BlockFactory {
         rand: &mut RandomnessEngine,
         creator: Option<usize>,
         parent: usize,
-        difficulty: f64,
+        profile: &BitcoinProfile,
         weight: f64,
     ) -> BitcoinBlock {
-        if FORK_LOGGER {
-            for (index, block) in blocks.iter().enumerate() {
-                if block.parents.contains(&parent) {
-                    println!(
-                        "A fork for the parent:{}, block1:{}, block2:{}",
-                        parent,
-                        index,
-                        blocks.len()
-                    );
-                }
-            }
-        }
-
-        BitcoinBlock::new_with_parents(
+        let full_size = BlockFactory::sample_bitcoin_block_size(rand, profile);
+        let mut block = BitcoinBlock::new_with_parents(
             simulator.simulation_time,
             creator,
             blocks[parent].height + 1,
             vec![parent],
-            BlockFactory::compact_size(BlockFactory::sample_bitcoin_block_size(rand)),
-            difficulty,
+            BlockFactory::compact_size(full_size),
+            profile.difficulty,
             weight,
-        )
+        );
+        block.full_size = full_size;
+        block.key_height = blocks[parent].key_height + 1;
+        block
     }
 
     fn compact_size(size: u64) -> u64 {
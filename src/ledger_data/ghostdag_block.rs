@@ -0,0 +1,126 @@
+use crate::ledger_data::block::Block;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// The GHOSTDAG coloring of the past of a block, computed by its miner from
+/// its parents.
+#[derive(Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct GhostDAGData {
+    /// Number of blue blocks in the past of the block.
+    pub blue_score: u64,
+    /// The parent with the highest blue score; `None` only for genesis.
+    pub selected_parent: Option<usize>,
+    /// Blue blocks of the mergeset (the past of the block which is not in the
+    /// past of its selected parent), starting with the selected parent and
+    /// then in topological order.
+    pub mergeset_blues: Vec<usize>,
+    /// Red blocks of the mergeset, in topological order.
+    pub mergeset_reds: Vec<usize>,
+    /// Sizes of the anticones of blue blocks within the blue set of the block,
+    /// for the blues whose anticone size is set or changed by this block:
+    /// <blue_block, anticone_size>
+    pub blues_anticone_sizes: BTreeMap<usize, usize>,
+}
+
+#[derive(Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct GhostDAGBlock {
+    creation_time_int: u64, // to directly have Hash and Eq traits.
+    pub creator: Option<usize>,
+    pub height: i32,
+    pub parents: Vec<usize>,
+    pub size: u64,
+    pub ghostdag_data: GhostDAGData,
+}
+
+impl PartialOrd for GhostDAGBlock {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare the `blue_score` of two blocks.
+impl Ord for GhostDAGBlock {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ghostdag_data
+            .blue_score
+            .cmp(&other.ghostdag_data.blue_score)
+    }
+}
+
+impl Block for GhostDAGBlock {
+    /// returns the float value of creation time when needed.
+    fn get_creation_time(&self) -> f64 {
+        f64::from_bits(self.creation_time_int)
+    }
+    fn get_creator(&self) -> Option<usize> {
+        self.creator
+    }
+    fn get_height(&self) -> i32 {
+        self.height
+    }
+    fn get_size(&self) -> u64 {
+        self.size
+    }
+    fn get_parents(&self) -> &Vec<usize> {
+        &self.parents
+    }
+
+    fn set_creation_time(&mut self, creation_time: f64) {
+        self.creation_time_int = creation_time.to_bits();
+    }
+    fn set_creator(&mut self, creator: Option<usize>) {
+        self.creator = creator;
+    }
+    fn set_height(&mut self, height: i32) {
+        self.height = height;
+    }
+    fn set_size(&mut self, size: u64) {
+        self.size = size;
+    }
+    fn set_parents(&mut self, parents: Vec<usize>) {
+        self.parents = parents
+    }
+}
+
+impl GhostDAGBlock {
+    pub(crate) fn generate_genesis_block() -> Self {
+        Self {
+            creation_time_int: 0.0_f64.to_bits(),
+            creator: None,
+            height: 0,
+            parents: Vec::new(),
+            size: 0,
+            ghostdag_data: GhostDAGData::default(),
+        }
+    }
+
+    /// Creates a block on all of `parents`. The height of the block is one
+    /// more than its highest parent.
+    pub fn new_with_parents(
+        creation_time: f64,
+        creator: Option<usize>,
+        parents: Vec<usize>,
+        size: u64,
+        ghostdag_data: GhostDAGData,
+        blocks: &[GhostDAGBlock],
+    ) -> Self {
+        let height = parents
+            .iter()
+            .map(|&parent| blocks[parent].height)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        Self {
+            creation_time_int: creation_time.to_bits(),
+            creator,
+            height,
+            parents,
+            size,
+            ghostdag_data,
+        }
+    }
+
+    pub fn blue_score(&self) -> u64 {
+        self.ghostdag_data.blue_score
+    }
+}
@@ -0,0 +1,94 @@
+/// An epoch boundary block used as a Casper FFG source or target.
+#[derive(Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub struct Checkpoint {
+    pub epoch: u64,
+    pub block_index: usize,
+}
+
+impl Checkpoint {
+    pub fn new(epoch: u64, block_index: usize) -> Self {
+        Self { epoch, block_index }
+    }
+}
+
+/// An attestation of a validator, including both its LMD-GHOST head vote and
+/// its Casper FFG source and target vote.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub struct Vote {
+    pub voter: usize,
+    pub slot: u64,
+    pub head: usize,
+    pub source: Checkpoint,
+    pub target: Checkpoint,
+}
+
+impl Vote {
+    pub fn new(
+        voter: usize,
+        slot: u64,
+        head: usize,
+        source: Checkpoint,
+        target: Checkpoint,
+    ) -> Self {
+        Self {
+            voter,
+            slot,
+            head,
+            source,
+            target,
+        }
+    }
+}
+
+/// The phase of a BFT protocol a vote belongs to.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum BFTVoteType {
+    /// A new block proposed by the leader (PBFT pre-prepare).
+    Proposal,
+    /// PBFT phases.
+    Prepare,
+    Commit,
+    ViewChange,
+    /// Tendermint phases.
+    Prevote,
+    Precommit,
+    /// A HotStuff vote, sent to the leader of the next view.
+    Vote,
+    /// A HotStuff timeout message, carrying the highest QC of the replica.
+    NewView,
+}
+
+/// A message of a BFT protocol. Proposals carry their block.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub struct BFTVote {
+    pub voter: usize,
+    /// The view (or round) of the vote.
+    pub view: u64,
+    /// The height (or sequence number) of the voted block.
+    pub height: u64,
+    pub vote_type: BFTVoteType,
+    pub block_index: Option<usize>,
+    /// The view of the certificate backing `block_index`, if any: the view in
+    /// which a PBFT view change vote prepared the block, or the valid round of
+    /// the block of a Tendermint proposal.
+    pub valid_view: Option<u64>,
+}
+
+impl BFTVote {
+    pub fn new(
+        voter: usize,
+        view: u64,
+        height: u64,
+        vote_type: BFTVoteType,
+        block_index: Option<usize>,
+    ) -> Self {
+        Self {
+            voter,
+            view,
+            height,
+            vote_type,
+            block_index,
+            valid_view: None,
+        }
+    }
+}
@@ -1,23 +1,62 @@
+pub mod bandwidth_logger;
+pub mod block_arrival_logger;
 pub mod block_confirmation_logger;
 pub mod block_generation_logger;
 pub mod block_propagation_delay_logger;
 pub mod blockchain_reorg_logger;
+pub mod blue_set_logger;
+pub mod chain_growth_logger;
+pub mod confirmation_latency_logger;
+pub mod dag_confirmation_time_logger;
+pub mod in_memory_sink;
+pub mod miner_fairness_logger;
+pub mod missed_slot_logger;
+pub mod node_type_filter;
+pub mod output_format;
+pub mod pool_work_switch_logger;
+pub mod record;
+pub mod stale_block_logger;
+pub mod time_to_finality_logger;
 
 use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::log::in_memory_sink::{DiscardingRecordWriter, InMemoryRecords};
+use crate::log::output_format::{OutputFormat, RecordWriter};
+use crate::log::record::{FinalRow, Record};
 use crate::network::message::MessageType;
+use crate::network::node::NodeType;
 use crate::scenario::ScenarioData;
-use csv::Writer;
-use std::fs::File;
 use std::path::Path;
 
 #[derive(Default, PartialEq)]
 pub enum EventLoggerInfo {
     IsBlockConfirmationEvent(usize, usize, f64), // block, node, time
+    IsBlockGenerationEvent(usize, usize, f64),   // block, creator, time
     IsReceiveEvent(usize, usize, usize, MessageType, f64), // block, from, node, msg_type, time
+    IsStratumWorkEvent(usize, usize, usize, f64), // pool, hasher, block, time
+    IsBlockValidationEvent(usize, usize, f64),   // block, node, time
+    IsSlotEvent(u64, usize, f64),                // slot, proposer, time
+    IsSamplingEvent(f64, f64),                   // interval, time
     #[default]
     NotLoggerEvent,
 }
 
+impl EventLoggerInfo {
+    /// Returns the node at which the event happens: the creator of a block,
+    /// the receiver of a message, the pool operator of a stratum work or the
+    /// proposer of a slot.
+    pub fn node_index(&self) -> Option<usize> {
+        match self {
+            Self::IsBlockConfirmationEvent(_, node, _)
+            | Self::IsBlockGenerationEvent(_, node, _)
+            | Self::IsReceiveEvent(_, _, node, _, _)
+            | Self::IsStratumWorkEvent(node, _, _, _)
+            | Self::IsBlockValidationEvent(_, node, _)
+            | Self::IsSlotEvent(_, node, _) => Some(*node),
+            Self::IsSamplingEvent(_, _) | Self::NotLoggerEvent => None,
+        }
+    }
+}
+
 pub trait CSVLogger {
     fn csv_starting_comment(&mut self, scenario_data: &ScenarioData) -> Vec<String> {
         scenario_data.into()
@@ -40,18 +79,29 @@ pub trait CSVLogger {
         false
     }
     fn csv_header_output(&self) -> Vec<String>;
-    fn csv_event_output(
-        &self,
-        info: &EventLoggerInfo,
-        network: &dyn NetworkLogHandler,
-    ) -> Vec<String>;
-    fn csv_node_output(&self, _node_index: usize) -> Vec<String> {
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record;
+    /// The interval in seconds between the [`SamplingEvent`]s the logger
+    /// needs, if it samples the network periodically.
+    ///
+    /// [`SamplingEvent`]: crate::simulator::event::sampling_event::SamplingEvent
+    fn sampling_interval(&self) -> Option<f64> {
+        None
+    }
+    fn csv_node_output(&self, _node_index: usize) -> Record {
+        Record::default()
+    }
+    /// Rows written once at the end of the simulation, after the records per
+    /// node.
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
         Vec::default()
     }
 }
 
 pub trait Logger {
     fn initial_log(&mut self, scenario: &ScenarioData) -> csv::Result<()>;
+    fn sampling_interval(&self) -> Option<f64> {
+        None
+    }
     fn log_before_each_event(
         &mut self,
         info: &EventLoggerInfo,
@@ -76,6 +126,62 @@ pub trait NetworkLogHandler {
     fn get_block_parents(&self, block_index: usize) -> &Vec<usize>;
     fn get_num_of_nodes(&self) -> usize;
 
+    /// Returns the size in bytes of a vote, by default of an Ethereum
+    /// attestation.
+    fn get_vote_size(&self, _vote_index: usize) -> u64 {
+        MessageType::VoteMessage.get_size_with_block_size(|| 0)
+    }
+
+    /// Returns the role of a node, for networks with several node types.
+    fn get_node_type(&self, _node_index: usize) -> Option<NodeType> {
+        None
+    }
+
+    /// Returns whether the events at a node are logged. Only the nodes of the
+    /// types of a [`NodeTypeFilter`] are, for the logger it wraps.
+    ///
+    /// [`NodeTypeFilter`]: crate::log::node_type_filter::NodeTypeFilter
+    fn is_node_logged(&self, _node_index: usize) -> bool {
+        true
+    }
+
+    /// Returns the hash power of a node, if it mines.
+    fn get_node_hash_power(&self, _node_index: usize) -> Option<f64> {
+        None
+    }
+
+    /// Returns the head of the canonical chain of a node, for chain-based
+    /// consensus algorithms.
+    fn get_canonical_chain_head(&self, _node_index: usize) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether a block is a Bitcoin-NG microblock, which is signed by
+    /// the leader of its epoch rather than mined.
+    fn is_microblock(&self, _block_index: usize) -> bool {
+        false
+    }
+
+    /// Returns the block proposed in a Proof-of-Stake slot, if any.
+    fn get_block_of_slot(&self, _slot: u64) -> Option<usize> {
+        None
+    }
+
+    // block DAG methods:
+    /// Returns the GHOSTDAG blue score of a block, if the ledger is a DAG.
+    fn get_block_blue_score(&self, _block_index: usize) -> Option<u64> {
+        None
+    }
+    /// Returns the number of blue and red blocks merged by a block.
+    fn get_block_mergeset_sizes(&self, _block_index: usize) -> Option<(usize, usize)> {
+        None
+    }
+    /// Returns the color of a block in the ordering of a node, `true` for
+    /// blue, once the node has ordered it.
+    fn is_block_blue(&self, _node_index: usize, _block_index: usize) -> Option<bool> {
+        None
+    }
+
     // reorg logger methods:
     fn block_reorg_before(
         &self,
@@ -97,32 +203,70 @@ pub trait NetworkLogHandler {
     }
 }
 
+/// Writes the records of a [`CSVLogger`] to a file, in an [`OutputFormat`], or
+/// keeps them in memory.
 pub struct EventLogger<C: CSVLogger> {
     csv_logger: C,
-    csv_writer: Writer<File>,
+    record_writer: Box<dyn RecordWriter>,
 }
 
 impl<C: CSVLogger> EventLogger<C> {
     pub fn from_path(path: &Path, csv_logger: C) -> csv::Result<Self> {
-        let csv_writer = Writer::from_path(path)?;
+        Ok(Self::from_path_with_format(
+            path,
+            csv_logger,
+            OutputFormat::Csv,
+        )?)
+    }
+
+    pub fn from_path_with_format(
+        path: &Path,
+        csv_logger: C,
+        output_format: OutputFormat,
+    ) -> std::io::Result<Self> {
         Ok(Self {
             csv_logger,
-            csv_writer,
+            record_writer: output_format.create_writer(path)?,
         })
     }
+
+    /// Keeps the records in memory instead of writing them to a file, e.g. for
+    /// tests. They are read through the returned [`InMemoryRecords`].
+    pub fn in_memory(csv_logger: C) -> (Self, InMemoryRecords) {
+        let records = InMemoryRecords::default();
+        let event_logger = Self {
+            csv_logger,
+            record_writer: records.create_writer(),
+        };
+        (event_logger, records)
+    }
+
+    /// Writes no records, for a logger whose typed events are read from an
+    /// [`InMemoryEvents`] it pushes them to.
+    ///
+    /// [`InMemoryEvents`]: crate::log::in_memory_sink::InMemoryEvents
+    pub fn without_records(csv_logger: C) -> Self {
+        Self {
+            csv_logger,
+            record_writer: Box::new(DiscardingRecordWriter),
+        }
+    }
 }
 
 impl<C: CSVLogger> Logger for EventLogger<C> {
     fn initial_log(&mut self, scenario: &ScenarioData) -> csv::Result<()> {
         // Write the comment as a regular record, starting with #
         let comment = self.csv_logger.csv_starting_comment(scenario);
-        self.csv_writer.write_record(&comment)?;
+        self.record_writer.write_comment(&comment)?;
 
         // Write the header
         let headers = self.csv_logger.csv_header_output();
-        self.csv_writer.write_record(&headers)?;
+        self.record_writer.write_header(&headers)?;
         Ok(())
     }
+    fn sampling_interval(&self) -> Option<f64> {
+        self.csv_logger.sampling_interval()
+    }
     fn log_before_each_event(
         &mut self,
         info: &EventLoggerInfo,
@@ -132,8 +276,8 @@ impl<C: CSVLogger> Logger for EventLogger<C> {
             .csv_logger
             .csv_output_condition_before_event(info, network)
         {
-            self.csv_writer
-                .write_record(self.csv_logger.csv_event_output(info, network))?;
+            self.record_writer
+                .write_record(&self.csv_logger.csv_event_output(info, network))?;
         }
         Ok(())
     }
@@ -146,8 +290,8 @@ impl<C: CSVLogger> Logger for EventLogger<C> {
             .csv_logger
             .csv_output_condition_after_event(info, network)
         {
-            self.csv_writer
-                .write_record(self.csv_logger.csv_event_output(info, network))?;
+            self.record_writer
+                .write_record(&self.csv_logger.csv_event_output(info, network))?;
         }
         Ok(())
     }
@@ -155,10 +299,16 @@ impl<C: CSVLogger> Logger for EventLogger<C> {
     fn final_log(&mut self, scenario_data: &ScenarioData) -> Result<(), std::io::Error> {
         if self.csv_logger.csv_output_condition_final_per_node() {
             for node in 0..scenario_data.num_of_nodes {
-                self.csv_writer
-                    .write_record(self.csv_logger.csv_node_output(node))?;
+                self.record_writer
+                    .write_record(&self.csv_logger.csv_node_output(node))?;
+            }
+        }
+        for row in self.csv_logger.csv_final_output() {
+            match row {
+                FinalRow::Record(record) => self.record_writer.write_record(&record)?,
+                FinalRow::Header(header) => self.record_writer.write_header(&header)?,
             }
         }
-        self.csv_writer.flush()
+        self.record_writer.finish()
     }
 }
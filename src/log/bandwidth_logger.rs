@@ -0,0 +1,162 @@
+use crate::log::record::{Field, FinalRow, Record};
+use crate::log::EventLoggerInfo::IsReceiveEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use crate::network::message::{DataType, MessageType};
+use std::collections::BTreeMap;
+
+/// The kinds of messages accounted separately.
+const MESSAGE_KINDS: [&str; 5] = ["Inv", "GetData", "Block", "Tx", "Vote"];
+
+/// Returns the index in [`MESSAGE_KINDS`] of a message. Block headers count as
+/// inv, as both announce a new block.
+fn message_kind(msg_type: &MessageType) -> usize {
+    match msg_type {
+        MessageType::InvMessage(_) | MessageType::HeadersMessage => 0,
+        MessageType::RequestDataMessage(_) => 1,
+        MessageType::DataMessage(DataType::IsBlock) => 2,
+        MessageType::DataMessage(DataType::IsTx) => 3,
+        MessageType::VoteMessage => 4,
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct Traffic {
+    bytes: u64,
+    messages: u64,
+}
+
+impl Traffic {
+    fn add(&mut self, size: u64) {
+        self.bytes += size;
+        self.messages += 1;
+    }
+}
+
+type TrafficPerKind = [Traffic; MESSAGE_KINDS.len()];
+
+#[derive(Default, Debug, Clone)]
+struct NodeTraffic {
+    sent: TrafficPerKind,
+    received: TrafficPerKind,
+}
+
+/// Counts the bytes and messages each node sends and receives, per kind of
+/// message, and writes them per node at the end of the run. Messages a node
+/// delivers to itself are not counted, nor the traffic of the nodes which are
+/// not logged.
+///
+/// With time buckets, the bytes and messages sent or received by the logged
+/// nodes in each bucket are written after the records per node, under their
+/// own header.
+#[derive(Default)]
+pub struct BandwidthLogger {
+    nodes: Vec<NodeTraffic>,
+    bucket_duration: Option<f64>,
+    buckets: BTreeMap<u64, TrafficPerKind>,
+}
+
+impl BandwidthLogger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also accounts the traffic of the whole network in buckets of
+    /// `bucket_duration` seconds.
+    pub fn new_with_time_buckets(bucket_duration: f64) -> Self {
+        Self {
+            bucket_duration: Some(bucket_duration),
+            ..Self::default()
+        }
+    }
+}
+
+impl CSVLogger for BandwidthLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsReceiveEvent(block_index, from, node, msg_type, time) = info {
+            if from == node {
+                return false;
+            }
+            if self.nodes.is_empty() {
+                self.nodes = vec![NodeTraffic::default(); network.get_num_of_nodes()];
+            }
+
+            let kind = message_kind(msg_type);
+            // the index of a vote message is the one of the vote.
+            let size = match msg_type {
+                MessageType::VoteMessage => network.get_vote_size(*block_index),
+                _ => msg_type.get_size_with_block_size(|| network.get_block_size(*block_index)),
+            };
+            let is_sender_logged = network.is_node_logged(*from);
+            let is_receiver_logged = network.is_node_logged(*node);
+            if is_sender_logged {
+                self.nodes[*from].sent[kind].add(size);
+            }
+            if is_receiver_logged {
+                self.nodes[*node].received[kind].add(size);
+            }
+            if !is_sender_logged && !is_receiver_logged {
+                return false;
+            }
+            if let Some(bucket_duration) = self.bucket_duration {
+                let bucket = (time / bucket_duration).floor() as u64;
+                self.buckets.entry(bucket).or_default()[kind].add(size);
+            }
+        }
+        false
+    }
+
+    fn csv_output_condition_final_per_node(&self) -> bool {
+        true
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let mut header = vec!["Node".to_string()];
+        for direction in ["Sent", "Received"] {
+            for kind in MESSAGE_KINDS {
+                header.push(format!("{kind}{direction}Bytes"));
+                header.push(format!("{kind}{direction}Messages"));
+            }
+        }
+        header
+    }
+
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        vec![Field::Null; 1 + 4 * MESSAGE_KINDS.len()]
+    }
+
+    fn csv_node_output(&self, node_index: usize) -> Record {
+        let node = self.nodes.get(node_index).cloned().unwrap_or_default();
+        let mut record: Record = vec![node_index.into()];
+        for traffic in node.sent.iter().chain(node.received.iter()) {
+            record.push(traffic.bytes.into());
+            record.push(traffic.messages.into());
+        }
+        record
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        let Some(bucket_duration) = self.bucket_duration else {
+            return Vec::default();
+        };
+
+        let mut header = vec!["BucketStartTime".to_string()];
+        for kind in MESSAGE_KINDS {
+            header.push(format!("{kind}Bytes"));
+            header.push(format!("{kind}Messages"));
+        }
+        let mut records = vec![FinalRow::Header(header)];
+        for (bucket, traffics) in &self.buckets {
+            let mut record: Record = vec![(*bucket as f64 * bucket_duration).into()];
+            for traffic in traffics {
+                record.push(traffic.bytes.into());
+                record.push(traffic.messages.into());
+            }
+            records.push(FinalRow::Record(record));
+        }
+        records
+    }
+}
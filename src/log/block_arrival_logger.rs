@@ -0,0 +1,187 @@
+use crate::log::block_propagation_delay_logger::{num_of_full_block_nodes, num_of_nodes_for_share};
+use crate::log::in_memory_sink::InMemoryEvents;
+use crate::log::record::{Field, FinalRow, Record};
+use crate::log::EventLoggerInfo::{IsBlockGenerationEvent, IsReceiveEvent};
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use crate::network::message::{DataType, MessageType};
+use crate::scenario::simulation_result::{BlockPropagation, PROPAGATION_DELAY_COLUMN_PREFIX};
+use std::collections::{BTreeMap, HashSet};
+
+/// The shares of the nodes at which the propagation delay of a block is
+/// written by default.
+pub const DEFAULT_PROPAGATION_SHARES: [f64; 7] = [0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+
+struct BlockArrivals {
+    creation_time: f64,
+    height: i32,
+    creator: Option<usize>,
+    size: u64,
+    received_by: HashSet<usize>,
+    /// The nodes in the order they received the block, with the time.
+    arrivals: Vec<(usize, f64)>,
+}
+
+/// Records when each node receives each block, and writes at the end of the
+/// run the propagation curve of every block: its propagation delay when each
+/// of the given shares of the nodes has received it. The creator receives its
+/// block with no delay, and the shares are of the logged nodes receiving full
+/// blocks, so without the SPV clients. The delay is missing if the share is never
+/// reached.
+///
+/// With node arrivals, the time each node received each block is written too,
+/// after the propagation curves and under its own header.
+pub struct BlockArrivalLogger {
+    shares: Vec<f64>,
+    with_node_arrivals: bool,
+    propagation_sink: Option<InMemoryEvents<BlockPropagation>>,
+    num_of_full_block_nodes: Option<usize>,
+    blocks: BTreeMap<usize, BlockArrivals>,
+}
+
+impl Default for BlockArrivalLogger {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROPAGATION_SHARES.to_vec())
+    }
+}
+
+impl BlockArrivalLogger {
+    pub fn new(shares: Vec<f64>) -> Self {
+        Self {
+            shares,
+            with_node_arrivals: false,
+            propagation_sink: None,
+            num_of_full_block_nodes: None,
+            blocks: BTreeMap::new(),
+        }
+    }
+
+    /// Also writes the arrival time of every block at every node.
+    pub fn with_node_arrivals(mut self) -> Self {
+        self.with_node_arrivals = true;
+        self
+    }
+
+    /// Also pushes the propagation of every block to the sink at the end of
+    /// the run.
+    pub fn with_propagation_sink(mut self, sink: InMemoryEvents<BlockPropagation>) -> Self {
+        self.propagation_sink = Some(sink);
+        self
+    }
+
+    /// Returns the propagation of every block received so far, in block
+    /// order.
+    pub fn block_propagations(&self) -> Vec<BlockPropagation> {
+        self.blocks
+            .iter()
+            .map(|(block_index, block)| BlockPropagation {
+                block_index: *block_index,
+                height: block.height,
+                creator: block.creator,
+                size: block.size,
+                creation_time: block.creation_time,
+                propagation_delays: self
+                    .shares
+                    .iter()
+                    .map(|share| {
+                        let num_of_nodes = num_of_nodes_for_share(
+                            self.num_of_full_block_nodes.unwrap_or_default(),
+                            *share,
+                        );
+                        let delay = num_of_nodes
+                            .checked_sub(1)
+                            .and_then(|i| block.arrivals.get(i))
+                            .map(|(_, time)| time - block.creation_time);
+                        (*share, delay)
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl CSVLogger for BlockArrivalLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        let (block, node, time) = match info {
+            IsReceiveEvent(block, _, node, MessageType::DataMessage(DataType::IsBlock), time) => {
+                (block, node, time)
+            }
+            IsBlockGenerationEvent(block, creator, time) => (block, creator, time),
+            _ => return false,
+        };
+        if !network.is_node_logged(*node) {
+            return false;
+        }
+        self.num_of_full_block_nodes
+            .get_or_insert_with(|| num_of_full_block_nodes(network));
+        let block_arrivals = self.blocks.entry(*block).or_insert_with(|| BlockArrivals {
+            creation_time: network.get_block_creation_time(*block),
+            height: network.get_block_height(*block),
+            creator: network.get_block_creator(*block),
+            size: network.get_block_size(*block),
+            received_by: HashSet::new(),
+            arrivals: Vec::new(),
+        });
+        if block_arrivals.received_by.insert(*node) {
+            block_arrivals.arrivals.push((*node, *time));
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let mut header: Vec<String> = vec![
+            "BlockIndex",
+            "BlockHeight",
+            "BlockCreator",
+            "BlockSize",
+            "CreationTime",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        for share in &self.shares {
+            header.push(format!("{PROPAGATION_DELAY_COLUMN_PREFIX}{share}"));
+        }
+        header
+    }
+
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        vec![Field::Null; 5 + self.shares.len()]
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        let block_propagations = self.block_propagations();
+        let mut records: Vec<FinalRow> = block_propagations
+            .iter()
+            .map(|propagation| FinalRow::Record(propagation.to_record()))
+            .collect();
+        if let Some(sink) = &self.propagation_sink {
+            for propagation in block_propagations {
+                sink.push(propagation);
+            }
+        }
+
+        if self.with_node_arrivals {
+            records.push(FinalRow::header(&[
+                "BlockIndex",
+                "Node",
+                "ArrivalTime",
+                "PropagationDelay",
+            ]));
+            for (block_index, block) in &self.blocks {
+                for (node, time) in &block.arrivals {
+                    records.push(FinalRow::Record(vec![
+                        block_index.into(),
+                        node.into(),
+                        time.into(),
+                        (time - block.creation_time).into(),
+                    ]));
+                }
+            }
+        }
+        records
+    }
+}
@@ -1,3 +1,4 @@
+use crate::log::record::{Field, Record};
 use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
 
 #[derive(Default)]
@@ -24,25 +25,18 @@ impl CSVLogger for BlockConfirmationLogger {
         header_str.into_iter().map(String::from).collect()
     }
 
-    fn csv_event_output(
-        &self,
-        info: &EventLoggerInfo,
-        network: &dyn NetworkLogHandler,
-    ) -> Vec<String> {
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
         if let EventLoggerInfo::IsBlockConfirmationEvent(block_index, node_index, time) = info {
             vec![
-                time.to_string(),
-                node_index.to_string(),
-                network.get_block_height(*block_index).to_string(),
-                network.get_block_size(*block_index).to_string(),
-                network.get_block_creation_time(*block_index).to_string(),
-                network
-                    .get_block_creator(*block_index)
-                    .map(|c| c.to_string())
-                    .unwrap_or("None".to_string()),
+                time.into(),
+                node_index.into(),
+                network.get_block_height(*block_index).into(),
+                network.get_block_size(*block_index).into(),
+                network.get_block_creation_time(*block_index).into(),
+                network.get_block_creator(*block_index).into(),
             ]
         } else {
-            vec![String::new(); 6]
+            vec![Field::Null; 6]
         }
     }
 }
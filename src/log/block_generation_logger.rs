@@ -1,3 +1,70 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsBlockGenerationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::HashSet;
+
+/// Logs every created block. A block forks when one of its parents already
+/// had a child, i.e. it has a sibling.
+#[derive(Default)]
 pub struct BlockGenerationLogger {
-    // csv_logger:
+    parents_with_child: HashSet<usize>,
+    is_fork: bool,
+}
+
+impl BlockGenerationLogger {
+    pub fn new() -> Self {
+        BlockGenerationLogger::default()
+    }
+}
+
+impl CSVLogger for BlockGenerationLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsBlockGenerationEvent(block_index, _, _) = info {
+            self.is_fork = false;
+            for &parent in network.get_block_parents(*block_index) {
+                // not short-circuited, as every parent gets a child.
+                self.is_fork |= !self.parents_with_child.insert(parent);
+            }
+            return true;
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "BlockIndex",
+            "BlockHeight",
+            "BlockCreator",
+            "BlockSize",
+            "Parents",
+            "IsFork",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsBlockGenerationEvent(block_index, node_index, time) = info {
+            let parents: Vec<String> = network
+                .get_block_parents(*block_index)
+                .iter()
+                .map(|parent| parent.to_string())
+                .collect();
+            vec![
+                time.into(),
+                block_index.into(),
+                network.get_block_height(*block_index).into(),
+                node_index.into(),
+                network.get_block_size(*block_index).into(),
+                parents.join(";").into(),
+                self.is_fork.into(),
+            ]
+        } else {
+            vec![Field::Null; 7]
+        }
+    }
 }
@@ -1,10 +1,34 @@
-use crate::log::EventLoggerInfo::IsReceiveEvent;
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::{IsBlockGenerationEvent, IsReceiveEvent};
 use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
 use crate::network::message::{DataType, MessageType};
+use crate::network::node::NodeType;
 use std::collections::{HashMap, HashSet};
 
+/// Returns the number of nodes needed to reach `share` of the `num_of_nodes`
+/// nodes: the share rounded up, at least one node and at most all of them.
+pub(crate) fn num_of_nodes_for_share(num_of_nodes: usize, share: f64) -> usize {
+    // the tolerance keeps shares like 0.1 * 30 = 3.0000000000000004 at 3.
+    let num_of_nodes_for_share = (num_of_nodes as f64 * share - 1e-9).ceil();
+    (num_of_nodes_for_share.max(1.0) as usize).min(num_of_nodes)
+}
+
+/// Returns the number of logged nodes which receive full blocks, the SPV
+/// clients only receiving their headers.
+pub(crate) fn num_of_full_block_nodes(network: &dyn NetworkLogHandler) -> usize {
+    (0..network.get_num_of_nodes())
+        .filter(|node| {
+            network.is_node_logged(*node)
+                && !matches!(network.get_node_type(*node), Some(NodeType::SPVClient))
+        })
+        .count()
+}
+
+/// Writes a row for each block when the given share of the nodes receiving
+/// full blocks has received it, its creator included.
 pub struct BlockPropagationDelayLogger {
     shared_of_nodes_received_block: f64,
+    num_of_full_block_nodes: Option<usize>,
     received_by: HashMap<usize, HashSet<usize>>, // <block_index, Set<node_index>>
 }
 
@@ -12,6 +36,7 @@ impl BlockPropagationDelayLogger {
     pub fn new(shared_of_nodes_received_block: f64) -> Self {
         Self {
             shared_of_nodes_received_block,
+            num_of_full_block_nodes: None,
             received_by: HashMap::new(),
         }
     }
@@ -23,19 +48,27 @@ impl CSVLogger for BlockPropagationDelayLogger {
         info: &EventLoggerInfo,
         network: &dyn NetworkLogHandler,
     ) -> bool {
-        if let IsReceiveEvent(block, _, node, MessageType::DataMessage(DataType::IsBlock), _) = info
-        {
-            if self.received_by.contains_key(block) {
-                self.received_by.get_mut(block).unwrap().insert(*node);
-            } else {
-                self.received_by.insert(*block, HashSet::new());
-                self.received_by.get_mut(block).unwrap().insert(*node);
+        let (block, node) = match info {
+            IsReceiveEvent(block, _, node, MessageType::DataMessage(DataType::IsBlock), _) => {
+                (block, node)
             }
-            let exact_number =
-                (network.get_num_of_nodes() as f64) * self.shared_of_nodes_received_block;
-            return self.received_by.get(block).unwrap().len() == (exact_number as usize);
+            // the creator has the block from its creation.
+            IsBlockGenerationEvent(block, creator, _) => (block, creator),
+            _ => return false,
+        };
+        if !network.is_node_logged(*node) {
+            return false;
         }
-        false
+        // a block received again by a node does not write the row twice.
+        let received_by = self.received_by.entry(*block).or_default();
+        if !received_by.insert(*node) {
+            return false;
+        }
+        let num_of_full_block_nodes = *self
+            .num_of_full_block_nodes
+            .get_or_insert_with(|| num_of_full_block_nodes(network));
+        received_by.len()
+            == num_of_nodes_for_share(num_of_full_block_nodes, self.shared_of_nodes_received_block)
     }
 
     fn csv_header_output(&self) -> Vec<String> {
@@ -50,24 +83,19 @@ impl CSVLogger for BlockPropagationDelayLogger {
         header_str.into_iter().map(String::from).collect()
     }
 
-    fn csv_event_output(
-        &self,
-        info: &EventLoggerInfo,
-        network: &dyn NetworkLogHandler,
-    ) -> Vec<String> {
-        if let IsReceiveEvent(block_index, _, _, _, time) = info {
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsReceiveEvent(block_index, _, _, _, time)
+        | IsBlockGenerationEvent(block_index, _, time) = info
+        {
             return vec![
-                time.to_string(),
-                (time - network.get_block_creation_time(*block_index)).to_string(),
-                block_index.to_string(),
-                network.get_block_height(*block_index).to_string(),
-                network
-                    .get_block_creator(*block_index)
-                    .map(|c| c.to_string())
-                    .unwrap_or("None".to_string()),
-                network.get_block_size(*block_index).to_string(),
+                time.into(),
+                (time - network.get_block_creation_time(*block_index)).into(),
+                block_index.into(),
+                network.get_block_height(*block_index).into(),
+                network.get_block_creator(*block_index).into(),
+                network.get_block_size(*block_index).into(),
             ];
         }
-        vec![String::new(); 6]
+        vec![Field::Null; 6]
     }
 }
@@ -1,7 +1,10 @@
 use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::log::in_memory_sink::InMemoryEvents;
+use crate::log::record::{Field, Record};
 use crate::log::EventLoggerInfo::IsReceiveEvent;
 use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
 use crate::network::message::{DataType, MessageType};
+use crate::scenario::simulation_result::ReorgEvent;
 
 #[derive(Default)]
 pub struct BlockchainReorgLogger {
@@ -9,12 +12,21 @@ pub struct BlockchainReorgLogger {
     pub(crate) new_block_received: bool,
     pub(crate) previous_head_chain_index: Option<usize>,
     pub(crate) current_node_index: Option<usize>,
+    /// The reorg caused by the current event, if any.
+    reorg_event: Option<ReorgEvent>,
+    reorg_sink: Option<InMemoryEvents<ReorgEvent>>,
 }
 
 impl BlockchainReorgLogger {
     pub fn new() -> Self {
         BlockchainReorgLogger::default()
     }
+
+    /// Also pushes every reorg to the sink, as it happens.
+    pub fn with_reorg_sink(mut self, sink: InMemoryEvents<ReorgEvent>) -> Self {
+        self.reorg_sink = Some(sink);
+        self
+    }
 }
 
 impl CSVLogger for BlockchainReorgLogger {
@@ -32,10 +44,30 @@ impl CSVLogger for BlockchainReorgLogger {
 
     fn csv_output_condition_after_event(
         &mut self,
-        _: &EventLoggerInfo,
+        info: &EventLoggerInfo,
         network: &dyn NetworkLogHandler,
     ) -> bool {
-        network.block_reorg_after(self)
+        self.reorg_event = None;
+        if !network.block_reorg_after(self) {
+            return false;
+        }
+        if let (IsReceiveEvent(block_index, _, node_index, _, time), Some(previous_head)) =
+            (info, self.previous_head_chain_index)
+        {
+            let reorg_event = ReorgEvent {
+                time: *time,
+                node_index: *node_index,
+                block_height: network.get_block_height(*block_index),
+                block_creation_time: network.get_block_creation_time(*block_index),
+                block_creator: network.get_block_creator(*block_index),
+                reorg_length: network.block_reorg_output_length(self, previous_head, node_index),
+            };
+            if let Some(sink) = &self.reorg_sink {
+                sink.push(reorg_event.clone());
+            }
+            self.reorg_event = Some(reorg_event);
+        }
+        true
     }
 
     fn csv_header_output(&self) -> Vec<String> {
@@ -50,29 +82,10 @@ impl CSVLogger for BlockchainReorgLogger {
         header_str.into_iter().map(String::from).collect()
     }
 
-    fn csv_event_output(
-        &self,
-        info: &EventLoggerInfo,
-        network: &dyn NetworkLogHandler,
-    ) -> Vec<String> {
-        if let IsReceiveEvent(block_index, _, node_index, _, time) = info {
-            if let Some(previous_head) = self.previous_head_chain_index {
-                let reorg_length =
-                    network.block_reorg_output_length(&self, previous_head, node_index);
-
-                return vec![
-                    time.to_string(),
-                    node_index.to_string(),
-                    network.get_block_height(*block_index).to_string(),
-                    network.get_block_creation_time(*block_index).to_string(),
-                    network
-                        .get_block_creator(*block_index)
-                        .map(|c| c.to_string())
-                        .unwrap_or("None".to_string()),
-                    reorg_length.to_string(),
-                ];
-            }
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        match &self.reorg_event {
+            Some(reorg_event) => reorg_event.to_record(),
+            None => vec![Field::Null; 6],
         }
-        vec![String::new(); 6]
     }
 }
@@ -0,0 +1,56 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsBlockConfirmationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::HashSet;
+
+/// Logs the GHOSTDAG data of each block when it is first confirmed by any
+/// node: its blue score, the sizes of the blue and red sets it merges, and its
+/// color in the ordering of that node.
+#[derive(Default)]
+pub struct BlueSetLogger {
+    logged_blocks: HashSet<usize>,
+}
+
+impl CSVLogger for BlueSetLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        _: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsBlockConfirmationEvent(block, _, _) = info {
+            return self.logged_blocks.insert(*block);
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "BlockIndex",
+            "BlockCreator",
+            "NumOfParents",
+            "BlueScore",
+            "MergesetBlues",
+            "MergesetReds",
+            "IsBlue",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsBlockConfirmationEvent(block_index, node_index, time) = info {
+            let mergeset_sizes = network.get_block_mergeset_sizes(*block_index);
+            return vec![
+                time.into(),
+                block_index.into(),
+                network.get_block_creator(*block_index).into(),
+                network.get_block_parents(*block_index).len().into(),
+                network.get_block_blue_score(*block_index).into(),
+                mergeset_sizes.map(|(blues, _)| blues).into(),
+                mergeset_sizes.map(|(_, reds)| reds).into(),
+                network.is_block_blue(*node_index, *block_index).into(),
+            ];
+        }
+        vec![Field::Null; 8]
+    }
+}
@@ -0,0 +1,86 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsSamplingEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::HashMap;
+
+/// Samples the canonical chain heads of all the nodes every `interval`
+/// seconds, to follow how the network converges: the number of distinct
+/// heads, the highest and median head heights, the fraction of nodes on the
+/// most common head and how many blocks the least synced node is behind the
+/// highest head. Nodes without a chain-based consensus are not sampled.
+pub struct ChainGrowthLogger {
+    interval: f64,
+}
+
+impl ChainGrowthLogger {
+    pub fn new(interval: f64) -> Self {
+        Self { interval }
+    }
+}
+
+impl CSVLogger for ChainGrowthLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        _network: &dyn NetworkLogHandler,
+    ) -> bool {
+        matches!(info, IsSamplingEvent(interval, _) if *interval == self.interval)
+    }
+
+    fn sampling_interval(&self) -> Option<f64> {
+        Some(self.interval)
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "NumOfHeads",
+            "MaxHeadHeight",
+            "MedianHeadHeight",
+            "MajorityHeadFraction",
+            "LeastSyncedNodeLag",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        let IsSamplingEvent(_, time) = info else {
+            return vec![Field::Null; 6];
+        };
+
+        let mut nodes_on_head: HashMap<usize, usize> = HashMap::new();
+        let mut head_heights = Vec::new();
+        for node in 0..network.get_num_of_nodes() {
+            if let Some(head) = network.get_canonical_chain_head(node) {
+                *nodes_on_head.entry(head).or_default() += 1;
+                head_heights.push(network.get_block_height(head));
+            }
+        }
+        if head_heights.is_empty() {
+            let mut record: Record = vec![time.into(), 0_usize.into()];
+            record.resize(6, Field::Null);
+            return record;
+        }
+
+        head_heights.sort_unstable();
+        let num_of_sampled_nodes = head_heights.len();
+        let max_height = head_heights[num_of_sampled_nodes - 1];
+        let min_height = head_heights[0];
+        let median_height = if num_of_sampled_nodes % 2 == 0 {
+            let upper = num_of_sampled_nodes / 2;
+            (head_heights[upper - 1] + head_heights[upper]) as f64 / 2.0
+        } else {
+            head_heights[num_of_sampled_nodes / 2] as f64
+        };
+        let nodes_on_majority_head = nodes_on_head.values().max().copied().unwrap_or_default();
+
+        vec![
+            time.into(),
+            nodes_on_head.len().into(),
+            max_height.into(),
+            median_height.into(),
+            (nodes_on_majority_head as f64 / num_of_sampled_nodes as f64).into(),
+            (max_height - min_height).into(),
+        ]
+    }
+}
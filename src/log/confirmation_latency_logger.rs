@@ -0,0 +1,226 @@
+use crate::log::record::{Field, FinalRow, Record};
+use crate::log::EventLoggerInfo::IsBlockConfirmationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashSet};
+
+struct BlockConfirmations {
+    height: i32,
+    creator: Option<usize>,
+    creation_time: f64,
+    /// The time each node first confirmed the block, also when it confirmed it
+    /// again after a reorg.
+    confirmation_times: BTreeMap<usize, f64>,
+}
+
+/// Aggregates the confirmations of all the logged nodes instead of writing
+/// one row for each of them. At the end of the run, writes for each block the delays
+/// after its creation at which the first, the median and the last node
+/// confirmed it. The median is over all the logged nodes and is empty until
+/// more than half of them confirmed the block. Then, under their own headers, the histogram of the
+/// confirmation latencies of all the nodes and the confirmed blocks which
+/// were later reorged out, which are safety violations.
+///
+/// A confirmed block confirms its ancestors too, through their first parent.
+/// Reorgs of confirmed blocks are only tracked in networks with chain-based
+/// consensus, where a node confirming a block off the chain of the block it
+/// confirmed before has reorged it out.
+pub struct ConfirmationLatencyLogger {
+    histogram_bin_width: f64,
+    num_of_logged_nodes: usize,
+    blocks: BTreeMap<usize, BlockConfirmations>,
+    confirmed_by: Vec<HashSet<usize>>,
+    /// The highest block each node has confirmed.
+    confirmed_tips: Vec<Option<usize>>,
+    histogram: BTreeMap<u64, usize>,
+    /// The block, the node and the time of each confirmed block reorged out.
+    reorged_confirmed_blocks: Vec<(usize, usize, f64)>,
+}
+
+impl ConfirmationLatencyLogger {
+    /// The confirmation latencies are counted in bins of
+    /// `histogram_bin_width` seconds.
+    pub fn new(histogram_bin_width: f64) -> Self {
+        Self {
+            histogram_bin_width,
+            num_of_logged_nodes: 0,
+            blocks: BTreeMap::new(),
+            confirmed_by: Vec::new(),
+            confirmed_tips: Vec::new(),
+            histogram: BTreeMap::new(),
+            reorged_confirmed_blocks: Vec::new(),
+        }
+    }
+
+    fn confirm(&mut self, block: usize, node: usize, time: f64, network: &dyn NetworkLogHandler) {
+        let confirmation = self
+            .blocks
+            .entry(block)
+            .or_insert_with(|| BlockConfirmations {
+                height: network.get_block_height(block),
+                creator: network.get_block_creator(block),
+                creation_time: network.get_block_creation_time(block),
+                confirmation_times: BTreeMap::new(),
+            });
+        let Entry::Vacant(entry) = confirmation.confirmation_times.entry(node) else {
+            return;
+        };
+        entry.insert(time);
+        let latency = time - confirmation.creation_time;
+        let bin = (latency / self.histogram_bin_width).floor() as u64;
+        *self.histogram.entry(bin).or_default() += 1;
+    }
+
+    /// Records the reorg of the blocks confirmed by the node from its previous
+    /// confirmed tip down to the block where the new confirmed chain forks.
+    fn reorg_confirmed_blocks(
+        &mut self,
+        previous_tip: usize,
+        fork: Option<usize>,
+        node: usize,
+        time: f64,
+        network: &dyn NetworkLogHandler,
+    ) {
+        let fork_height = fork.map(|fork| network.get_block_height(fork));
+        let mut block = previous_tip;
+        while Some(block) != fork
+            && fork_height.is_none_or(|height| network.get_block_height(block) > height)
+        {
+            // the genesis block is never confirmed.
+            let Some(parent) = network.get_block_parents(block).first() else {
+                break;
+            };
+            self.confirmed_by[node].remove(&block);
+            self.reorged_confirmed_blocks.push((block, node, time));
+            block = *parent;
+        }
+    }
+}
+
+impl CSVLogger for ConfirmationLatencyLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsBlockConfirmationEvent(block, node, time) = info {
+            if !network.is_node_logged(*node) {
+                return false;
+            }
+            if self.confirmed_by.is_empty() {
+                self.confirmed_by = vec![HashSet::new(); network.get_num_of_nodes()];
+                self.confirmed_tips = vec![None; network.get_num_of_nodes()];
+                self.num_of_logged_nodes = (0..network.get_num_of_nodes())
+                    .filter(|node| network.is_node_logged(*node))
+                    .count();
+            }
+
+            // confirms the block and its ancestors not confirmed yet, up to the
+            // genesis block, which is not counted.
+            let mut newly_confirmed = Vec::new();
+            let mut fork = None;
+            let mut ancestor = *block;
+            loop {
+                let parents = network.get_block_parents(ancestor);
+                if parents.is_empty() {
+                    break;
+                }
+                if self.confirmed_by[*node].contains(&ancestor) {
+                    fork = Some(ancestor);
+                    break;
+                }
+                newly_confirmed.push(ancestor);
+                ancestor = parents[0];
+            }
+            if newly_confirmed.is_empty() {
+                return false;
+            }
+
+            if network.get_canonical_chain_head(*node).is_some() {
+                if let Some(previous_tip) = self.confirmed_tips[*node] {
+                    if fork != Some(previous_tip) {
+                        self.reorg_confirmed_blocks(previous_tip, fork, *node, *time, network);
+                    }
+                }
+            }
+            self.confirmed_tips[*node] = Some(*block);
+            for confirmed_block in newly_confirmed.into_iter().rev() {
+                self.confirmed_by[*node].insert(confirmed_block);
+                self.confirm(confirmed_block, *node, *time, network);
+            }
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "BlockIndex",
+            "BlockHeight",
+            "BlockCreator",
+            "BlockCreationTime",
+            "NumOfConfirmingNodes",
+            "FirstConfirmationDelay",
+            "MedianConfirmationDelay",
+            "LastConfirmationDelay",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        vec![Field::Null; 8]
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        let mut records = Vec::new();
+        for (block_index, block) in &self.blocks {
+            let mut delays: Vec<f64> = block
+                .confirmation_times
+                .values()
+                .map(|time| time - block.creation_time)
+                .collect();
+            delays.sort_by(f64::total_cmp);
+            let num_of_nodes = delays.len();
+            // the nodes which have not confirmed the block are the slowest.
+            let median = self.num_of_logged_nodes / 2;
+            let median_delay = if num_of_nodes <= median {
+                None
+            } else if self.num_of_logged_nodes % 2 == 0 {
+                Some((delays[median - 1] + delays[median]) / 2.0)
+            } else {
+                Some(delays[median])
+            };
+            records.push(FinalRow::Record(vec![
+                block_index.into(),
+                block.height.into(),
+                block.creator.into(),
+                block.creation_time.into(),
+                num_of_nodes.into(),
+                delays[0].into(),
+                median_delay.into(),
+                delays[num_of_nodes - 1].into(),
+            ]));
+        }
+
+        records.push(FinalRow::header(&["LatencyBinStart", "Count"]));
+        for (bin, count) in &self.histogram {
+            records.push(FinalRow::Record(vec![
+                (*bin as f64 * self.histogram_bin_width).into(),
+                count.into(),
+            ]));
+        }
+
+        records.push(FinalRow::header(&[
+            "ReorgedConfirmedBlock",
+            "NodeIndex",
+            "Time",
+        ]));
+        for (block, node, time) in &self.reorged_confirmed_blocks {
+            records.push(FinalRow::Record(vec![
+                block.into(),
+                node.into(),
+                time.into(),
+            ]));
+        }
+        records
+    }
+}
@@ -0,0 +1,66 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsBlockConfirmationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::{HashMap, HashSet};
+
+/// Logs the time from the creation of a block until it is confirmed by a given
+/// share of all nodes. In a block DAG every block is confirmed, blue or red,
+/// when a chain block merging it is deep enough; its color is logged from the
+/// ordering of the node completing the share.
+pub struct DAGConfirmationTimeLogger {
+    share_of_nodes_confirmed_block: f64,
+    confirmed_by: HashMap<usize, HashSet<usize>>, // <block_index, Set<node_index>>
+}
+
+impl DAGConfirmationTimeLogger {
+    pub fn new(share_of_nodes_confirmed_block: f64) -> Self {
+        Self {
+            share_of_nodes_confirmed_block,
+            confirmed_by: HashMap::new(),
+        }
+    }
+}
+
+impl CSVLogger for DAGConfirmationTimeLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsBlockConfirmationEvent(block, node, _) = info {
+            let confirmed_by = self.confirmed_by.entry(*block).or_default();
+            let required_nodes = ((network.get_num_of_nodes() as f64)
+                * self.share_of_nodes_confirmed_block)
+                .ceil()
+                .max(1.0) as usize;
+            return confirmed_by.insert(*node) && confirmed_by.len() == required_nodes;
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "ConfirmationTime",
+            "BlockIndex",
+            "BlueScore",
+            "IsBlue",
+            "BlockCreator",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsBlockConfirmationEvent(block_index, node_index, time) = info {
+            return vec![
+                time.into(),
+                (time - network.get_block_creation_time(*block_index)).into(),
+                block_index.into(),
+                network.get_block_blue_score(*block_index).into(),
+                network.is_block_blue(*node_index, *block_index).into(),
+                network.get_block_creator(*block_index).into(),
+            ];
+        }
+        vec![Field::Null; 6]
+    }
+}
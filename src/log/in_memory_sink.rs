@@ -0,0 +1,138 @@
+use crate::log::output_format::RecordWriter;
+use crate::log::record::Record;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The records of a table, under their header.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RecordTable {
+    pub header: Vec<String>,
+    pub records: Vec<Record>,
+}
+
+/// The records written by an in-memory [`EventLogger`], shared with the
+/// logger so they can be read after the run of the scenario owning it. The
+/// first table is under the header of the logger, and each header written at
+/// the end of the simulation starts a new table. The starting comment is not
+/// kept.
+///
+/// [`EventLogger`]: crate::log::EventLogger
+#[derive(Default, Debug, Clone)]
+pub struct InMemoryRecords {
+    tables: Rc<RefCell<Vec<RecordTable>>>,
+}
+
+impl InMemoryRecords {
+    /// Returns a copy of the tables written so far.
+    pub fn tables(&self) -> Vec<RecordTable> {
+        self.tables.borrow().clone()
+    }
+
+    /// Takes the tables written so far, leaving none.
+    pub fn take(&self) -> Vec<RecordTable> {
+        self.tables.take()
+    }
+
+    pub(crate) fn create_writer(&self) -> Box<dyn RecordWriter> {
+        Box::new(InMemoryRecordWriter {
+            tables: Rc::clone(&self.tables),
+        })
+    }
+}
+
+/// The typed events pushed by a logger, e.g. the [`BlockPropagation`]s of a
+/// [`BlockArrivalLogger`], shared with the logger so they can be read after
+/// the run without going through its records.
+///
+/// [`BlockPropagation`]: crate::scenario::simulation_result::BlockPropagation
+/// [`BlockArrivalLogger`]: crate::log::block_arrival_logger::BlockArrivalLogger
+#[derive(Debug)]
+pub struct InMemoryEvents<T> {
+    events: Rc<RefCell<Vec<T>>>,
+}
+
+impl<T> Default for InMemoryEvents<T> {
+    fn default() -> Self {
+        Self {
+            events: Rc::default(),
+        }
+    }
+}
+
+impl<T> Clone for InMemoryEvents<T> {
+    fn clone(&self) -> Self {
+        Self {
+            events: Rc::clone(&self.events),
+        }
+    }
+}
+
+impl<T: Clone> InMemoryEvents<T> {
+    /// Returns a copy of the events pushed so far.
+    pub fn events(&self) -> Vec<T> {
+        self.events.borrow().clone()
+    }
+}
+
+impl<T> InMemoryEvents<T> {
+    /// Takes the events pushed so far, leaving none.
+    pub fn take(&self) -> Vec<T> {
+        self.events.take()
+    }
+
+    pub(crate) fn push(&self, event: T) {
+        self.events.borrow_mut().push(event);
+    }
+}
+
+/// Drops the records, for a logger only read through its [`InMemoryEvents`].
+pub(crate) struct DiscardingRecordWriter;
+
+impl RecordWriter for DiscardingRecordWriter {
+    fn write_comment(&mut self, _: &[String]) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_header(&mut self, _: &[String]) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, _: &Record) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct InMemoryRecordWriter {
+    tables: Rc<RefCell<Vec<RecordTable>>>,
+}
+
+impl RecordWriter for InMemoryRecordWriter {
+    fn write_comment(&mut self, _: &[String]) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_header(&mut self, header: &[String]) -> std::io::Result<()> {
+        self.tables.borrow_mut().push(RecordTable {
+            header: header.to_vec(),
+            records: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &Record) -> std::io::Result<()> {
+        let mut tables = self.tables.borrow_mut();
+        if tables.is_empty() {
+            tables.push(RecordTable::default());
+        }
+        tables.last_mut().unwrap().records.push(record.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::log::record::{Field, FinalRow, Record};
+use crate::log::stale_block_logger::{MainChainTracker, MainChainView};
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::BTreeMap;
+
+/// Attributes the blocks of the main chain to their miners at the end of the
+/// run, and writes for each miner its share of the main chain next to its
+/// share of the hash power, and their ratio. Miners with hash power but no
+/// block on the main chain are written too.
+///
+/// The records of the miners are followed by the Gini coefficients of the
+/// block shares and of the hash power shares, under their own header. A
+/// block share Gini above the hash power one means the network favors some
+/// miners, e.g. the large or well connected ones.
+///
+/// Only the logged miners are written, their shares staying of the whole
+/// network. With Bitcoin-NG, the shares are of the key blocks, since the
+/// microblocks are signed by the leaders rather than mined.
+#[derive(Default)]
+pub struct MinerFairnessLogger {
+    main_chain_tracker: MainChainTracker,
+    /// Hash power of each logged mining node, read at the first event.
+    hash_power: BTreeMap<usize, f64>,
+    total_hash_power: f64,
+    hash_power_read: bool,
+}
+
+impl MinerFairnessLogger {
+    pub fn new(view: MainChainView) -> Self {
+        Self {
+            main_chain_tracker: MainChainTracker::new(view),
+            ..Self::default()
+        }
+    }
+}
+
+/// Returns the Gini coefficient of the values, from 0 if they are all equal
+/// to 1 if a single one is nonzero.
+pub(crate) fn gini_coefficient(values: &[f64]) -> f64 {
+    let total: f64 = values.iter().sum();
+    if values.is_empty() || total <= 0.0 {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n = sorted.len() as f64;
+    let weighted_sum: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (i + 1) as f64 * value)
+        .sum();
+    2.0 * weighted_sum / (n * total) - (n + 1.0) / n
+}
+
+impl CSVLogger for MinerFairnessLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if !self.hash_power_read {
+            self.hash_power_read = true;
+            for node in 0..network.get_num_of_nodes() {
+                if let Some(hash_power) = network.get_node_hash_power(node) {
+                    self.total_hash_power += hash_power;
+                    if network.is_node_logged(node) {
+                        self.hash_power.insert(node, hash_power);
+                    }
+                }
+            }
+        }
+        self.main_chain_tracker.update(info, network);
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Miner",
+            "HashPower",
+            "HashPowerShare",
+            "MainChainBlocks",
+            "BlockShare",
+            "BlockShareToHashPowerShare",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        vec![Field::Null; 6]
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        let mined_blocks: Vec<_> = self
+            .main_chain_tracker
+            .main_chain()
+            .iter()
+            .map(|block_index| self.main_chain_tracker.blocks[block_index])
+            .filter(|block| !block.is_microblock)
+            .collect();
+        let mut main_chain_blocks: BTreeMap<usize, usize> =
+            self.hash_power.keys().map(|miner| (*miner, 0)).collect();
+        for block in &mined_blocks {
+            if block.is_logged {
+                *main_chain_blocks.entry(block.creator).or_default() += 1;
+            }
+        }
+
+        let num_of_main_chain_blocks = mined_blocks.len();
+        let mut block_shares = Vec::new();
+        let mut hash_power_shares = Vec::new();
+        let mut records = Vec::new();
+        for (miner, num_of_blocks) in main_chain_blocks {
+            let block_share = if num_of_main_chain_blocks == 0 {
+                0.0
+            } else {
+                num_of_blocks as f64 / num_of_main_chain_blocks as f64
+            };
+            block_shares.push(block_share);
+
+            let mut record: Record = vec![miner.into()];
+            match self.hash_power.get(&miner) {
+                Some(hash_power) => {
+                    let hash_power_share = hash_power / self.total_hash_power;
+                    hash_power_shares.push(hash_power_share);
+                    record.push(hash_power.into());
+                    record.push(hash_power_share.into());
+                    record.push(num_of_blocks.into());
+                    record.push(block_share.into());
+                    record.push((block_share / hash_power_share).into());
+                }
+                None => {
+                    record.extend([Field::Null, Field::Null]);
+                    record.push(num_of_blocks.into());
+                    record.push(block_share.into());
+                    record.push(Field::Null);
+                }
+            }
+            records.push(FinalRow::Record(record));
+        }
+
+        records.push(FinalRow::header(&["BlockShareGini", "HashPowerShareGini"]));
+        records.push(FinalRow::Record(vec![
+            gini_coefficient(&block_shares).into(),
+            gini_coefficient(&hash_power_shares).into(),
+        ]));
+        records
+    }
+}
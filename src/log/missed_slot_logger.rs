@@ -0,0 +1,33 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsSlotEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+
+/// Logs the Proof-of-Stake slots in which no block was proposed, e.g. because
+/// the proposer was offline.
+#[derive(Default)]
+pub struct MissedSlotLogger;
+
+impl CSVLogger for MissedSlotLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsSlotEvent(slot, _, _) = info {
+            return network.get_block_of_slot(*slot).is_none();
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec!["Time", "Slot", "Proposer"];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, _network: &dyn NetworkLogHandler) -> Record {
+        if let IsSlotEvent(slot, proposer, time) = info {
+            return vec![time.into(), slot.into(), proposer.into()];
+        }
+        vec![Field::Null; 3]
+    }
+}
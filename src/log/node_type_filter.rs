@@ -0,0 +1,181 @@
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::log::record::{FinalRow, Record};
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use crate::network::node::NodeType;
+use crate::scenario::ScenarioData;
+
+/// Wraps a [`CSVLogger`] to only write the events happening at nodes of the
+/// given types, e.g. the block confirmations seen by SPV clients. Nodes of a
+/// network without node types match no type.
+///
+/// The loggers aggregating the events until the end of the run only count the
+/// nodes of the given types too: the stale blocks and main chain shares of
+/// their miners, the arrivals, confirmations and traffic at them. The rows
+/// written per node at the end of the simulation are not filtered, the nodes
+/// of other types having nothing counted.
+pub struct NodeTypeFilter<C: CSVLogger> {
+    csv_logger: C,
+    node_types: Vec<NodeType>,
+}
+
+/// The network seen by the wrapped logger, where only the nodes of the types
+/// are logged.
+struct NodeTypeView<'a> {
+    network: &'a dyn NetworkLogHandler,
+    node_types: &'a [NodeType],
+}
+
+impl NetworkLogHandler for NodeTypeView<'_> {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        self.network.get_block_creation_time(block_index)
+    }
+    fn get_block_creator(&self, block_index: usize) -> Option<usize> {
+        self.network.get_block_creator(block_index)
+    }
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        self.network.get_block_height(block_index)
+    }
+    fn get_block_size(&self, block_index: usize) -> u64 {
+        self.network.get_block_size(block_index)
+    }
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        self.network.get_block_parents(block_index)
+    }
+    fn get_num_of_nodes(&self) -> usize {
+        self.network.get_num_of_nodes()
+    }
+    fn get_vote_size(&self, vote_index: usize) -> u64 {
+        self.network.get_vote_size(vote_index)
+    }
+    fn get_node_type(&self, node_index: usize) -> Option<NodeType> {
+        self.network.get_node_type(node_index)
+    }
+    fn is_node_logged(&self, node_index: usize) -> bool {
+        self.network.is_node_logged(node_index)
+            && self
+                .network
+                .get_node_type(node_index)
+                .is_some_and(|node_type| self.node_types.contains(&node_type))
+    }
+    fn get_node_hash_power(&self, node_index: usize) -> Option<f64> {
+        self.network.get_node_hash_power(node_index)
+    }
+    fn get_canonical_chain_head(&self, node_index: usize) -> Option<usize> {
+        self.network.get_canonical_chain_head(node_index)
+    }
+    fn is_microblock(&self, block_index: usize) -> bool {
+        self.network.is_microblock(block_index)
+    }
+    fn get_block_of_slot(&self, slot: u64) -> Option<usize> {
+        self.network.get_block_of_slot(slot)
+    }
+    fn get_block_blue_score(&self, block_index: usize) -> Option<u64> {
+        self.network.get_block_blue_score(block_index)
+    }
+    fn get_block_mergeset_sizes(&self, block_index: usize) -> Option<(usize, usize)> {
+        self.network.get_block_mergeset_sizes(block_index)
+    }
+    fn is_block_blue(&self, node_index: usize, block_index: usize) -> Option<bool> {
+        self.network.is_block_blue(node_index, block_index)
+    }
+    fn block_reorg_before(
+        &self,
+        reorg_logger: &mut BlockchainReorgLogger,
+        block_index: &usize,
+        node_index: &usize,
+    ) {
+        self.network
+            .block_reorg_before(reorg_logger, block_index, node_index)
+    }
+    fn block_reorg_after(&self, reorg_logger: &mut BlockchainReorgLogger) -> bool {
+        self.network.block_reorg_after(reorg_logger)
+    }
+    fn block_reorg_output_length(
+        &self,
+        reorg_logger: &BlockchainReorgLogger,
+        previous_head: usize,
+        node_index: &usize,
+    ) -> i32 {
+        self.network
+            .block_reorg_output_length(reorg_logger, previous_head, node_index)
+    }
+}
+
+impl<C: CSVLogger> NodeTypeFilter<C> {
+    pub fn new(csv_logger: C, node_types: Vec<NodeType>) -> Self {
+        Self {
+            csv_logger,
+            node_types,
+        }
+    }
+
+    /// Events which do not happen at a node are left to the wrapped logger.
+    fn is_node_type_logged(info: &EventLoggerInfo, view: &NodeTypeView) -> bool {
+        info.node_index()
+            .is_none_or(|node| view.is_node_logged(node))
+    }
+}
+
+impl<C: CSVLogger> CSVLogger for NodeTypeFilter<C> {
+    fn csv_starting_comment(&mut self, scenario_data: &ScenarioData) -> Vec<String> {
+        self.csv_logger.csv_starting_comment(scenario_data)
+    }
+
+    // the wrapped logger sees every event, as it may keep state across them,
+    // but only the nodes of the types are logged in its view of the network.
+    fn csv_output_condition_before_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        let view = NodeTypeView {
+            network,
+            node_types: &self.node_types,
+        };
+        self.csv_logger
+            .csv_output_condition_before_event(info, &view)
+            && Self::is_node_type_logged(info, &view)
+    }
+
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        let view = NodeTypeView {
+            network,
+            node_types: &self.node_types,
+        };
+        self.csv_logger
+            .csv_output_condition_after_event(info, &view)
+            && Self::is_node_type_logged(info, &view)
+    }
+
+    fn csv_output_condition_final_per_node(&self) -> bool {
+        self.csv_logger.csv_output_condition_final_per_node()
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        self.csv_logger.csv_header_output()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        let view = NodeTypeView {
+            network,
+            node_types: &self.node_types,
+        };
+        self.csv_logger.csv_event_output(info, &view)
+    }
+
+    fn sampling_interval(&self) -> Option<f64> {
+        self.csv_logger.sampling_interval()
+    }
+
+    fn csv_node_output(&self, node_index: usize) -> Record {
+        self.csv_logger.csv_node_output(node_index)
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        self.csv_logger.csv_final_output()
+    }
+}
@@ -0,0 +1,200 @@
+//! The output formats of the loggers. Only row-oriented text formats are
+//! supported: columnar binary formats such as Parquet or Arrow IPC would need
+//! the `arrow`/`parquet` crates, which are not dependencies of this crate, so
+//! [`OutputFormat::ColumnarJson`] stands in for them.
+
+use crate::log::record::{Field, Record};
+use csv::{Writer, WriterBuilder};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// The format of the file an [`EventLogger`] writes.
+///
+/// [`EventLogger`]: crate::log::EventLogger
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// A header row, then a row per record. The starting comment is the first
+    /// row.
+    #[default]
+    Csv,
+    /// A JSON object per line and per record, keyed by the header. The
+    /// starting comment is not written.
+    JsonLines,
+    /// A JSON array with an object per table, mapping each column of the
+    /// header to the array of its values. The starting comment is not
+    /// written.
+    ///
+    /// All the records are kept in memory until the end of the simulation,
+    /// so the memory used grows with the length of the log; prefer
+    /// [`Self::JsonLines`] for long runs logging every event.
+    ColumnarJson,
+}
+
+impl OutputFormat {
+    pub(crate) fn create_writer(&self, path: &Path) -> std::io::Result<Box<dyn RecordWriter>> {
+        Ok(match self {
+            Self::Csv => Box::new(CsvRecordWriter {
+                // flexible, as the starting comment and the records may differ in length.
+                csv_writer: WriterBuilder::new().flexible(true).from_path(path)?,
+            }),
+            Self::JsonLines => Box::new(JsonLinesRecordWriter {
+                writer: BufWriter::new(File::create(path)?),
+                header: Vec::new(),
+            }),
+            Self::ColumnarJson => Box::new(ColumnarJsonRecordWriter {
+                writer: BufWriter::new(File::create(path)?),
+                tables: Vec::new(),
+            }),
+        })
+    }
+}
+
+/// Writes the rows of a logger in an [`OutputFormat`]. A header written after
+/// records starts a new table.
+pub(crate) trait RecordWriter {
+    fn write_comment(&mut self, comment: &[String]) -> std::io::Result<()>;
+    fn write_header(&mut self, header: &[String]) -> std::io::Result<()>;
+    fn write_record(&mut self, record: &Record) -> std::io::Result<()>;
+    fn finish(&mut self) -> std::io::Result<()>;
+}
+
+struct CsvRecordWriter {
+    csv_writer: Writer<File>,
+}
+
+impl RecordWriter for CsvRecordWriter {
+    fn write_comment(&mut self, comment: &[String]) -> std::io::Result<()> {
+        Ok(self.csv_writer.write_record(comment)?)
+    }
+
+    fn write_header(&mut self, header: &[String]) -> std::io::Result<()> {
+        Ok(self.csv_writer.write_record(header)?)
+    }
+
+    fn write_record(&mut self, record: &Record) -> std::io::Result<()> {
+        Ok(self
+            .csv_writer
+            .write_record(record.iter().map(|field| field.to_string()))?)
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        self.csv_writer.flush()
+    }
+}
+
+struct JsonLinesRecordWriter {
+    writer: BufWriter<File>,
+    header: Vec<String>,
+}
+
+impl RecordWriter for JsonLinesRecordWriter {
+    fn write_comment(&mut self, _: &[String]) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_header(&mut self, header: &[String]) -> std::io::Result<()> {
+        self.header = header.to_vec();
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &Record) -> std::io::Result<()> {
+        let mut line = String::from("{");
+        for (i, (column, field)) in self.header.iter().zip(record).enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            push_json_string(&mut line, column);
+            line.push(':');
+            push_json_field(&mut line, field);
+        }
+        line.push_str("}\n");
+        self.writer.write_all(line.as_bytes())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+struct ColumnarJsonRecordWriter {
+    writer: BufWriter<File>,
+    tables: Vec<(Vec<String>, Vec<Record>)>,
+}
+
+impl RecordWriter for ColumnarJsonRecordWriter {
+    fn write_comment(&mut self, _: &[String]) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_header(&mut self, header: &[String]) -> std::io::Result<()> {
+        self.tables.push((header.to_vec(), Vec::new()));
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &Record) -> std::io::Result<()> {
+        if self.tables.is_empty() {
+            self.tables.push((Vec::new(), Vec::new()));
+        }
+        self.tables.last_mut().unwrap().1.push(record.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        let mut json = String::from("[");
+        for (i, (header, records)) in self.tables.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push('{');
+            for (j, column) in header.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                push_json_string(&mut json, column);
+                json.push_str(":[");
+                for (k, record) in records.iter().enumerate() {
+                    if k > 0 {
+                        json.push(',');
+                    }
+                    push_json_field(&mut json, record.get(j).unwrap_or(&Field::Null));
+                }
+                json.push(']');
+            }
+            json.push('}');
+        }
+        json.push_str("]\n");
+        self.writer.write_all(json.as_bytes())?;
+        self.writer.flush()
+    }
+}
+
+/// Appends the field as a JSON value. Infinite and NaN floats are `null`.
+pub(crate) fn push_json_field(json: &mut String, field: &Field) {
+    match field {
+        Field::Float(value) if !value.is_finite() => json.push_str("null"),
+        Field::Null => json.push_str("null"),
+        Field::Str(value) => push_json_string(json, value),
+        Field::Bool(_) | Field::Int(_) | Field::UInt(_) | Field::Float(_) => {
+            json.push_str(&field.to_string())
+        }
+    }
+}
+
+/// Appends the value as a JSON string, escaping the quotes, the backslashes
+/// and the control characters.
+pub(crate) fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
@@ -0,0 +1,47 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsStratumWorkEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+
+/// Logs each time a hasher of a mining pool switches to new work, together
+/// with the delay between the creation of the block and the work switch.
+#[derive(Default)]
+pub struct PoolWorkSwitchLogger;
+
+impl CSVLogger for PoolWorkSwitchLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        _: &dyn NetworkLogHandler,
+    ) -> bool {
+        matches!(info, IsStratumWorkEvent(..))
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "PoolIndex",
+            "HasherIndex",
+            "BlockHeight",
+            "BlockCreationTime",
+            "BlockCreator",
+            "WorkSwitchDelay",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsStratumWorkEvent(pool, hasher, block_index, time) = info {
+            let creation_time = network.get_block_creation_time(*block_index);
+            return vec![
+                time.into(),
+                pool.into(),
+                hasher.into(),
+                network.get_block_height(*block_index).into(),
+                creation_time.into(),
+                network.get_block_creator(*block_index).into(),
+                (time - creation_time).into(),
+            ];
+        }
+        vec![Field::Null; 7]
+    }
+}
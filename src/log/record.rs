@@ -0,0 +1,145 @@
+use std::fmt::{Display, Formatter};
+
+/// A typed field of a logged record, so the output formats which keep types
+/// do not have to parse them back from text.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum Field {
+    /// A missing value, e.g. the creator of the genesis block.
+    #[default]
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+}
+
+/// A logged record, with a field for each column of the header.
+pub type Record = Vec<Field>;
+
+/// A row written at the end of the simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FinalRow {
+    /// A record of the current table.
+    Record(Record),
+    /// The header of a new table, for the records after it.
+    Header(Vec<String>),
+}
+
+impl FinalRow {
+    pub fn header(header: &[&str]) -> Self {
+        Self::Header(header.iter().map(|column| column.to_string()).collect())
+    }
+}
+
+impl Field {
+    /// Returns the number in the field, if any.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(value) => Some(*value as f64),
+            Self::UInt(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer in the field, if any.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            Self::UInt(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the nonnegative integer in the field, if any.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Int(value) => u64::try_from(*value).ok(),
+            Self::UInt(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the field as text, as in CSV. A missing value is written `None`.
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "None"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::UInt(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Str(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<bool> for Field {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for Field {
+    fn from(value: i32) -> Self {
+        Self::Int(value as i64)
+    }
+}
+
+impl From<i64> for Field {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<u64> for Field {
+    fn from(value: u64) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<usize> for Field {
+    fn from(value: usize) -> Self {
+        Self::UInt(value as u64)
+    }
+}
+
+impl From<f64> for Field {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<String> for Field {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl<T: Into<Field>> From<Option<T>> for Field {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+macro_rules! impl_from_reference {
+    ($($t:ty),*) => {
+        $(
+            impl From<&$t> for Field {
+                fn from(value: &$t) -> Self {
+                    (*value).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_reference!(bool, i32, i64, u64, usize, f64);
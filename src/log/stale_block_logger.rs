@@ -0,0 +1,203 @@
+use crate::log::record::{Field, FinalRow, Record};
+use crate::log::EventLoggerInfo::IsBlockGenerationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// The view of the main chain at the end of the run.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainChainView {
+    /// The chain of the highest created block, the first one on ties.
+    #[default]
+    Omniscient,
+    /// The canonical chain of a reference node, from its consensus. Falls back
+    /// to the omniscient view for networks without chain-based consensus.
+    Node(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GeneratedBlock {
+    pub(crate) parent: Option<usize>,
+    pub(crate) height: i32,
+    pub(crate) creator: usize,
+    /// Whether the creator is logged, so the block counted.
+    pub(crate) is_logged: bool,
+    pub(crate) is_microblock: bool,
+}
+
+/// Keeps the created blocks and the head of the main chain in a
+/// [`MainChainView`], for the loggers which need the main chain at the end of
+/// the run.
+#[derive(Default)]
+pub(crate) struct MainChainTracker {
+    view: MainChainView,
+    /// The created blocks, by index, so parents come before children.
+    pub(crate) blocks: BTreeMap<usize, GeneratedBlock>,
+    omniscient_head: Option<usize>,
+    node_head: Option<usize>,
+}
+
+impl MainChainTracker {
+    pub(crate) fn new(view: MainChainView) -> Self {
+        Self {
+            view,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn update(&mut self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) {
+        if let IsBlockGenerationEvent(block_index, creator, _) = info {
+            let height = network.get_block_height(*block_index);
+            self.blocks.insert(
+                *block_index,
+                GeneratedBlock {
+                    parent: network.get_block_parents(*block_index).first().copied(),
+                    height,
+                    creator: *creator,
+                    is_logged: network.is_node_logged(*creator),
+                    is_microblock: network.is_microblock(*block_index),
+                },
+            );
+            if self
+                .omniscient_head
+                .is_none_or(|head| height > self.blocks[&head].height)
+            {
+                self.omniscient_head = Some(*block_index);
+            }
+        }
+        if let MainChainView::Node(node) = self.view {
+            if info.node_index() == Some(node) {
+                if let Some(head) = network.get_canonical_chain_head(node) {
+                    self.node_head = Some(head);
+                }
+            }
+        }
+    }
+
+    /// Returns the created blocks on the main chain.
+    pub(crate) fn main_chain(&self) -> HashSet<usize> {
+        let head = match self.view {
+            MainChainView::Node(_) => self.node_head.or(self.omniscient_head),
+            MainChainView::Omniscient => self.omniscient_head,
+        };
+        let mut main_chain = HashSet::new();
+        let mut block = head;
+        while let Some(block_index) = block {
+            let Some(generated) = self.blocks.get(&block_index) else {
+                break;
+            };
+            main_chain.insert(block_index);
+            block = generated.parent;
+        }
+        main_chain
+    }
+}
+
+/// Writes a summary of the stale blocks at the end of the run: the total,
+/// main chain and stale blocks, the stale rate, the histogram of fork lengths
+/// and the stale blocks of each miner. A fork is a branch of stale blocks off
+/// the main chain, and its length is the number of blocks of its longest
+/// chain. Blocks with several parents are followed through their first one.
+///
+/// Only the blocks of logged miners are counted, while the main chain and the
+/// forks are of all the blocks.
+pub struct StaleBlockLogger {
+    main_chain_tracker: MainChainTracker,
+}
+
+impl StaleBlockLogger {
+    pub fn new(view: MainChainView) -> Self {
+        Self {
+            main_chain_tracker: MainChainTracker::new(view),
+        }
+    }
+}
+
+impl CSVLogger for StaleBlockLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        self.main_chain_tracker.update(info, network);
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec!["Metric", "Key", "Value"];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, _: &EventLoggerInfo, _: &dyn NetworkLogHandler) -> Record {
+        vec![Field::Null; 3]
+    }
+
+    fn csv_final_output(&mut self) -> Vec<FinalRow> {
+        let main_chain = self.main_chain_tracker.main_chain();
+        let blocks = &self.main_chain_tracker.blocks;
+
+        // blocks come in creation order, so parents before children.
+        let mut fork_of_block: HashMap<usize, usize> = HashMap::new();
+        let mut fork_lengths: BTreeMap<usize, i32> = BTreeMap::new();
+        let mut miner_blocks: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+        let mut num_of_blocks: usize = 0;
+        let mut num_of_main_chain_blocks: usize = 0;
+        for (&block_index, block) in blocks {
+            let is_stale = !main_chain.contains(&block_index);
+            if block.is_logged {
+                let (created, stale) = miner_blocks.entry(block.creator).or_default();
+                *created += 1;
+                num_of_blocks += 1;
+                if is_stale {
+                    *stale += 1;
+                } else {
+                    num_of_main_chain_blocks += 1;
+                }
+            }
+            if !is_stale {
+                continue;
+            }
+
+            let fork = block
+                .parent
+                .and_then(|parent| fork_of_block.get(&parent).copied())
+                .unwrap_or(block_index);
+            fork_of_block.insert(block_index, fork);
+            let length = block.height - blocks[&fork].height + 1;
+            let fork_length = fork_lengths.entry(fork).or_default();
+            *fork_length = (*fork_length).max(length);
+        }
+
+        let num_of_stale_blocks = num_of_blocks - num_of_main_chain_blocks;
+        let stale_rate = if num_of_blocks == 0 {
+            0.0
+        } else {
+            num_of_stale_blocks as f64 / num_of_blocks as f64
+        };
+        let mut fork_length_histogram: BTreeMap<i32, usize> = BTreeMap::new();
+        for length in fork_lengths.into_values() {
+            *fork_length_histogram.entry(length).or_default() += 1;
+        }
+
+        let row = |metric: &str, key: Field, value: Field| {
+            FinalRow::Record(vec![metric.into(), key, value])
+        };
+        let mut records = vec![
+            row("TotalBlocks", Field::Null, num_of_blocks.into()),
+            row(
+                "MainChainBlocks",
+                Field::Null,
+                num_of_main_chain_blocks.into(),
+            ),
+            row("StaleBlocks", Field::Null, num_of_stale_blocks.into()),
+            row("StaleRate", Field::Null, stale_rate.into()),
+        ];
+        for (length, count) in fork_length_histogram {
+            records.push(row("ForkLength", length.into(), count.into()));
+        }
+        for (miner, (created, stale)) in miner_blocks {
+            records.push(row("MinerBlocks", miner.into(), created.into()));
+            records.push(row("MinerStaleBlocks", miner.into(), stale.into()));
+        }
+        records
+    }
+}
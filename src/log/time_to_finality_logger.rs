@@ -0,0 +1,63 @@
+use crate::log::record::{Field, Record};
+use crate::log::EventLoggerInfo::IsBlockConfirmationEvent;
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use std::collections::{HashMap, HashSet};
+
+/// Logs the time from the creation of a checkpoint block until it is finalized
+/// by a given share of all nodes. Finalizing a checkpoint also finalizes its
+/// ancestors, which are not logged separately.
+pub struct TimeToFinalityLogger {
+    shared_of_nodes_finalized_block: f64,
+    finalized_by: HashMap<usize, HashSet<usize>>, // <block_index, Set<node_index>>
+}
+
+impl TimeToFinalityLogger {
+    pub fn new(shared_of_nodes_finalized_block: f64) -> Self {
+        Self {
+            shared_of_nodes_finalized_block,
+            finalized_by: HashMap::new(),
+        }
+    }
+}
+
+impl CSVLogger for TimeToFinalityLogger {
+    fn csv_output_condition_after_event(
+        &mut self,
+        info: &EventLoggerInfo,
+        network: &dyn NetworkLogHandler,
+    ) -> bool {
+        if let IsBlockConfirmationEvent(block, node, _) = info {
+            let finalized_by = self.finalized_by.entry(*block).or_default();
+            let required_nodes = ((network.get_num_of_nodes() as f64)
+                * self.shared_of_nodes_finalized_block)
+                .ceil()
+                .max(1.0) as usize;
+            return finalized_by.insert(*node) && finalized_by.len() == required_nodes;
+        }
+        false
+    }
+
+    fn csv_header_output(&self) -> Vec<String> {
+        let header_str = vec![
+            "Time",
+            "TimeToFinality",
+            "BlockIndex",
+            "BlockHeight",
+            "BlockCreator",
+        ];
+        header_str.into_iter().map(String::from).collect()
+    }
+
+    fn csv_event_output(&self, info: &EventLoggerInfo, network: &dyn NetworkLogHandler) -> Record {
+        if let IsBlockConfirmationEvent(block_index, _, time) = info {
+            return vec![
+                time.into(),
+                (time - network.get_block_creation_time(*block_index)).into(),
+                block_index.into(),
+                network.get_block_height(*block_index).into(),
+                network.get_block_creator(*block_index).into(),
+            ];
+        }
+        vec![Field::Null; 5]
+    }
+}
@@ -38,6 +38,8 @@ fn main() -> Result<(), std::io::Error> {
         BlockchainReorgLogger::new(),
     )?));
 
+    bitcoin_scenario.set_run_manifest_path(&logger_dir.join("bitcoin-run-manifest.json"));
+
     bitcoin_scenario.run()?;
 
     Ok(())
@@ -5,10 +5,15 @@
 //! design pattern.
 // #![allow(unused)]
 
+pub mod bft_network;
 pub mod bitcoin_network;
 pub mod ecs;
+pub mod ethereum_network;
+pub mod ghostdag_network;
 pub mod message;
 pub mod node;
+pub mod p2p_network;
+pub mod praos_network;
 pub mod resource;
 pub mod stats;
 
@@ -19,6 +24,7 @@ use crate::simulator::event::receive_event::ReceiveEvent;
 use crate::simulator::event::send_event::SendEvent;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
+use std::any::Any;
 
 pub trait Network: NetworkLogHandler {
     // used in events:
@@ -41,6 +47,15 @@ pub trait Network: NetworkLogHandler {
         rand: &mut RandomnessEngine,
         mining_event: &BlockMiningProcess,
     );
+    /// Executes an event which only some protocols use, e.g. the start of a
+    /// Proof-of-Stake slot. A network handles the events of its protocol and
+    /// ignores the rest.
+    fn protocol_event(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        event: &dyn Any,
+    );
 }
 
 //----------State----------//
@@ -55,5 +70,3 @@ pub struct NetworkState {
     pub simulator: Simulator,
     pub rand: RandomnessEngine,
 }
-
-pub struct EthereumNetwork;
@@ -0,0 +1,192 @@
+mod bft_actions;
+pub(crate) mod bft_scenarios_preparation;
+
+use crate::consensus::algorithm::BFTConsensus;
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::block_factory::BFT_VOTE_SIZE;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
+use crate::network::node::connection::node_is_connected;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::bft_timeout_event::BFTTimeoutEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// A permissioned network of fully connected replicas running a BFT consensus.
+/// All votes, including the proposals carrying their blocks, are sent as
+/// `VoteMessage`s.
+pub type BFTNetwork<C> = P2PNetwork<C, BFTProtocol>;
+
+/// A BFT consensus the BFT network can run: it orders BFT blocks and keeps a
+/// local block tree per replica. Implemented for all such consensus
+/// algorithms.
+pub trait BFTNetworkConsensus: BFTConsensus<B = BFTBlock, L = LocalBlockTree> + Clone {}
+
+impl<C> BFTNetworkConsensus for C where C: BFTConsensus<B = BFTBlock, L = LocalBlockTree> + Clone {}
+
+/// A vote sent by a replica, to a single replica or to all of them.
+#[derive(Debug, Clone)]
+pub struct BFTMessage {
+    pub vote: BFTVote,
+    pub recipient: Option<usize>,
+}
+
+/// The blocks a Byzantine leader proposed in the same view and height.
+#[derive(Default, Debug, Clone)]
+pub struct Equivocations(HashMap<(u64, u64), Vec<usize>>);
+
+impl Equivocations {
+    pub fn add(&mut self, proposal: &BFTVote, blocks: Vec<usize>) {
+        self.0.insert((proposal.view, proposal.height), blocks);
+    }
+
+    /// Returns the votes a Byzantine replica sends instead of `vote`: a vote
+    /// for each of the blocks of the view and height if its leader
+    /// equivocated, so conflicting votes.
+    pub fn conflicting_votes(&self, vote: BFTVote) -> Vec<BFTVote> {
+        match (vote.vote_type, vote.block_index) {
+            (BFTVoteType::Proposal, _) | (_, None) => vec![vote],
+            _ => match self.0.get(&(vote.view, vote.height)) {
+                Some(blocks) => blocks
+                    .iter()
+                    .map(|block| BFTVote {
+                        block_index: Some(*block),
+                        ..vote
+                    })
+                    .collect(),
+                None => vec![vote],
+            },
+        }
+    }
+}
+
+/// Returns the replicas receiving each of the two blocks of an equivocating
+/// leader: the first and the second half of the correct replicas, while the
+/// Byzantine ones receive both.
+pub fn equivocation_recipients(is_byzantine: &[bool]) -> [Vec<usize>; 2] {
+    let num_of_replicas = is_byzantine.len();
+    let half = num_of_replicas / 2;
+    [
+        (0..num_of_replicas)
+            .filter(|&node| node < half || is_byzantine[node])
+            .collect(),
+        (0..num_of_replicas)
+            .filter(|&node| node >= half || is_byzantine[node])
+            .collect(),
+    ]
+}
+
+/// The votes and timeouts of the replicas.
+pub struct BFTProtocol {
+    /// All votes, including proposals which carry their blocks.
+    pub messages: Vec<BFTMessage>,
+    pub block_size: u64,
+    /// Crashed replicas stay silent: they neither propose nor vote.
+    pub is_crashed: Vec<bool>,
+    /// Byzantine replicas follow the consensus, but propose two blocks when
+    /// they lead, each to half of the correct replicas, and vote for both
+    /// blocks of an equivocating leader.
+    pub is_byzantine: Vec<bool>,
+    pub equivocations: Equivocations,
+}
+
+impl<C: BFTNetworkConsensus> NetworkProtocol<C> for BFTProtocol {
+    fn receive_vote(
+        network: &mut BFTNetwork<C>,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+    ) {
+        let node = receive_event.node;
+        if network.protocol.is_crashed[node] {
+            return;
+        }
+
+        let download_delay = network.simulate_download_delay(simulator, receive_event);
+        let vote = network.protocol.messages[receive_event.block_index].vote;
+        if let (BFTVoteType::Proposal, Some(block_index)) = (vote.vote_type, vote.block_index) {
+            network.ecs.local_ledger[node].add(block_index, &network.resource.blocks);
+        }
+
+        // the state of the replica is updated right away and the resulting
+        // messages are sent once the received one is downloaded.
+        let actions = network.ecs.consensus_algorithm[node].new_incoming_vote(
+            &vote,
+            &network.resource.blocks,
+            &network.resource.config,
+            &network.ecs.local_ledger[node],
+            simulator,
+        );
+        network.apply_actions(simulator, node, actions, download_delay);
+    }
+
+    /// A message goes to its recipient, or to all other replicas.
+    fn vote_recipients(network: &BFTNetwork<C>, send_event: &SendEvent) -> Vec<usize> {
+        let node = send_event.node;
+        match network.protocol.messages[send_event.block_index].recipient {
+            Some(recipient) => vec![recipient],
+            None => (0..network.ecs.num_of_nodes)
+                .filter(|&i| i != node)
+                .collect(),
+        }
+    }
+
+    /// A proposal carries its block; other votes are of fixed size.
+    fn vote_size(network: &BFTNetwork<C>, message_index: usize) -> u64 {
+        let vote = &network.protocol.messages[message_index].vote;
+        match (vote.vote_type, vote.block_index) {
+            (BFTVoteType::Proposal, Some(block_index)) => {
+                BFT_VOTE_SIZE + network.resource.blocks[block_index].size
+            }
+            _ => BFT_VOTE_SIZE,
+        }
+    }
+
+    fn handle_event(
+        network: &mut BFTNetwork<C>,
+        simulator: &mut Simulator,
+        _: &mut RandomnessEngine,
+        event: &dyn Any,
+    ) {
+        let Some(timeout_event) = event.downcast_ref::<BFTTimeoutEvent>() else {
+            return;
+        };
+        let node = timeout_event.node;
+        if !node_is_connected(&network.ecs.is_connected, node) || network.protocol.is_crashed[node]
+        {
+            return;
+        }
+
+        let actions = network.ecs.consensus_algorithm[node].timeout(
+            timeout_event.view,
+            timeout_event.height,
+            timeout_event.step,
+            &network.resource.blocks,
+            &network.resource.config,
+            &network.ecs.local_ledger[node],
+            simulator,
+        );
+        network.apply_actions(simulator, node, actions, 0.0);
+    }
+}
+
+impl<C: BFTNetworkConsensus> BFTNetwork<C> {
+    pub fn new_with_size(
+        num_of_nodes: usize,
+        average_num_of_blocks: usize,
+        config: C::G,
+        block_size: u64,
+    ) -> Self {
+        let protocol = BFTProtocol {
+            messages: Vec::new(),
+            block_size,
+            is_crashed: vec![false; num_of_nodes],
+            is_byzantine: vec![false; num_of_nodes],
+            equivocations: Equivocations::default(),
+        };
+        Self::new(num_of_nodes, average_num_of_blocks, config, 0, protocol)
+    }
+}
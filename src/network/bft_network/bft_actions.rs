@@ -0,0 +1,155 @@
+use crate::consensus::algorithm::BFTAction;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::vote::BFTVote;
+use crate::network::bft_network::{
+    equivocation_recipients, BFTMessage, BFTNetwork, BFTNetworkConsensus,
+};
+use crate::network::message::MessageType::VoteMessage;
+use crate::simulator::event::bft_timeout_event::BFTTimeoutEvent;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::Simulator;
+
+impl<C: BFTNetworkConsensus> BFTNetwork<C> {
+    /// Carries out the actions returned by the consensus of `node`, `delay`
+    /// seconds from now.
+    pub(crate) fn apply_actions(
+        &mut self,
+        simulator: &mut Simulator,
+        node: usize,
+        actions: Vec<BFTAction>,
+        delay: f64,
+    ) {
+        let is_byzantine = self.protocol.is_byzantine[node];
+        for action in actions {
+            match action {
+                BFTAction::Propose { vote, parent } if is_byzantine => {
+                    self.equivocate(simulator, node, &vote, parent, delay);
+                }
+                BFTAction::Propose { vote, parent } => {
+                    let block_index = self.create_block(simulator, &vote, parent, delay);
+                    let vote = BFTVote {
+                        block_index: Some(block_index),
+                        ..vote
+                    };
+                    let message_index = self.new_message(vote, None);
+                    self.broadcast(simulator, node, message_index, delay);
+                }
+                BFTAction::Broadcast(vote) => {
+                    for vote in self.votes_of(node, vote) {
+                        let message_index = self.new_message(vote, None);
+                        self.broadcast(simulator, node, message_index, delay);
+                    }
+                }
+                BFTAction::SendTo { vote, to } => {
+                    for vote in self.votes_of(node, vote) {
+                        let message_index = self.new_message(vote, Some(to));
+                        self.send_to(simulator, node, message_index, to, delay);
+                    }
+                }
+                BFTAction::ScheduleTimeout {
+                    view,
+                    height,
+                    step,
+                    delay: timeout,
+                } => {
+                    let timeout_event = Box::new(BFTTimeoutEvent::new(node, view, height, step));
+                    simulator.put_event(timeout_event, delay + timeout);
+                }
+            }
+        }
+    }
+
+    /// Returns the votes sent by `node` for a vote of its consensus.
+    fn votes_of(&self, node: usize, vote: BFTVote) -> Vec<BFTVote> {
+        if self.protocol.is_byzantine[node] {
+            self.protocol.equivocations.conflicting_votes(vote)
+        } else {
+            vec![vote]
+        }
+    }
+
+    /// Creates two blocks on `parent` and sends each of them to half of the
+    /// correct replicas.
+    fn equivocate(
+        &mut self,
+        simulator: &mut Simulator,
+        node: usize,
+        vote: &BFTVote,
+        parent: usize,
+        delay: f64,
+    ) {
+        let blocks: Vec<usize> = (0..2)
+            .map(|_| self.create_block(simulator, vote, parent, delay))
+            .collect();
+        self.protocol.equivocations.add(vote, blocks.clone());
+        let recipients = equivocation_recipients(&self.protocol.is_byzantine);
+        for (block_index, recipients) in blocks.into_iter().zip(recipients) {
+            let vote = BFTVote {
+                block_index: Some(block_index),
+                ..*vote
+            };
+            for to in recipients {
+                let message_index = self.new_message(vote, Some(to));
+                self.send_to(simulator, node, message_index, to, delay);
+            }
+        }
+    }
+
+    /// Sends a message to a single replica, itself without any delay.
+    fn send_to(
+        &mut self,
+        simulator: &mut Simulator,
+        node: usize,
+        index: usize,
+        to: usize,
+        delay: f64,
+    ) {
+        if to == node {
+            let receive_event = Box::new(ReceiveEvent::new(index, node, node, VoteMessage));
+            simulator.put_event(receive_event, delay);
+        } else {
+            let send_event = Box::new(SendEvent::new(index, node, node, VoteMessage));
+            simulator.put_event(send_event, delay);
+        }
+    }
+
+    /// Sends a message to all replicas. The sender receives its own message
+    /// without any delay.
+    fn broadcast(&mut self, simulator: &mut Simulator, node: usize, index: usize, delay: f64) {
+        let receive_event = Box::new(ReceiveEvent::new(index, node, node, VoteMessage));
+        simulator.put_event(receive_event, delay);
+        let send_event = Box::new(SendEvent::new(index, node, node, VoteMessage));
+        simulator.put_event(send_event, delay);
+    }
+
+    fn create_block(
+        &mut self,
+        simulator: &mut Simulator,
+        vote: &BFTVote,
+        parent: usize,
+        delay: f64,
+    ) -> usize {
+        let block = BFTBlock::new_with_parent(
+            simulator.simulation_time + delay,
+            Some(vote.voter),
+            self.resource.blocks[parent].height + 1,
+            parent,
+            self.protocol.block_size,
+            vote.view,
+        );
+        self.resource.blocks.push(block);
+        let block_index = self.resource.blocks.len() - 1;
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(block_index, vote.voter)),
+            delay,
+        );
+        block_index
+    }
+
+    fn new_message(&mut self, vote: BFTVote, recipient: Option<usize>) -> usize {
+        self.protocol.messages.push(BFTMessage { vote, recipient });
+        self.protocol.messages.len() - 1
+    }
+}
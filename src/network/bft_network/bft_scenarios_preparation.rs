@@ -0,0 +1,56 @@
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::network::bft_network::{BFTNetwork, BFTNetworkConsensus};
+use crate::network::node::connection::set_all_nodes_connected;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+impl<C: BFTNetworkConsensus> BFTNetwork<C> {
+    /// Prepares the network. Replicas are placed in `node_regions` if given,
+    /// otherwise sampled from the node distribution of the network stats. The
+    /// Byzantine replicas are sampled among the ones not crashed.
+    pub fn prepare(
+        &mut self,
+        rand: &mut RandomnessEngine,
+        num_of_crashed_replicas: usize,
+        num_of_byzantine_replicas: usize,
+        node_regions: Option<&[usize]>,
+    ) {
+        let num_of_nodes = self.ecs.num_of_nodes;
+        self.configure_consensus();
+
+        match node_regions {
+            Some(node_regions) => {
+                assert_eq!(node_regions.len(), num_of_nodes);
+                self.ecs.region = node_regions.to_vec();
+            }
+            None => self.sample_node_regions(rand),
+        }
+
+        set_all_nodes_connected(&mut self.ecs.is_connected, num_of_nodes);
+        let replicas: Vec<usize> = (0..num_of_nodes).collect();
+        for crashed in rand.sample_nodes_bigger_than_size(&replicas, num_of_crashed_replicas) {
+            self.protocol.is_crashed[crashed] = true;
+        }
+        let correct_replicas: Vec<usize> = (0..num_of_nodes)
+            .filter(|&node| !self.protocol.is_crashed[node])
+            .collect();
+        for byzantine in
+            rand.sample_nodes_bigger_than_size(&correct_replicas, num_of_byzantine_replicas)
+        {
+            self.protocol.is_byzantine[byzantine] = true;
+        }
+
+        self.assign_ledgers_and_links(rand, BFTBlock::generate_genesis_block());
+    }
+
+    /// Starts the consensus of all correct replicas.
+    pub(crate) fn insert_initial_event(&mut self, simulator: &mut Simulator) {
+        for node in 0..self.ecs.num_of_nodes {
+            if self.protocol.is_crashed[node] {
+                continue;
+            }
+            let actions = self.ecs.consensus_algorithm[node].start(&self.resource.config);
+            self.apply_actions(simulator, node, actions, 0.0);
+        }
+    }
+}
@@ -1,179 +1,148 @@
 mod bitcoin_block_mining;
 mod bitcoin_generate_block;
-mod bitcoin_receive;
+mod bitcoin_mining_pool;
+mod bitcoin_ng_microblock;
 pub(crate) mod bitcoin_scenarios_preparation;
-mod bitcoin_send;
 
-use crate::ledger_data::block::Block;
+use crate::consensus::algorithm::nakamoto_consensus::NakamotoConsensus;
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::bitcoin_ng_config::BitcoinNGConfig;
+use crate::consensus::config::nakamoto_consensus_config::NakamotoConsensusConfig;
+use crate::ledger_data::bitcoin_block::BitcoinBlock;
 use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
-use crate::log::NetworkLogHandler;
-use crate::network::ecs::bitcoin_ecs::BitcoinECS;
-use crate::network::message::MessageType::{DataMessage, InvMessage, RequestDataMessage};
 use crate::network::node::connection::node_is_connected;
-use crate::network::resource::bitcoin_resource::BitcoinResource;
-use crate::network::Network;
-use crate::simulator::event::block_mining_process::BlockMiningProcess;
-use crate::simulator::event::generate_block_event::GenerateBlockWithoutTxEvent;
-use crate::simulator::event::receive_event::ReceiveEvent;
-use crate::simulator::event::send_event::SendEvent;
+use crate::network::node::mining_pool::{MiningPool, MiningPoolConfig};
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::BitcoinProfile;
+use crate::simulator::event::generate_microblock_event::GenerateMicroblockEvent;
+use crate::simulator::event::stratum_work_event::StratumWorkEvent;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
+use std::any::Any;
+
+/// A Proof-of-Work network relaying blocks with inv/getdata, with optional
+/// mining pools, relay overlay, validation delays and Bitcoin-NG. `C` is the
+/// chain-based consensus run by every node.
+pub type BitcoinNetwork<C = NakamotoConsensus> = P2PNetwork<C, BitcoinProtocol>;
+
+/// A chain-based consensus the Bitcoin network can run: it orders Bitcoin
+/// blocks, is configured like Nakamoto consensus and keeps a local block tree
+/// per node. Implemented for all such consensus algorithms.
+pub trait BitcoinConsensus:
+    ChainBasedConsensus<B = BitcoinBlock, G = NakamotoConsensusConfig, L = LocalBlockTree>
+    + Clone
+    + Default
+{
+}
+
+impl<C> BitcoinConsensus for C where
+    C: ChainBasedConsensus<B = BitcoinBlock, G = NakamotoConsensusConfig, L = LocalBlockTree>
+        + Clone
+        + Default
+{
+}
 
-pub struct BitcoinNetwork {
-    pub ecs: BitcoinECS,
-    pub resource: BitcoinResource,
+/// The Proof-of-Work mining of the Bitcoin network.
+#[derive(Default)]
+pub struct BitcoinProtocol {
+    pub profile: BitcoinProfile,
+    pub mining_pool_config: Option<MiningPoolConfig>,
+    pub bitcoin_ng_config: Option<BitcoinNGConfig>,
+    /// The mining pool operated by each node, if any.
+    pub mining_pool: Vec<Option<MiningPool>>,
 }
 
-impl Network for BitcoinNetwork {
-    fn generate_new_block_without_tx(
-        &mut self,
+impl<C: BitcoinConsensus> NetworkProtocol<C> for BitcoinProtocol {
+    fn generate_block(
+        network: &mut BitcoinNetwork<C>,
         simulator: &mut Simulator,
         rand: &mut RandomnessEngine,
-        generate_event: &GenerateBlockWithoutTxEvent,
+        node: usize,
     ) {
-        let node = generate_event.node;
-        if !node_is_connected(&self.ecs.is_connected, node) {
-            return;
-        }
-
-        self.generate_new_block_and_receive_it(simulator, rand, node);
-    }
-
-    fn receive(&mut self, simulator: &mut Simulator, receive_event: &ReceiveEvent) {
-        let node = receive_event.node;
-        if !node_is_connected(&self.ecs.is_connected, node) {
-            return;
-        }
-
-        match receive_event.msg_type {
-            DataMessage(_) => self.process_data_message(simulator, receive_event),
-            InvMessage(_) => self.process_inv_message(simulator, receive_event),
-            RequestDataMessage(_) => self.process_request_data_message(simulator, receive_event),
-            _ => (),
-        }
+        network.generate_new_block_and_receive_it(simulator, rand, node);
     }
 
-    fn send(
-        &mut self,
+    fn mine_block(
+        network: &mut BitcoinNetwork<C>,
         simulator: &mut Simulator,
         rand: &mut RandomnessEngine,
-        send_event: &SendEvent,
+        miner: usize,
     ) {
-        let node = send_event.node;
-        if !node_is_connected(&self.ecs.is_connected, node) {
-            return;
-        }
-
-        match &send_event.msg_type {
-            InvMessage(_) => self.send_inv_to_neighbors(simulator, rand, send_event),
-            DataMessage(_) | RequestDataMessage(_) => {
-                self.simulate_upload(simulator, rand, send_event.from, send_event);
-            }
-            _ => (),
-        }
+        network.mine_new_block(simulator, rand, miner);
     }
 
-    fn block_mining(
-        &mut self,
+    /// A pool operator sends its new chain head to its hashers.
+    fn accept_block(
+        network: &mut BitcoinNetwork<C>,
+        node: usize,
+        block_index: usize,
         simulator: &mut Simulator,
-        rand: &mut RandomnessEngine,
-        mining_event: &BlockMiningProcess,
     ) {
-        let miner = mining_event.miner;
-        if !node_is_connected(&self.ecs.is_connected, miner) {
-            return;
+        let previous_head = network.ecs.consensus_algorithm[node].get_canonical_chain_head_index();
+        network.update_consensus(node, block_index, simulator);
+        let current_head = network.ecs.consensus_algorithm[node].get_canonical_chain_head_index();
+        if current_head != previous_head {
+            network.notify_pool_hashers(node, current_head, simulator);
         }
-
-        self.mine_new_block(simulator, rand, miner);
-    }
-}
-
-impl NetworkLogHandler for BitcoinNetwork {
-    fn get_block_creation_time(&self, block_index: usize) -> f64 {
-        self.resource.blocks[block_index].get_creation_time()
-    }
-
-    fn get_block_creator(&self, block_index: usize) -> Option<usize> {
-        self.resource.blocks[block_index].get_creator()
-    }
-
-    fn get_block_height(&self, block_index: usize) -> i32 {
-        self.resource.blocks[block_index].get_height()
     }
 
-    fn get_block_size(&self, block_index: usize) -> u64 {
-        self.resource.blocks[block_index].get_size()
+    fn handle_event(
+        network: &mut BitcoinNetwork<C>,
+        simulator: &mut Simulator,
+        _: &mut RandomnessEngine,
+        event: &dyn Any,
+    ) {
+        if let Some(work_event) = event.downcast_ref::<StratumWorkEvent>() {
+            if node_is_connected(&network.ecs.is_connected, work_event.pool) {
+                network.update_hasher_work(work_event);
+            }
+        } else if let Some(microblock_event) = event.downcast_ref::<GenerateMicroblockEvent>() {
+            if node_is_connected(&network.ecs.is_connected, microblock_event.leader) {
+                network.generate_microblock_and_receive_it(simulator, microblock_event);
+            }
+        }
     }
 
-    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
-        self.resource.blocks[block_index].get_parents()
+    fn canonical_chain_head(network: &BitcoinNetwork<C>, node_index: usize) -> Option<usize> {
+        network
+            .ecs
+            .consensus_algorithm
+            .get(node_index)
+            .map(|consensus| consensus.get_canonical_chain_head_index())
     }
 
-    fn get_num_of_nodes(&self) -> usize {
-        self.ecs.num_of_nodes
+    fn is_microblock(network: &BitcoinNetwork<C>, block_index: usize) -> bool {
+        network.resource.blocks[block_index].is_microblock
     }
 
-    // fn add_to_local_block_tree(&self, local_block_tree: &mut LocalBlockTree, block_index: usize) {
-    //     local_block_tree.add(block_index, &self.resource.blocks);
-    // }
-
     fn block_reorg_before(
-        &self,
+        network: &BitcoinNetwork<C>,
         reorg_logger: &mut BlockchainReorgLogger,
-        block_index: &usize,
-        node: &usize,
+        block_index: usize,
+        node_index: usize,
     ) {
-        reorg_logger
-            .network_view_block_tree
-            .add(*block_index, &self.resource.blocks);
-        reorg_logger.previous_head_chain_index =
-            Some(self.ecs.consensus_algorithm[*node].current_main_chain_head_index);
-        reorg_logger.current_node_index = Some(*node);
-        reorg_logger.new_block_received = true;
+        network.chain_reorg_before(reorg_logger, block_index, node_index);
     }
 
-    fn block_reorg_after(&self, reorg_logger: &mut BlockchainReorgLogger) -> bool {
-        if reorg_logger.new_block_received {
-            reorg_logger.new_block_received = false;
-            if let Some(node_index) = reorg_logger.current_node_index {
-                let current_head_chain_index =
-                    self.ecs.consensus_algorithm[node_index].current_main_chain_head_index;
-                if let Some(previous_head_index) = reorg_logger.previous_head_chain_index {
-                    let ancestor_index = reorg_logger
-                        .network_view_block_tree
-                        .get_single_ancestor_of_height(
-                            current_head_chain_index,
-                            self.resource.blocks[previous_head_index].height,
-                            &self.resource.blocks,
-                        );
-                    return ancestor_index != reorg_logger.previous_head_chain_index;
-                }
-            }
-        }
-        false
+    fn block_reorg_after(
+        network: &BitcoinNetwork<C>,
+        reorg_logger: &mut BlockchainReorgLogger,
+    ) -> bool {
+        network.chain_reorg_after(reorg_logger)
     }
 
     fn block_reorg_output_length(
-        &self,
+        network: &BitcoinNetwork<C>,
         reorg_logger: &BlockchainReorgLogger,
         previous_head: usize,
-        node_index: &usize,
+        node_index: usize,
     ) -> i32 {
-        let node_chain_head =
-            self.ecs.consensus_algorithm[*node_index].current_main_chain_head_index;
-        let common_ancestor = reorg_logger.network_view_block_tree.get_common_ancestor(
-            node_chain_head,
-            previous_head,
-            &self.resource.blocks,
-        );
-        let reorg_length: i32 = self.resource.blocks[node_chain_head].height
-            - self.resource.blocks[common_ancestor].height;
-
-        reorg_length
+        network.chain_reorg_output_length(reorg_logger, previous_head, node_index)
     }
 }
 
-impl BitcoinNetwork {
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
     pub fn new_with_size(
         num_of_nodes: usize,
         average_num_of_blocks: usize,
@@ -183,16 +152,22 @@ impl BitcoinNetwork {
         difficulty: f64,
         num_of_miners: usize,
     ) -> Self {
-        Self {
-            ecs: BitcoinECS::create_with_size(num_of_nodes),
-            resource: BitcoinResource::new(
-                average_num_of_blocks,
-                average_block_mining_interval,
-                confirmation_depth,
-                genesis_block_index,
-                difficulty,
-                num_of_miners,
-            ),
-        }
+        let config = NakamotoConsensusConfig::new(
+            average_block_mining_interval,
+            confirmation_depth,
+            genesis_block_index,
+            difficulty,
+        );
+        let protocol = BitcoinProtocol {
+            mining_pool: vec![None; num_of_nodes],
+            ..BitcoinProtocol::default()
+        };
+        Self::new(
+            num_of_nodes,
+            average_num_of_blocks,
+            config,
+            num_of_miners,
+            protocol,
+        )
     }
 }
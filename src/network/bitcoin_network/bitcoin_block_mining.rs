@@ -1,10 +1,10 @@
-use crate::network::bitcoin_network::BitcoinNetwork;
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
 use crate::simulator::event::block_mining_process::BlockMiningProcess;
 use crate::simulator::event::generate_block_event::GenerateBlockWithoutTxEvent;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
 
-impl BitcoinNetwork {
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
     pub fn mine_new_block(
         &mut self,
         simulator: &mut Simulator,
@@ -1,16 +1,17 @@
-use crate::consensus::algorithm::nakamoto_consensus::NakamotoConsensus;
 use crate::ledger_data::bitcoin_block::BitcoinBlock;
-use crate::ledger_data::block_factory::BlockFactory;
-use crate::network::bitcoin_network::BitcoinNetwork;
+use crate::ledger_data::block_factory::{BlockFactory, BITCOIN_NG_KEY_BLOCK_SIZE};
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
 use crate::network::message::DataType::IsBlock;
 use crate::network::message::MessageType::DataMessage;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::BitcoinProfile;
 use crate::network::Network;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
 use crate::simulator::event::receive_event::ReceiveEvent;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
 
 // Block generation methods and associated functions:
-impl BitcoinNetwork {
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
     pub fn generate_new_block_and_receive_it(
         &mut self,
         simulator: &mut Simulator,
@@ -19,39 +20,53 @@ impl BitcoinNetwork {
     ) {
         // here a new block will be added to the current slice.
         let new_block_index = self.resource.blocks.len();
+        let parent = self.mining_parent(node, rand);
 
-        let bitcoin_block_without_tx = BitcoinNetwork::new_block_from_factory(
+        let mut bitcoin_block_without_tx = Self::new_block_from_factory(
             &self.resource.blocks,
             simulator,
             rand,
-            &self.ecs.consensus_algorithm[node],
+            parent,
             node,
-            self.resource.config.difficulty,
+            &self.protocol.profile,
         );
+        // a Bitcoin-NG key block only elects its miner, the transactions are
+        // in the microblocks.
+        if self.protocol.bitcoin_ng_config.is_some() {
+            bitcoin_block_without_tx.size = BITCOIN_NG_KEY_BLOCK_SIZE;
+            bitcoin_block_without_tx.full_size = BITCOIN_NG_KEY_BLOCK_SIZE;
+        }
         self.resource.blocks.push(bitcoin_block_without_tx);
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(new_block_index, node)),
+            0.0,
+        );
 
         let receive_at_this_node =
             ReceiveEvent::new(new_block_index, node, node, DataMessage(IsBlock));
         self.receive(simulator, &receive_at_this_node);
+
+        if let Some(config) = self.protocol.bitcoin_ng_config {
+            self.start_leadership(node, new_block_index, &config, simulator);
+        }
     }
 
     fn new_block_from_factory(
         blocks: &[BitcoinBlock],
         simulator: &Simulator,
         rand: &mut RandomnessEngine,
-        consensus_algorithm: &NakamotoConsensus,
+        parent: usize,
         node: usize,
-        difficulty: f64,
+        profile: &BitcoinProfile,
     ) -> BitcoinBlock {
-        let canonical_chain_head = consensus_algorithm.current_main_chain_head_index;
         let weight: f64 = rand.sample_exponential_distribution_mean_1();
         BlockFactory::sample_bitcoin_block(
             blocks,
             simulator,
             rand,
             Some(node),
-            canonical_chain_head,
-            difficulty,
+            parent,
+            profile,
             weight,
         )
     }
@@ -0,0 +1,39 @@
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
+use crate::simulator::event::stratum_work_event::StratumWorkEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+// Mining pool methods and associated functions:
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
+    /// Returns the block that a newly found block of the node extends. For a
+    /// pool operator, this is the work of the hasher who found the block,
+    /// otherwise the canonical chain head of the node.
+    pub(crate) fn mining_parent(&self, node: usize, rand: &mut RandomnessEngine) -> usize {
+        match &self.protocol.mining_pool[node] {
+            Some(pool) => pool.hashers[pool.sample_block_finder(rand)].current_work,
+            None => self.ecs.consensus_algorithm[node].get_canonical_chain_head_index(),
+        }
+    }
+
+    /// Sends the new chain head of a pool operator to each of its hashers
+    /// with their stratum latency.
+    pub(crate) fn notify_pool_hashers(
+        &self,
+        operator: usize,
+        head_index: usize,
+        simulator: &mut Simulator,
+    ) {
+        if let Some(pool) = &self.protocol.mining_pool[operator] {
+            for (hasher_index, hasher) in pool.hashers.iter().enumerate() {
+                let work_event = StratumWorkEvent::new(operator, hasher_index, head_index);
+                simulator.put_event(Box::new(work_event), hasher.stratum_latency);
+            }
+        }
+    }
+
+    pub(crate) fn update_hasher_work(&mut self, work_event: &StratumWorkEvent) {
+        if let Some(pool) = self.protocol.mining_pool[work_event.pool].as_mut() {
+            pool.hashers[work_event.hasher].current_work = work_event.block_index;
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::consensus::config::bitcoin_ng_config::BitcoinNGConfig;
+use crate::ledger_data::bitcoin_block::BitcoinBlock;
+use crate::ledger_data::block_factory::BITCOIN_NG_MICROBLOCK_HEADER_SIZE;
+use crate::ledger_data::single_parent::SingleParent;
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::DataMessage;
+use crate::network::Network;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
+use crate::simulator::event::generate_microblock_event::GenerateMicroblockEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::Simulator;
+use std::collections::HashMap;
+
+// Bitcoin-NG microblock methods and associated functions:
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
+    /// Makes the miner of a key block the leader, which emits its first
+    /// microblock one microblock interval later.
+    pub(crate) fn start_leadership(
+        &self,
+        leader: usize,
+        key_block: usize,
+        config: &BitcoinNGConfig,
+        simulator: &mut Simulator,
+    ) {
+        let microblock_event = GenerateMicroblockEvent::new(leader, key_block);
+        simulator.put_event(Box::new(microblock_event), config.microblock_interval);
+    }
+
+    /// The leader extends its chain head with a microblock, as long as the
+    /// head is still in the epoch of its key block. Otherwise a newer key
+    /// block has ended its leadership.
+    pub(crate) fn generate_microblock_and_receive_it(
+        &mut self,
+        simulator: &mut Simulator,
+        microblock_event: &GenerateMicroblockEvent,
+    ) {
+        let Some(config) = self.protocol.bitcoin_ng_config else {
+            return;
+        };
+        let leader = microblock_event.leader;
+        let head = self.ecs.consensus_algorithm[leader].get_canonical_chain_head_index();
+        if self.latest_key_block(head) != microblock_event.key_block {
+            return;
+        }
+
+        let new_block_index = self.resource.blocks.len();
+        let microblock = BitcoinBlock::new_microblock(
+            simulator.simulation_time,
+            leader,
+            head,
+            &self.resource.blocks[head],
+            BITCOIN_NG_MICROBLOCK_HEADER_SIZE + config.microblock_size,
+        );
+        self.resource.blocks.push(microblock);
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(new_block_index, leader)),
+            0.0,
+        );
+
+        let receive_at_this_node =
+            ReceiveEvent::new(new_block_index, leader, leader, DataMessage(IsBlock));
+        self.receive(simulator, &receive_at_this_node);
+
+        self.start_leadership(leader, microblock_event.key_block, &config, simulator);
+    }
+
+    /// Returns the key block of the epoch the block belongs to.
+    pub(crate) fn latest_key_block(&self, block_index: usize) -> usize {
+        let mut key_block = block_index;
+        while self.resource.blocks[key_block].is_microblock {
+            match self.resource.blocks[key_block].get_single_parent() {
+                Some(parent) => key_block = parent,
+                None => break,
+            }
+        }
+        key_block
+    }
+
+    /// Returns the microblock fees earned by each leader on the chain ending
+    /// at `head`. The fees of a microblock are its transaction bytes, split
+    /// between its leader and the miner of the next key block; the next
+    /// leader's share of the last epoch is not paid yet.
+    pub fn microblock_fees_by_leader(&self, head: usize) -> HashMap<usize, f64> {
+        let mut fees: HashMap<usize, f64> = HashMap::new();
+        let Some(config) = self.protocol.bitcoin_ng_config else {
+            return fees;
+        };
+
+        let mut next_leader: Option<usize> = None;
+        let mut block_index = head;
+        loop {
+            let block = &self.resource.blocks[block_index];
+            match (block.is_microblock, block.creator) {
+                (true, Some(leader)) => {
+                    let fee = block.size.saturating_sub(BITCOIN_NG_MICROBLOCK_HEADER_SIZE) as f64;
+                    let (leader_fee, next_leader_fee) = config.split_fee(fee);
+                    *fees.entry(leader).or_default() += leader_fee;
+                    if let Some(next_leader) = next_leader {
+                        *fees.entry(next_leader).or_default() += next_leader_fee;
+                    }
+                }
+                (false, creator) => next_leader = creator,
+                _ => (),
+            }
+            match block.get_single_parent() {
+                Some(parent) => block_index = parent,
+                None => return fees,
+            }
+        }
+    }
+}
@@ -1,29 +1,29 @@
-use crate::consensus::algorithm::DAGBasedConsensus;
-use crate::consensus::blockchain::local_block_tree::assign_initial_local_block_trees;
 use crate::ledger_data::bitcoin_block::BitcoinBlock;
-use crate::network::bitcoin_network::BitcoinNetwork;
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
 use crate::network::node::connection::set_all_nodes_connected;
-use crate::network::node::link::assign_all_bandwidths;
-use crate::network::node::neighbors::{assign_random_neighbors, is_neighbors_bidirectional};
+use crate::network::node::mining_pool::assign_mining_pools;
+use crate::network::node::node_type::assign_node_types;
+use crate::network::node::relay_overlay::assign_relay_overlay_members;
 use crate::network::stats::eighty_six_countries::bitcoin_stats::{
     reset_and_sample_all_bitcoin_miners_hash_power, sample_bitcoin_miner_nodes,
-    sample_bitcoin_node_countries,
+    sample_bitcoin_node_regions,
 };
 use crate::simulator::event::block_mining_process::BlockMiningProcess;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
 
-impl BitcoinNetwork {
+impl<C: BitcoinConsensus> BitcoinNetwork<C> {
+    /// Samples the miners, regions, neighbors, links and hash power, and sets
+    /// up the mining pools, relay overlay and node types. Returns an error if
+    /// the relay overlay has a member which is not a node of the network.
     pub fn prepare(
         &mut self,
         rand: &mut RandomnessEngine,
         average_block_mining_interval: f64,
         min_neighbors: usize,
         num_of_miners: usize,
-    ) {
-        for (node_index, consensus) in &mut self.ecs.consensus_algorithm.iter_mut().enumerate() {
-            consensus.initial_configuration(&self.resource.config, node_index);
-        }
+    ) -> Result<(), String> {
+        self.configure_consensus();
 
         sample_bitcoin_miner_nodes(
             &mut self.resource.miners,
@@ -31,46 +31,53 @@ impl BitcoinNetwork {
             self.ecs.num_of_nodes,
             num_of_miners,
         );
-        sample_bitcoin_node_countries(
-            &mut self.ecs.country,
+        sample_bitcoin_node_regions(
+            &mut self.ecs.region,
             &self.resource.miners,
+            self.resource.network_stats.as_ref(),
             rand,
             self.ecs.num_of_nodes,
             self.resource.num_of_miners(),
         );
         set_all_nodes_connected(&mut self.ecs.is_connected, self.ecs.num_of_nodes);
-        assign_random_neighbors(
-            &mut self.ecs.neighbors,
-            rand,
-            min_neighbors,
-            self.ecs.num_of_nodes,
-        );
-
-        assert!(is_neighbors_bidirectional(&self.ecs.neighbors));
-
-        assign_initial_local_block_trees(&mut self.ecs.local_block_tree, self.ecs.num_of_nodes);
-
-        assign_all_bandwidths(
-            &mut self.ecs.uplink,
-            &mut self.ecs.downlink,
-            &self.ecs.country,
-            rand,
-            self.ecs.num_of_nodes,
-        );
-
-        // Genesis must be always the first block in the blocks. (genesis_index=0)
-        self.resource
-            .blocks
-            .push(BitcoinBlock::generate_genesis_block());
+        self.assign_neighbors(rand, min_neighbors);
+        self.assign_ledgers_and_links(rand, BitcoinBlock::generate_genesis_block());
 
         let miners = self.resource.miners.clone();
         reset_and_sample_all_bitcoin_miners_hash_power(
             &miners,
             &mut self.ecs.hash_power,
+            &self.protocol.profile,
             rand,
             average_block_mining_interval,
             self.resource.config.difficulty,
         );
+
+        if let Some(mining_pool_config) = self.protocol.mining_pool_config {
+            assign_mining_pools(
+                &mut self.protocol.mining_pool,
+                &miners,
+                &mining_pool_config,
+                self.resource.config.genesis_block_index,
+                rand,
+            );
+        }
+
+        if let Some(relay_overlay_config) = &self.resource.relay_overlay_config {
+            assign_relay_overlay_members(
+                &mut self.ecs.relay_overlay_member,
+                &miners,
+                relay_overlay_config,
+            )?;
+        }
+
+        assign_node_types(
+            &mut self.ecs.node_type,
+            &miners,
+            &self.resource.node_type_config,
+            rand,
+        );
+        Ok(())
     }
 
     pub(crate) fn insert_initial_event(
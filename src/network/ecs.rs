@@ -1 +1 @@
-pub mod bitcoin_ecs;
+pub mod p2p_ecs;
@@ -1,53 +1,60 @@
-use crate::consensus::algorithm::nakamoto_consensus::NakamotoConsensus;
-use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
-use crate::network::node::{AlreadySeenBlocks, Downlink, Neighbors, NodeName, Uplink};
-use crate::network::stats::eighty_six_countries::Country;
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::network::node::{AlreadySeenBlocks, Downlink, Neighbors, NodeName, NodeType, Uplink};
 
 //----------ECS----------//
 /// The Entity-Component-System(ECS) design. Each node is solely denoted by a
-/// `usize` number.
-pub struct BitcoinECS {
+/// `usize` number. `C` is the consensus algorithm run by the nodes.
+pub struct P2PECS<C: DAGBasedConsensus> {
     // components:
     pub node_name: Vec<Option<NodeName>>,
     pub is_connected: Vec<bool>,
     pub neighbors: Vec<Neighbors>,
     pub uplink: Vec<Uplink>,
     pub downlink: Vec<Downlink>,
-    pub country: Vec<Country>,
-    pub local_block_tree: Vec<LocalBlockTree>,
+    pub region: Vec<usize>,
+    pub local_ledger: Vec<C::L>,
     pub already_seen_blocks: Vec<AlreadySeenBlocks>,
-    pub consensus_algorithm: Vec<NakamotoConsensus>,
+    pub consensus_algorithm: Vec<C>,
     pub hash_power: Vec<Option<f64>>,
+    pub relay_overlay_member: Vec<bool>,
+    pub node_type: Vec<NodeType>,
+    /// The time each node finishes validating its queued blocks.
+    pub validation_done_time: Vec<f64>,
     // entities:
     pub num_of_nodes: usize,
 }
 
-impl BitcoinECS {
-    pub fn create_with_size(num_of_nodes: usize) -> Self {
+impl<C: DAGBasedConsensus + Clone> P2PECS<C> {
+    pub fn create_with_size(num_of_nodes: usize, config: &C::G) -> Self {
         let node_name: Vec<Option<NodeName>> = vec![None; num_of_nodes];
         let is_connected: Vec<bool> = vec![bool::default(); num_of_nodes];
         let neighbors: Vec<Neighbors> = vec![Neighbors::default(); num_of_nodes];
         let uplink: Vec<Uplink> = vec![Uplink::default(); num_of_nodes];
         let downlink: Vec<Downlink> = vec![Downlink::default(); num_of_nodes];
-        let country: Vec<Country> = vec![Country::default(); num_of_nodes];
-        let local_block_tree: Vec<LocalBlockTree> = vec![LocalBlockTree::default(); num_of_nodes];
-        let consensus_algorithm: Vec<NakamotoConsensus> =
-            vec![NakamotoConsensus::default(); num_of_nodes];
+        let region: Vec<usize> = vec![usize::default(); num_of_nodes];
+        let local_ledger: Vec<C::L> = vec![C::L::default(); num_of_nodes];
         let already_seen_blocks: Vec<AlreadySeenBlocks> =
             vec![AlreadySeenBlocks::default(); num_of_nodes];
+        let consensus_algorithm: Vec<C> = vec![C::new(config); num_of_nodes];
         let hash_power: Vec<Option<f64>> = vec![None; num_of_nodes];
+        let relay_overlay_member: Vec<bool> = vec![false; num_of_nodes];
+        let node_type: Vec<NodeType> = vec![NodeType::default(); num_of_nodes];
+        let validation_done_time: Vec<f64> = vec![0.0; num_of_nodes];
         Self {
             node_name,
             is_connected,
             neighbors,
-            num_of_nodes,
             uplink,
             downlink,
-            country,
-            local_block_tree,
-            consensus_algorithm,
+            region,
+            local_ledger,
             already_seen_blocks,
+            consensus_algorithm,
             hash_power,
+            relay_overlay_member,
+            node_type,
+            validation_done_time,
+            num_of_nodes,
         }
     }
 }
@@ -0,0 +1,111 @@
+pub(crate) mod ethereum_scenarios_preparation;
+mod ethereum_slot;
+
+use crate::consensus::algorithm::gasper_consensus::GasperConsensus;
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::consensus::config::gasper_consensus_config::GasperConsensusConfig;
+use crate::ledger_data::vote::Vote;
+use crate::network::message::MessageType::VoteMessage;
+use crate::network::message::RelayProtocol;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::attestation_event::AttestationEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::event::slot_event::SlotEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+/// A Proof-of-Stake network running the Gasper consensus. Every node is a
+/// validator; blocks and votes are gossiped to all neighbors.
+pub type EthereumNetwork = P2PNetwork<GasperConsensus, GasperProtocol>;
+
+/// The slots, committees and attestations of Gasper.
+pub struct GasperProtocol {
+    pub votes: Vec<Vote>,
+    /// The votes received by each node.
+    pub already_seen_votes: Vec<HashSet<usize>>,
+    pub block_size: u64,
+    /// Attestation committee of each slot of the current epoch.
+    pub committees: Vec<Vec<usize>>,
+    /// The block proposed in each slot: <slot, block_index>
+    pub slot_blocks: HashMap<u64, usize>,
+}
+
+impl NetworkProtocol<GasperConsensus> for GasperProtocol {
+    fn receive_vote(
+        network: &mut EthereumNetwork,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+    ) {
+        let node = receive_event.node;
+        let vote_index = receive_event.block_index;
+        if !network.protocol.already_seen_votes[node].insert(vote_index) {
+            return;
+        }
+
+        let download_delay = network.simulate_download_delay(simulator, receive_event);
+        network.ecs.consensus_algorithm[node].new_incoming_vote(
+            &network.protocol.votes[vote_index],
+            &network.resource.blocks,
+            &network.resource.config,
+            &network.ecs.local_ledger[node],
+            simulator,
+        );
+        let propagate_event = Box::new(SendEvent::new(
+            vote_index,
+            receive_event.from,
+            node,
+            VoteMessage,
+        ));
+        simulator.put_event(propagate_event, download_delay);
+    }
+
+    fn handle_event(
+        network: &mut EthereumNetwork,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        event: &dyn Any,
+    ) {
+        if let Some(slot_event) = event.downcast_ref::<SlotEvent>() {
+            network.start_new_slot(simulator, rand, slot_event);
+        } else if let Some(attestation_event) = event.downcast_ref::<AttestationEvent>() {
+            network.attest_for_slot(simulator, attestation_event.slot);
+        }
+    }
+
+    fn canonical_chain_head(network: &EthereumNetwork, node_index: usize) -> Option<usize> {
+        network
+            .ecs
+            .consensus_algorithm
+            .get(node_index)
+            .map(|consensus| consensus.get_canonical_chain_head_index())
+    }
+
+    fn block_of_slot(network: &EthereumNetwork, slot: u64) -> Option<usize> {
+        network.protocol.slot_blocks.get(&slot).copied()
+    }
+}
+
+impl EthereumNetwork {
+    pub fn new_with_size(
+        num_of_nodes: usize,
+        average_num_of_blocks: usize,
+        config: GasperConsensusConfig,
+        block_size: u64,
+    ) -> Self {
+        let protocol = GasperProtocol {
+            votes: Vec::new(),
+            already_seen_votes: vec![HashSet::new(); num_of_nodes],
+            block_size,
+            committees: Vec::new(),
+            slot_blocks: HashMap::new(),
+        };
+        let mut network = Self::new(num_of_nodes, average_num_of_blocks, config, 0, protocol);
+        network.resource.relay_protocol = RelayProtocol::UnsolicitedPush {
+            num_of_push_peers: None,
+        };
+        network
+    }
+}
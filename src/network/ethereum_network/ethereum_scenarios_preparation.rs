@@ -0,0 +1,65 @@
+use crate::ledger_data::ethereum_block::EthereumBlock;
+use crate::network::ethereum_network::EthereumNetwork;
+use crate::network::node::connection::set_all_nodes_connected;
+use crate::simulator::event::slot_event::SlotEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+/// Median stake of a validator node (ETH).
+const VALIDATOR_STAKE_MEDIAN: f64 = 32.0;
+const VALIDATOR_STAKE_STDDEV: f64 = 1.0;
+
+impl EthereumNetwork {
+    /// Prepares the network. If no validator stakes are set in the config,
+    /// they are sampled from a log-normal distribution.
+    pub fn prepare(
+        &mut self,
+        rand: &mut RandomnessEngine,
+        min_neighbors: usize,
+        offline_validator_share: f64,
+    ) {
+        let num_of_nodes = self.ecs.num_of_nodes;
+        if self.resource.config.validator_stakes.len() != num_of_nodes {
+            let validator_stakes = (0..num_of_nodes)
+                .map(|_| {
+                    rand.sample_log_normal_distribution(
+                        VALIDATOR_STAKE_MEDIAN,
+                        VALIDATOR_STAKE_STDDEV,
+                    )
+                })
+                .collect();
+            self.resource.config.set_validator_stakes(validator_stakes);
+        }
+
+        self.configure_consensus();
+        self.sample_node_regions(rand);
+
+        set_all_nodes_connected(&mut self.ecs.is_connected, num_of_nodes);
+        let num_of_offline_validators = (offline_validator_share * num_of_nodes as f64) as usize;
+        let validators: Vec<usize> = (0..num_of_nodes).collect();
+        for offline in rand.sample_nodes_bigger_than_size(&validators, num_of_offline_validators) {
+            self.ecs.is_connected[offline] = false;
+        }
+
+        self.assign_neighbors(rand, min_neighbors);
+        self.assign_ledgers_and_links(rand, EthereumBlock::generate_genesis_block());
+        self.protocol
+            .slot_blocks
+            .insert(0, self.resource.config.genesis_block_index);
+    }
+
+    /// Assigns the committees of the first epoch and starts slot 1, as the
+    /// genesis block belongs to slot 0.
+    pub(crate) fn insert_initial_event(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        self.assign_committees(rand);
+        let proposer = self.sample_proposer(rand);
+        simulator.put_event(
+            Box::new(SlotEvent::new(1, proposer)),
+            self.resource.config.slot_duration,
+        );
+    }
+}
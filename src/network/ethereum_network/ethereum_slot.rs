@@ -0,0 +1,136 @@
+use crate::consensus::algorithm::gasper_consensus::GasperConsensus;
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::ledger_data::ethereum_block::EthereumBlock;
+use crate::ledger_data::vote::Vote;
+use crate::network::ethereum_network::EthereumNetwork;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::{DataMessage, VoteMessage};
+use crate::network::node::connection::node_is_connected;
+use crate::network::Network;
+use crate::simulator::event::attestation_event::AttestationEvent;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::slot_event::SlotEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+// Slot methods and associated functions:
+impl EthereumNetwork {
+    /// The proposer of the slot (if online) proposes a block on its head. The
+    /// attestation of the slot happens a third of a slot later.
+    pub(crate) fn start_new_slot(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        slot_event: &SlotEvent,
+    ) {
+        let slot = slot_event.slot;
+        let slot_duration = self.resource.config.slot_duration;
+        if slot % self.resource.config.slots_per_epoch == 0 {
+            self.assign_committees(rand);
+        }
+
+        if node_is_connected(&self.ecs.is_connected, slot_event.proposer) {
+            self.propose_block(simulator, slot, slot_event.proposer);
+        }
+
+        simulator.put_event(Box::new(AttestationEvent::new(slot)), slot_duration / 3.0);
+        let next_proposer = self.sample_proposer(rand);
+        simulator.put_event(
+            Box::new(SlotEvent::new(slot + 1, next_proposer)),
+            slot_duration,
+        );
+    }
+
+    /// Each online member of the committee of the slot votes for its head and
+    /// for the checkpoint of the current epoch on the chain of its head.
+    pub(crate) fn attest_for_slot(&mut self, simulator: &mut Simulator, slot: u64) {
+        let epoch = self.resource.config.epoch_of_slot(slot);
+        let committee_index = (slot % self.resource.config.slots_per_epoch) as usize;
+        let committee = self.protocol.committees[committee_index].clone();
+        for validator in committee {
+            if !node_is_connected(&self.ecs.is_connected, validator) {
+                continue;
+            }
+
+            let head = self.update_head(validator, simulator);
+            let consensus = &self.ecs.consensus_algorithm[validator];
+            let vote = Vote::new(
+                validator,
+                slot,
+                head,
+                consensus.justified_checkpoint,
+                GasperConsensus::epoch_boundary_checkpoint(
+                    head,
+                    epoch,
+                    &self.resource.blocks,
+                    &self.resource.config,
+                ),
+            );
+            let vote_index = self.protocol.votes.len();
+            self.protocol.votes.push(vote);
+
+            let receive_at_this_node =
+                ReceiveEvent::new(vote_index, validator, validator, VoteMessage);
+            self.receive(simulator, &receive_at_this_node);
+        }
+    }
+
+    pub(crate) fn sample_proposer(&self, rand: &mut RandomnessEngine) -> usize {
+        rand.sample_from_distribution(&self.resource.config.validator_stakes)
+    }
+
+    /// Shuffles the validators into one attestation committee per slot of the
+    /// epoch.
+    pub(crate) fn assign_committees(&mut self, rand: &mut RandomnessEngine) {
+        let num_of_nodes = self.ecs.num_of_nodes;
+        let slots_per_epoch = self.resource.config.slots_per_epoch as usize;
+        let validators: Vec<usize> = (0..num_of_nodes).collect();
+        let shuffled = rand.sample_nodes_bigger_than_size(&validators, num_of_nodes);
+        self.protocol.committees = (0..slots_per_epoch)
+            .map(|i| {
+                shuffled
+                    .iter()
+                    .skip(i)
+                    .step_by(slots_per_epoch)
+                    .copied()
+                    .collect()
+            })
+            .collect();
+    }
+
+    fn propose_block(&mut self, simulator: &mut Simulator, slot: u64, proposer: usize) {
+        let parent = self.update_head(proposer, simulator);
+        let new_block_index = self.resource.blocks.len();
+        let block = EthereumBlock::new_with_parent(
+            simulator.simulation_time,
+            Some(proposer),
+            self.resource.blocks[parent].height + 1,
+            parent,
+            self.protocol.block_size,
+            slot,
+        );
+        self.resource.blocks.push(block);
+        self.protocol.slot_blocks.insert(slot, new_block_index);
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(new_block_index, proposer)),
+            0.0,
+        );
+
+        let receive_at_this_node =
+            ReceiveEvent::new(new_block_index, proposer, proposer, DataMessage(IsBlock));
+        self.receive(simulator, &receive_at_this_node);
+    }
+
+    /// Runs the fork choice of the node and returns its head.
+    fn update_head(&mut self, node: usize, simulator: &mut Simulator) -> usize {
+        let consensus = &mut self.ecs.consensus_algorithm[node];
+        consensus.update_chain(
+            &self.resource.blocks,
+            &self.resource.config,
+            &self.ecs.local_ledger[node],
+            simulator,
+        );
+        consensus.get_canonical_chain_head_index()
+    }
+}
@@ -0,0 +1,88 @@
+mod ghostdag_block_mining;
+pub(crate) mod ghostdag_scenarios_preparation;
+
+use crate::consensus::algorithm::ghostdag_consensus::GhostDAGConsensus;
+use crate::consensus::config::ghostdag_consensus_config::GhostDAGConsensusConfig;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::BitcoinProfile;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+/// A Proof-of-Work block DAG network running GHOSTDAG. Miners reference all
+/// the tips of their local block DAG, and blocks are relayed with inv and
+/// getdata messages.
+pub type GhostDAGNetwork = P2PNetwork<GhostDAGConsensus, GhostDAGProtocol>;
+
+/// The Proof-of-Work mining of a block DAG.
+#[derive(Default)]
+pub struct GhostDAGProtocol {
+    pub profile: BitcoinProfile,
+    /// Size of the body of every block in bytes. The header grows with the
+    /// number of parents.
+    pub block_size: u64,
+}
+
+impl NetworkProtocol<GhostDAGConsensus> for GhostDAGProtocol {
+    fn generate_block(
+        network: &mut GhostDAGNetwork,
+        simulator: &mut Simulator,
+        _: &mut RandomnessEngine,
+        node: usize,
+    ) {
+        network.generate_new_block_and_receive_it(simulator, node);
+    }
+
+    fn mine_block(
+        network: &mut GhostDAGNetwork,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        miner: usize,
+    ) {
+        network.mine_new_block(simulator, rand, miner);
+    }
+
+    fn block_blue_score(network: &GhostDAGNetwork, block_index: usize) -> Option<u64> {
+        Some(network.resource.blocks[block_index].blue_score())
+    }
+
+    fn block_mergeset_sizes(
+        network: &GhostDAGNetwork,
+        block_index: usize,
+    ) -> Option<(usize, usize)> {
+        let data = &network.resource.blocks[block_index].ghostdag_data;
+        Some((data.mergeset_blues.len(), data.mergeset_reds.len()))
+    }
+
+    fn is_block_blue(
+        network: &GhostDAGNetwork,
+        node_index: usize,
+        block_index: usize,
+    ) -> Option<bool> {
+        network.ecs.consensus_algorithm[node_index]
+            .colors
+            .get(&block_index)
+            .copied()
+    }
+}
+
+impl GhostDAGNetwork {
+    pub fn new_with_size(
+        num_of_nodes: usize,
+        average_num_of_blocks: usize,
+        config: GhostDAGConsensusConfig,
+        num_of_miners: usize,
+        block_size: u64,
+    ) -> Self {
+        let protocol = GhostDAGProtocol {
+            profile: BitcoinProfile::default(),
+            block_size,
+        };
+        Self::new(
+            num_of_nodes,
+            average_num_of_blocks,
+            config,
+            num_of_miners,
+            protocol,
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use crate::consensus::algorithm::ghostdag_consensus::GhostDAGConsensus;
+use crate::ledger_data::block_factory::GHOSTDAG_PARENT_HASH_SIZE;
+use crate::ledger_data::ghostdag_block::GhostDAGBlock;
+use crate::network::ghostdag_network::GhostDAGNetwork;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::DataMessage;
+use crate::network::Network;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
+use crate::simulator::event::block_mining_process::BlockMiningProcess;
+use crate::simulator::event::generate_block_event::GenerateBlockWithoutTxEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+// Block mining methods and associated functions:
+impl GhostDAGNetwork {
+    pub fn mine_new_block(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        miner: usize,
+    ) {
+        // generate new block
+        let generate_block_event = Box::new(GenerateBlockWithoutTxEvent::new(miner));
+        simulator.put_event(generate_block_event, 0.0);
+
+        self.schedule_next_mining(miner, simulator, rand);
+    }
+
+    pub(crate) fn schedule_next_mining(
+        &self,
+        miner: usize,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        let average_time_between_generation: f64 =
+            self.resource.config.difficulty / self.ecs.hash_power[miner].unwrap();
+        let time_to_next_generation =
+            rand.sample_exponential_distribution(average_time_between_generation);
+        let block_mining_process = BlockMiningProcess::new(miner);
+        simulator.put_event(Box::new(block_mining_process), time_to_next_generation);
+    }
+
+    /// Creates a block referencing all tips of the local block DAG of the
+    /// miner, colors its past and receives it at the miner.
+    pub fn generate_new_block_and_receive_it(&mut self, simulator: &mut Simulator, node: usize) {
+        let new_block_index = self.resource.blocks.len();
+        let mut parents: Vec<usize> = self.ecs.local_ledger[node].tips.iter().copied().collect();
+        parents.sort_unstable();
+
+        let ghostdag_data = GhostDAGConsensus::ghostdag_data(
+            &parents,
+            &self.resource.blocks,
+            self.resource.config.k,
+        );
+        let size = self.protocol.block_size + parents.len() as u64 * GHOSTDAG_PARENT_HASH_SIZE;
+        let block = GhostDAGBlock::new_with_parents(
+            simulator.simulation_time,
+            Some(node),
+            parents,
+            size,
+            ghostdag_data,
+            &self.resource.blocks,
+        );
+        self.resource.blocks.push(block);
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(new_block_index, node)),
+            0.0,
+        );
+
+        let receive_at_this_node =
+            ReceiveEvent::new(new_block_index, node, node, DataMessage(IsBlock));
+        self.receive(simulator, &receive_at_this_node);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ledger_data::ghostdag_block::GhostDAGBlock;
+use crate::network::ghostdag_network::GhostDAGNetwork;
+use crate::network::node::connection::set_all_nodes_connected;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::{
+    reset_and_sample_all_bitcoin_miners_hash_power, sample_bitcoin_miner_nodes,
+    sample_bitcoin_node_regions,
+};
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+impl GhostDAGNetwork {
+    /// Prepares the network. Miners, their regions and hash powers are
+    /// sampled as in the Bitcoin network, with the hash power scaled to the
+    /// average block interval of the config.
+    pub fn prepare(
+        &mut self,
+        rand: &mut RandomnessEngine,
+        min_neighbors: usize,
+        num_of_miners: usize,
+    ) {
+        let num_of_nodes = self.ecs.num_of_nodes;
+        self.configure_consensus();
+
+        sample_bitcoin_miner_nodes(&mut self.resource.miners, rand, num_of_nodes, num_of_miners);
+        sample_bitcoin_node_regions(
+            &mut self.ecs.region,
+            &self.resource.miners,
+            self.resource.network_stats.as_ref(),
+            rand,
+            num_of_nodes,
+            self.resource.num_of_miners(),
+        );
+        set_all_nodes_connected(&mut self.ecs.is_connected, num_of_nodes);
+        self.assign_neighbors(rand, min_neighbors);
+        self.assign_ledgers_and_links(rand, GhostDAGBlock::generate_genesis_block());
+
+        reset_and_sample_all_bitcoin_miners_hash_power(
+            &self.resource.miners,
+            &mut self.ecs.hash_power,
+            &self.protocol.profile,
+            rand,
+            self.resource.config.average_block_mining_interval,
+            self.resource.config.difficulty,
+        );
+    }
+
+    pub(crate) fn insert_initial_event(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        for &miner in &self.resource.miners {
+            self.schedule_next_mining(miner, simulator, rand);
+        }
+    }
+}
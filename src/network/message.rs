@@ -1,6 +1,7 @@
-use crate::ledger_data::bitcoin_block::BitcoinBlock;
+use crate::ledger_data::block::Block;
 use crate::ledger_data::block_factory::{
-    BITCOIN_INV_SIZE, GET_DATA_OVERHEAD, INV_MESSAGE_OVERHEAD,
+    BITCOIN_BLOCK_HEADER_SIZE, BITCOIN_INV_SIZE, ETHEREUM_ATTESTATION_SIZE, GET_DATA_OVERHEAD,
+    HEADERS_MESSAGE_OVERHEAD, INV_MESSAGE_OVERHEAD,
 };
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
@@ -14,22 +15,63 @@ pub enum MessageType {
     DataMessage(DataType),
     InvMessage(DataType),
     RequestDataMessage(DataType),
+    HeadersMessage,
     VoteMessage,
 }
 
+/// The protocol used to relay new blocks to the neighbors.
+#[derive(Debug, Default, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum RelayProtocol {
+    /// Blocks are announced with inv, then requested with getdata.
+    #[default]
+    Inv,
+    /// Blocks are announced with their header (`sendheaders`), then requested
+    /// with getdata.
+    HeadersFirst,
+    /// Full blocks are pushed unsolicited to the first `num_of_push_peers`
+    /// neighbors (all of them if `None`), and announced with inv to the rest.
+    UnsolicitedPush { num_of_push_peers: Option<usize> },
+}
+
+impl RelayProtocol {
+    /// Returns the message which announces a new block to the `i`-th neighbor.
+    pub fn block_announcement(&self, i: usize) -> MessageType {
+        match self {
+            Self::Inv => MessageType::InvMessage(DataType::IsBlock),
+            Self::HeadersFirst => MessageType::HeadersMessage,
+            Self::UnsolicitedPush { num_of_push_peers } => {
+                if num_of_push_peers.is_none_or(|n| i < n) {
+                    MessageType::DataMessage(DataType::IsBlock)
+                } else {
+                    MessageType::InvMessage(DataType::IsBlock)
+                }
+            }
+        }
+    }
+}
+
 impl MessageType {
-    pub fn get_size(&self, block_index: usize, blocks: &[BitcoinBlock]) -> u64 {
+    /// Returns the size of the message in bytes. For a `VoteMessage`, the
+    /// `block_index` is the index of the vote and is not used.
+    pub fn get_size<B: Block>(&self, block_index: usize, blocks: &[B]) -> u64 {
+        self.get_size_with_block_size(|| blocks[block_index].get_size())
+    }
+
+    /// Returns the size of the message in bytes, with `block_size` only called
+    /// for a message carrying a full block.
+    pub fn get_size_with_block_size(&self, block_size: impl FnOnce() -> u64) -> u64 {
         match self {
-            Self::DataMessage(dt) => Self::bitcoin_data_size(dt, block_index, blocks),
+            Self::DataMessage(dt) => Self::bitcoin_data_size(dt, block_size),
             Self::InvMessage(_) => Self::bitcoin_inv_size(),
             Self::RequestDataMessage(_) => Self::bitcoin_request_data_size(),
-            Self::VoteMessage => u64::default(), // never should be used for bitcoin.
+            Self::HeadersMessage => Self::bitcoin_headers_size(),
+            Self::VoteMessage => ETHEREUM_ATTESTATION_SIZE,
         }
     }
 
-    fn bitcoin_data_size(data_type: &DataType, block_index: usize, blocks: &[BitcoinBlock]) -> u64 {
+    fn bitcoin_data_size(data_type: &DataType, block_size: impl FnOnce() -> u64) -> u64 {
         match data_type {
-            DataType::IsBlock => blocks[block_index].size,
+            DataType::IsBlock => block_size(),
             DataType::IsTx => 1_u64, // todo
         }
     }
@@ -41,4 +83,8 @@ impl MessageType {
     fn bitcoin_request_data_size() -> u64 {
         BITCOIN_INV_SIZE + GET_DATA_OVERHEAD
     }
+
+    fn bitcoin_headers_size() -> u64 {
+        BITCOIN_BLOCK_HEADER_SIZE + HEADERS_MESSAGE_OVERHEAD
+    }
 }
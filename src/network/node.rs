@@ -4,7 +4,11 @@ use std::collections::HashMap;
 
 pub mod connection;
 pub mod link;
+pub mod mining_pool;
 pub mod neighbors;
+pub mod node_type;
+pub mod relay_overlay;
+pub mod validation;
 
 //----------Components----------//
 
@@ -59,9 +63,30 @@ pub struct Downlink {
 #[derive(Default, Debug, Clone)]
 pub struct AlreadySeenBlocks(pub HashMap<usize, bool>);
 
-/// Type of each node.(WIP)
-// #[derive(Default, Debug, Clone)]
+/// Role of each node, see [`NodeTypeConfig`].
+///
+/// [`NodeTypeConfig`]: crate::network::node::node_type::NodeTypeConfig
+#[derive(Default, Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub enum NodeType {
-    IsBitcoin,
-    IsEthereum,
+    /// A full node with hash power.
+    Miner,
+    /// A full node without hash power which relays every new block.
+    #[default]
+    RelayNode,
+    /// A pruned or listening-only full node: it downloads and validates
+    /// full blocks but does not announce them to its neighbors.
+    ListeningNode,
+    /// A light client which downloads only block headers. It neither
+    /// validates nor relays blocks.
+    SPVClient,
+}
+
+impl NodeType {
+    pub fn relays_blocks(&self) -> bool {
+        matches!(self, Self::Miner | Self::RelayNode)
+    }
+
+    pub fn validates_blocks(&self) -> bool {
+        !matches!(self, Self::SPVClient)
+    }
 }
@@ -1,16 +1,115 @@
 use super::{Downlink, Link, Uplink};
-use crate::network::stats::eighty_six_countries::{
-    sample_download_bandwidth, sample_upload_bandwidth, Country,
-};
+use crate::network::stats::NetworkStats;
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
 
 const BITS_PER_BYTE: u64 = 8;
 
+/// The model used to compute the time a data transfer occupies a link.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TransferModel {
+    /// Transfer time is `size * 8 / bandwidth`.
+    #[default]
+    Bandwidth,
+    /// Flow-level TCP model with handshake, slow start and a window limit.
+    TcpFlow(TcpFlowConfig),
+}
+
+impl TransferModel {
+    /// Returns the time needed to transfer `size` bytes over a path with the
+    /// bottleneck `bandwidth` in bits per second and the round trip time `rtt`
+    /// in seconds.
+    pub fn transfer_time(&self, size: u64, bandwidth: f64, rtt: f64) -> f64 {
+        match self {
+            Self::Bandwidth => ((size * BITS_PER_BYTE) as f64) / bandwidth,
+            Self::TcpFlow(tcp) => tcp.transfer_time(size, bandwidth, rtt),
+        }
+    }
+}
+
+/// Parameters of the flow-level TCP throughput model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TcpFlowConfig {
+    /// Number of round trips spent before the first data segment is sent. Peers
+    /// keep their connections open, so there is no handshake by default.
+    pub handshake_rtts: f64,
+    /// Maximum segment size in bytes.
+    pub max_segment_size: u64,
+    /// Initial congestion window in segments.
+    pub initial_window: u64,
+    /// Maximum window (the smaller of receive and congestion window) in bytes.
+    pub max_window: u64,
+}
+
+impl Default for TcpFlowConfig {
+    fn default() -> Self {
+        Self {
+            handshake_rtts: 0.0,
+            max_segment_size: 1460,
+            initial_window: 10,
+            max_window: 4 * 1024 * 1024,
+        }
+    }
+}
+
+impl TcpFlowConfig {
+    /// Returns the time needed to transfer `size` bytes over a single TCP flow.
+    /// The one-way propagation delay of the last segment is not included, as it
+    /// is added separately as the delivery delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: transferred data in bytes
+    /// * `bandwidth`: bottleneck bandwidth of the path in bits per second
+    /// * `rtt`: round trip time of the path in seconds
+    ///
+    pub fn transfer_time(&self, size: u64, bandwidth: f64, rtt: f64) -> f64 {
+        let serialization_time = |bytes: f64| bytes * (BITS_PER_BYTE as f64) / bandwidth;
+        let mss = self.max_segment_size.max(1);
+
+        // the window can not grow beyond the bandwidth-delay product or the window limit.
+        let bdp_segments = ((bandwidth * rtt) / ((BITS_PER_BYTE * mss) as f64)) as u64;
+        let max_window_segments = (self.max_window / mss).max(1);
+        let window_cap = bdp_segments.clamp(1, max_window_segments);
+
+        let mut time = self.handshake_rtts * rtt;
+        let mut remaining = size as f64;
+        let mut window = self.initial_window.max(1);
+
+        // slow start: the window doubles each round trip until it reaches the cap.
+        while window < window_cap {
+            let window_bytes = (window * mss) as f64;
+            if remaining <= window_bytes {
+                return time + serialization_time(remaining);
+            }
+            time += rtt.max(serialization_time(window_bytes));
+            remaining -= window_bytes;
+            window = window.saturating_mul(2);
+        }
+
+        // steady state: full windows of `window_cap` segments per round trip.
+        let window_bytes = (window_cap * mss) as f64;
+        let full_rounds = ((remaining / window_bytes).ceil() - 1.0).max(0.0);
+        time += full_rounds * rtt.max(serialization_time(window_bytes));
+        remaining -= full_rounds * window_bytes;
+        time + serialization_time(remaining)
+    }
+}
+
 pub fn remaining_time_to_load(link: &mut Link, simulator: &Simulator, size: u64) -> f64 {
     let loading_time = ((size * BITS_PER_BYTE) as f64) / link.bandwidth;
+    remaining_time_to_transfer(link, simulator, loading_time)
+}
+
+/// Occupies the link for `transfer_time` after its previous transfers are done
+/// and returns the remaining time from now until this transfer finishes.
+pub fn remaining_time_to_transfer(
+    link: &mut Link,
+    simulator: &Simulator,
+    transfer_time: f64,
+) -> f64 {
     let start_time = link.latest_loaded_time_done.max(simulator.simulation_time);
-    let end_time = start_time + loading_time;
+    let end_time = start_time + transfer_time;
     link.latest_loaded_time_done = end_time;
     end_time - simulator.simulation_time
 }
@@ -18,14 +117,15 @@ pub fn remaining_time_to_load(link: &mut Link, simulator: &Simulator, size: u64)
 pub fn assign_all_bandwidths(
     uplink: &mut [Uplink],
     downlink: &mut [Downlink],
-    country: &[Country],
+    region: &[usize],
+    network_stats: &dyn NetworkStats,
     rand: &mut RandomnessEngine,
     size: usize,
 ) {
     assert_eq!(uplink.len(), size);
     assert_eq!(downlink.len(), size);
     for i in 0..size {
-        uplink[i].link.bandwidth = sample_upload_bandwidth(country[i], rand);
-        downlink[i].link.bandwidth = sample_download_bandwidth(country[i], rand);
+        uplink[i].link.bandwidth = network_stats.sample_upload_bandwidth(region[i], rand);
+        downlink[i].link.bandwidth = network_stats.sample_download_bandwidth(region[i], rand);
     }
 }
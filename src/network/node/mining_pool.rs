@@ -0,0 +1,89 @@
+use crate::simulator::randomness_engine::RandomnessEngine;
+
+/// Parameters of the mining pools. When set, every miner is a pool operator
+/// whose hashers mine on the work they have received through stratum. A
+/// pool-to-pool relay network is a relay overlay among the miners, see
+/// [`RelayOverlayConfig`].
+///
+/// [`RelayOverlayConfig`]: crate::network::node::relay_overlay::RelayOverlayConfig
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MiningPoolConfig {
+    pub hashers_per_pool: usize,
+    /// Median of the log-normal stratum latency between operator and hasher in
+    /// seconds.
+    pub stratum_latency_median: f64,
+    pub stratum_latency_stddev: f64,
+}
+
+impl Default for MiningPoolConfig {
+    fn default() -> Self {
+        Self {
+            hashers_per_pool: 100,
+            stratum_latency_median: 0.1,
+            stratum_latency_stddev: 0.5,
+        }
+    }
+}
+
+/// A hasher connected to the pool operator.
+#[derive(Debug, Clone)]
+pub struct PoolHasher {
+    /// Share of the pool hash power.
+    pub hash_share: f64,
+    pub stratum_latency: f64,
+    /// The block index that the hasher is currently mining on.
+    pub current_work: usize,
+}
+
+/// Mining pool component of a pool operator node.
+#[derive(Default, Debug, Clone)]
+pub struct MiningPool {
+    pub hashers: Vec<PoolHasher>,
+}
+
+impl MiningPool {
+    pub fn new(
+        config: &MiningPoolConfig,
+        genesis_block_index: usize,
+        rand: &mut RandomnessEngine,
+    ) -> Self {
+        let weights: Vec<f64> = (0..config.hashers_per_pool)
+            .map(|_| rand.sample_exponential_distribution_mean_1())
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        let hashers = weights
+            .iter()
+            .map(|weight| PoolHasher {
+                hash_share: weight / total_weight,
+                stratum_latency: rand.sample_log_normal_distribution(
+                    config.stratum_latency_median,
+                    config.stratum_latency_stddev,
+                ),
+                current_work: genesis_block_index,
+            })
+            .collect();
+        Self { hashers }
+    }
+
+    /// Samples the hasher who found the block, weighted by its hash share.
+    pub fn sample_block_finder(&self, rand: &mut RandomnessEngine) -> usize {
+        let shares: Vec<f64> = self.hashers.iter().map(|h| h.hash_share).collect();
+        rand.sample_from_distribution(&shares)
+    }
+}
+
+pub fn assign_mining_pools(
+    mining_pool: &mut [Option<MiningPool>],
+    miners: &[usize],
+    config: &MiningPoolConfig,
+    genesis_block_index: usize,
+    rand: &mut RandomnessEngine,
+) {
+    assert!(
+        config.hashers_per_pool > 0,
+        "Error: a mining pool needs at least one hasher."
+    );
+    for &miner in miners {
+        mining_pool[miner] = Some(MiningPool::new(config, genesis_block_index, rand));
+    }
+}
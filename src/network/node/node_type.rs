@@ -0,0 +1,55 @@
+use crate::network::node::NodeType;
+use crate::simulator::randomness_engine::RandomnessEngine;
+
+/// Number of nodes of each role besides miners and relay nodes. Miners are
+/// the nodes with hash power; the non-mining nodes not picked as listening
+/// nodes or SPV clients are relay nodes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeTypeConfig {
+    pub num_of_listening_nodes: usize,
+    pub num_of_spv_clients: usize,
+}
+
+impl NodeTypeConfig {
+    pub fn new(num_of_listening_nodes: usize, num_of_spv_clients: usize) -> Self {
+        Self {
+            num_of_listening_nodes,
+            num_of_spv_clients,
+        }
+    }
+}
+
+/// Marks the miners, then samples the listening nodes and the SPV clients
+/// among the other nodes.
+pub fn assign_node_types(
+    node_type: &mut [NodeType],
+    miners: &[usize],
+    config: &NodeTypeConfig,
+    rand: &mut RandomnessEngine,
+) {
+    for &miner in miners {
+        node_type[miner] = NodeType::Miner;
+    }
+
+    let num_of_light_nodes = config.num_of_listening_nodes + config.num_of_spv_clients;
+    if num_of_light_nodes == 0 {
+        return;
+    }
+
+    let non_miners: Vec<usize> = (0..node_type.len())
+        .filter(|&node| node_type[node] != NodeType::Miner)
+        .collect();
+    assert!(
+        num_of_light_nodes <= non_miners.len(),
+        "Error: not enough non-mining nodes for the listening nodes and SPV clients."
+    );
+
+    let sampled = rand.sample_nodes_bigger_than_size(&non_miners, num_of_light_nodes);
+    let (spv_clients, listening_nodes) = sampled.split_at(config.num_of_spv_clients);
+    for &node in spv_clients {
+        node_type[node] = NodeType::SPVClient;
+    }
+    for &node in listening_nodes {
+        node_type[node] = NodeType::ListeningNode;
+    }
+}
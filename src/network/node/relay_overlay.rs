@@ -0,0 +1,67 @@
+use crate::network::node::link::TransferModel;
+
+/// The nodes which are members of the relay overlay.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelayOverlayMembers {
+    /// All miners (or mining pool operators).
+    Miners,
+    /// An explicit list of node indices.
+    Nodes(Vec<usize>),
+}
+
+/// A dedicated low-latency, high-bandwidth relay network (FIBRE-like) among a
+/// subset of nodes. The first member to have a new block, by creating or
+/// receiving it over P2P, forwards it unsolicited to all other members over
+/// their own links, in parallel with the normal P2P gossip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelayOverlayConfig {
+    pub members: RelayOverlayMembers,
+    /// Fixed one-way latency of the overlay links in seconds.
+    pub latency: f64,
+    /// Bandwidth of the overlay links in bits per second.
+    pub bandwidth: f64,
+}
+
+impl Default for RelayOverlayConfig {
+    fn default() -> Self {
+        Self {
+            members: RelayOverlayMembers::Miners,
+            latency: 0.02,
+            bandwidth: 1_000_000_000.0,
+        }
+    }
+}
+
+impl RelayOverlayConfig {
+    /// Returns the time from sending data of `size` bytes over an overlay link
+    /// until it is received, with the transfer model of the network. The round
+    /// trip time of an overlay link is twice its latency. Overlay links are
+    /// dedicated, so there is no queueing behind other transfers.
+    pub fn delivery_delay(&self, size: u64, transfer_model: &TransferModel) -> f64 {
+        self.latency + transfer_model.transfer_time(size, self.bandwidth, 2.0 * self.latency)
+    }
+}
+
+/// Marks the members of the relay overlay. Returns an error if a member is
+/// not a node of the network.
+pub fn assign_relay_overlay_members(
+    relay_overlay_member: &mut [bool],
+    miners: &[usize],
+    config: &RelayOverlayConfig,
+) -> Result<(), String> {
+    let members: &[usize] = match &config.members {
+        RelayOverlayMembers::Miners => miners,
+        RelayOverlayMembers::Nodes(nodes) => nodes,
+    };
+    let num_of_nodes = relay_overlay_member.len();
+    if let Some(member) = members.iter().find(|&&member| member >= num_of_nodes) {
+        return Err(format!(
+            "Index out of bounds for relay overlay member: {} (number of nodes: {})",
+            member, num_of_nodes
+        ));
+    }
+    for &member in members {
+        relay_overlay_member[member] = true;
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::network::node::NodeType;
+
+/// Average size of a Bitcoin transaction in bytes, used to estimate the number
+/// of transactions of a block from its size.
+pub const BITCOIN_AVERAGE_TX_SIZE: u64 = 500;
+
+/// The time a node spends to validate a received block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationDelay {
+    /// Constant validation time in seconds.
+    Fixed(f64),
+    /// `base + size * per_byte + tx_count * per_tx` seconds, where the number
+    /// of transactions is estimated from the block size.
+    Proportional {
+        base: f64,
+        per_byte: f64,
+        per_tx: f64,
+    },
+}
+
+impl Default for ValidationDelay {
+    fn default() -> Self {
+        Self::Fixed(0.0)
+    }
+}
+
+impl ValidationDelay {
+    pub fn validation_time(&self, block_size: u64) -> f64 {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Proportional {
+                base,
+                per_byte,
+                per_tx,
+            } => {
+                let tx_count = block_size / BITCOIN_AVERAGE_TX_SIZE;
+                base + (block_size as f64) * per_byte + (tx_count as f64) * per_tx
+            }
+        }
+    }
+}
+
+/// Block validation delays per node type, for the size of the block with all
+/// its transactions. A node validates one block at a time, so blocks received
+/// while it is busy wait for the previous ones. SPV clients do not validate
+/// blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ValidationConfig {
+    pub miner_delay: ValidationDelay,
+    pub relay_node_delay: ValidationDelay,
+    pub listening_node_delay: ValidationDelay,
+    /// Announce blocks to the neighbors right after download, before the full
+    /// validation is done (as in high-bandwidth compact block relay). The
+    /// consensus is still updated only after validation.
+    pub relay_before_validation: bool,
+}
+
+impl ValidationConfig {
+    pub fn validation_time(&self, node_type: NodeType, block_size: u64) -> f64 {
+        match node_type {
+            NodeType::Miner => self.miner_delay.validation_time(block_size),
+            NodeType::RelayNode => self.relay_node_delay.validation_time(block_size),
+            NodeType::ListeningNode => self.listening_node_delay.validation_time(block_size),
+            NodeType::SPVClient => 0.0,
+        }
+    }
+}
@@ -0,0 +1,353 @@
+mod p2p_chain_reorg;
+mod p2p_receive;
+mod p2p_relay_overlay;
+pub(crate) mod p2p_scenarios_preparation;
+mod p2p_send;
+mod p2p_validation;
+
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::ledger_data::block::Block;
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::log::NetworkLogHandler;
+use crate::network::ecs::p2p_ecs::P2PECS;
+use crate::network::message::MessageType::{
+    DataMessage, HeadersMessage, InvMessage, RequestDataMessage, VoteMessage,
+};
+use crate::network::node::connection::node_is_connected;
+use crate::network::node::NodeType;
+use crate::network::resource::p2p_resource::P2PResource;
+use crate::network::Network;
+use crate::simulator::event::block_mining_process::BlockMiningProcess;
+use crate::simulator::event::block_validation_event::BlockValidationEvent;
+use crate::simulator::event::generate_block_event::GenerateBlockWithoutTxEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::any::Any;
+
+/// A peer-to-peer network of nodes running the consensus `C`. The network
+/// relays blocks between neighbors, with the relay protocol, relay overlay,
+/// validation delays and node types of its resource, and leaves block
+/// creation, votes and its own events to the protocol `P`.
+pub struct P2PNetwork<C: DAGBasedConsensus, P> {
+    pub ecs: P2PECS<C>,
+    pub resource: P2PResource<C>,
+    pub protocol: P,
+}
+
+/// The part of a [`P2PNetwork`] specific to a protocol: how blocks are
+/// created, how votes are exchanged and what the loggers can read of its
+/// consensus. Every hook has a default for protocols not using it.
+pub trait NetworkProtocol<C: DAGBasedConsensus>: Sized {
+    /// Creates a new block at the node.
+    fn generate_block(
+        _network: &mut P2PNetwork<C, Self>,
+        _simulator: &mut Simulator,
+        _rand: &mut RandomnessEngine,
+        _node: usize,
+    ) {
+    }
+
+    /// The miner found a block: generates it and schedules the next one.
+    fn mine_block(
+        _network: &mut P2PNetwork<C, Self>,
+        _simulator: &mut Simulator,
+        _rand: &mut RandomnessEngine,
+        _miner: usize,
+    ) {
+    }
+
+    /// Passes a received block, connected to genesis, to the consensus of the
+    /// node.
+    fn accept_block(
+        network: &mut P2PNetwork<C, Self>,
+        node: usize,
+        block_index: usize,
+        simulator: &mut Simulator,
+    ) {
+        network.update_consensus(node, block_index, simulator);
+    }
+
+    /// Processes a vote received by a node.
+    fn receive_vote(
+        _network: &mut P2PNetwork<C, Self>,
+        _simulator: &mut Simulator,
+        _receive_event: &ReceiveEvent,
+    ) {
+    }
+
+    /// Returns the nodes a vote is uploaded to: the neighbors of the sender,
+    /// except the node `send_event.from` which had sent it to the sender.
+    fn vote_recipients(network: &P2PNetwork<C, Self>, send_event: &SendEvent) -> Vec<usize> {
+        network.ecs.neighbors[send_event.node]
+            .0
+            .iter()
+            .filter(|&&neighbor| neighbor != send_event.from)
+            .copied()
+            .collect()
+    }
+
+    /// Returns the size of a vote in bytes.
+    fn vote_size(_network: &P2PNetwork<C, Self>, _vote_index: usize) -> u64 {
+        VoteMessage.get_size_with_block_size(|| 0)
+    }
+
+    /// Executes an event of the protocol, see [`Network::protocol_event`].
+    fn handle_event(
+        _network: &mut P2PNetwork<C, Self>,
+        _simulator: &mut Simulator,
+        _rand: &mut RandomnessEngine,
+        _event: &dyn Any,
+    ) {
+    }
+
+    // log handler methods, see [`NetworkLogHandler`]:
+    fn canonical_chain_head(_network: &P2PNetwork<C, Self>, _node_index: usize) -> Option<usize> {
+        None
+    }
+    fn is_microblock(_network: &P2PNetwork<C, Self>, _block_index: usize) -> bool {
+        false
+    }
+    fn block_of_slot(_network: &P2PNetwork<C, Self>, _slot: u64) -> Option<usize> {
+        None
+    }
+    fn block_blue_score(_network: &P2PNetwork<C, Self>, _block_index: usize) -> Option<u64> {
+        None
+    }
+    fn block_mergeset_sizes(
+        _network: &P2PNetwork<C, Self>,
+        _block_index: usize,
+    ) -> Option<(usize, usize)> {
+        None
+    }
+    fn is_block_blue(
+        _network: &P2PNetwork<C, Self>,
+        _node_index: usize,
+        _block_index: usize,
+    ) -> Option<bool> {
+        None
+    }
+    fn block_reorg_before(
+        _network: &P2PNetwork<C, Self>,
+        _reorg_logger: &mut BlockchainReorgLogger,
+        _block_index: usize,
+        _node_index: usize,
+    ) {
+    }
+    fn block_reorg_after(
+        _network: &P2PNetwork<C, Self>,
+        _reorg_logger: &mut BlockchainReorgLogger,
+    ) -> bool {
+        false
+    }
+    fn block_reorg_output_length(
+        _network: &P2PNetwork<C, Self>,
+        _reorg_logger: &BlockchainReorgLogger,
+        _previous_head: usize,
+        _node_index: usize,
+    ) -> i32 {
+        0
+    }
+}
+
+impl<C: DAGBasedConsensus, P: NetworkProtocol<C>> Network for P2PNetwork<C, P> {
+    fn generate_new_block_without_tx(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        generate_event: &GenerateBlockWithoutTxEvent,
+    ) {
+        let node = generate_event.node;
+        if !node_is_connected(&self.ecs.is_connected, node) {
+            return;
+        }
+
+        P::generate_block(self, simulator, rand, node);
+    }
+
+    fn receive(&mut self, simulator: &mut Simulator, receive_event: &ReceiveEvent) {
+        let node = receive_event.node;
+        if !node_is_connected(&self.ecs.is_connected, node) {
+            return;
+        }
+
+        match receive_event.msg_type {
+            DataMessage(_) => self.process_data_message(simulator, receive_event),
+            // a header is all an SPV client downloads of a block.
+            HeadersMessage if self.ecs.node_type[node] == NodeType::SPVClient => {
+                self.process_data_message(simulator, receive_event)
+            }
+            InvMessage(_) | HeadersMessage => self.process_inv_message(simulator, receive_event),
+            RequestDataMessage(_) => self.process_request_data_message(simulator, receive_event),
+            VoteMessage => P::receive_vote(self, simulator, receive_event),
+        }
+    }
+
+    fn send(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        send_event: &SendEvent,
+    ) {
+        let node = send_event.node;
+        if !node_is_connected(&self.ecs.is_connected, node) {
+            return;
+        }
+
+        match send_event.msg_type {
+            InvMessage(_) => self.send_inv_to_neighbors(simulator, rand, send_event),
+            DataMessage(_) | RequestDataMessage(_) | HeadersMessage => {
+                self.simulate_upload(simulator, rand, send_event.from, send_event);
+            }
+            VoteMessage => {
+                for to in P::vote_recipients(self, send_event) {
+                    self.simulate_upload(simulator, rand, to, send_event);
+                }
+            }
+        }
+    }
+
+    fn block_mining(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        mining_event: &BlockMiningProcess,
+    ) {
+        let miner = mining_event.miner;
+        if !node_is_connected(&self.ecs.is_connected, miner) {
+            return;
+        }
+
+        P::mine_block(self, simulator, rand, miner);
+    }
+
+    fn protocol_event(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        event: &dyn Any,
+    ) {
+        match event.downcast_ref::<BlockValidationEvent>() {
+            Some(validation_event) => {
+                if node_is_connected(&self.ecs.is_connected, validation_event.node) {
+                    self.finish_block_validation(simulator, validation_event);
+                }
+            }
+            None => P::handle_event(self, simulator, rand, event),
+        }
+    }
+}
+
+impl<C: DAGBasedConsensus, P: NetworkProtocol<C>> NetworkLogHandler for P2PNetwork<C, P> {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        self.resource.blocks[block_index].get_creation_time()
+    }
+
+    fn get_block_creator(&self, block_index: usize) -> Option<usize> {
+        self.resource.blocks[block_index].get_creator()
+    }
+
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        self.resource.blocks[block_index].get_height()
+    }
+
+    fn get_block_size(&self, block_index: usize) -> u64 {
+        self.resource.blocks[block_index].get_size()
+    }
+
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        self.resource.blocks[block_index].get_parents()
+    }
+
+    fn get_num_of_nodes(&self) -> usize {
+        self.ecs.num_of_nodes
+    }
+
+    fn get_vote_size(&self, vote_index: usize) -> u64 {
+        P::vote_size(self, vote_index)
+    }
+
+    fn get_node_type(&self, node_index: usize) -> Option<NodeType> {
+        self.ecs.node_type.get(node_index).copied()
+    }
+
+    fn get_node_hash_power(&self, node_index: usize) -> Option<f64> {
+        self.ecs.hash_power.get(node_index).copied().flatten()
+    }
+
+    fn get_canonical_chain_head(&self, node_index: usize) -> Option<usize> {
+        P::canonical_chain_head(self, node_index)
+    }
+
+    fn is_microblock(&self, block_index: usize) -> bool {
+        P::is_microblock(self, block_index)
+    }
+
+    fn get_block_of_slot(&self, slot: u64) -> Option<usize> {
+        P::block_of_slot(self, slot)
+    }
+
+    fn get_block_blue_score(&self, block_index: usize) -> Option<u64> {
+        P::block_blue_score(self, block_index)
+    }
+
+    fn get_block_mergeset_sizes(&self, block_index: usize) -> Option<(usize, usize)> {
+        P::block_mergeset_sizes(self, block_index)
+    }
+
+    fn is_block_blue(&self, node_index: usize, block_index: usize) -> Option<bool> {
+        P::is_block_blue(self, node_index, block_index)
+    }
+
+    fn block_reorg_before(
+        &self,
+        reorg_logger: &mut BlockchainReorgLogger,
+        block_index: &usize,
+        node_index: &usize,
+    ) {
+        P::block_reorg_before(self, reorg_logger, *block_index, *node_index);
+    }
+
+    fn block_reorg_after(&self, reorg_logger: &mut BlockchainReorgLogger) -> bool {
+        P::block_reorg_after(self, reorg_logger)
+    }
+
+    fn block_reorg_output_length(
+        &self,
+        reorg_logger: &BlockchainReorgLogger,
+        previous_head: usize,
+        node_index: &usize,
+    ) -> i32 {
+        P::block_reorg_output_length(self, reorg_logger, previous_head, *node_index)
+    }
+}
+
+impl<C: DAGBasedConsensus + Clone, P> P2PNetwork<C, P> {
+    pub fn new(
+        num_of_nodes: usize,
+        average_num_of_blocks: usize,
+        config: C::G,
+        num_of_miners: usize,
+        protocol: P,
+    ) -> Self {
+        Self {
+            ecs: P2PECS::create_with_size(num_of_nodes, &config),
+            resource: P2PResource::new(average_num_of_blocks, config, num_of_miners),
+            protocol,
+        }
+    }
+}
+
+impl<C: DAGBasedConsensus, P> P2PNetwork<C, P> {
+    /// Updates the consensus of the node with a new block.
+    pub fn update_consensus(&mut self, node: usize, block_index: usize, simulator: &mut Simulator) {
+        self.ecs.consensus_algorithm[node].new_incoming_block(
+            block_index,
+            &self.resource.blocks,
+            &self.resource.config,
+            &self.ecs.local_ledger[node],
+            simulator,
+        );
+    }
+}
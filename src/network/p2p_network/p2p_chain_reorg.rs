@@ -0,0 +1,65 @@
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::ledger_data::block::Block;
+use crate::ledger_data::single_parent::SingleParent;
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::network::p2p_network::P2PNetwork;
+
+// Reorg logger methods of chain-based consensus algorithms:
+impl<C, P> P2PNetwork<C, P>
+where
+    C: ChainBasedConsensus,
+    C::B: SingleParent,
+{
+    pub fn chain_reorg_before(
+        &self,
+        reorg_logger: &mut BlockchainReorgLogger,
+        block_index: usize,
+        node: usize,
+    ) {
+        reorg_logger
+            .network_view_block_tree
+            .add(block_index, &self.resource.blocks);
+        reorg_logger.previous_head_chain_index =
+            Some(self.ecs.consensus_algorithm[node].get_canonical_chain_head_index());
+        reorg_logger.current_node_index = Some(node);
+        reorg_logger.new_block_received = true;
+    }
+
+    pub fn chain_reorg_after(&self, reorg_logger: &mut BlockchainReorgLogger) -> bool {
+        if reorg_logger.new_block_received {
+            reorg_logger.new_block_received = false;
+            if let Some(node_index) = reorg_logger.current_node_index {
+                let current_head_chain_index =
+                    self.ecs.consensus_algorithm[node_index].get_canonical_chain_head_index();
+                if let Some(previous_head_index) = reorg_logger.previous_head_chain_index {
+                    let ancestor_index = reorg_logger
+                        .network_view_block_tree
+                        .get_single_ancestor_of_height(
+                            current_head_chain_index,
+                            self.resource.blocks[previous_head_index].get_height(),
+                            &self.resource.blocks,
+                        );
+                    return ancestor_index != reorg_logger.previous_head_chain_index;
+                }
+            }
+        }
+        false
+    }
+
+    pub fn chain_reorg_output_length(
+        &self,
+        reorg_logger: &BlockchainReorgLogger,
+        previous_head: usize,
+        node_index: usize,
+    ) -> i32 {
+        let node_chain_head =
+            self.ecs.consensus_algorithm[node_index].get_canonical_chain_head_index();
+        let common_ancestor = reorg_logger.network_view_block_tree.get_common_ancestor(
+            node_chain_head,
+            previous_head,
+            &self.resource.blocks,
+        );
+        self.resource.blocks[node_chain_head].get_height()
+            - self.resource.blocks[common_ancestor].get_height()
+    }
+}
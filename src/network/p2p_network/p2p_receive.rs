@@ -0,0 +1,217 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::consensus::blockchain::LocalLedger;
+use crate::ledger_data::block::Block;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType;
+use crate::network::message::MessageType::{
+    DataMessage, HeadersMessage, InvMessage, RequestDataMessage, VoteMessage,
+};
+use crate::network::node::link::remaining_time_to_load;
+use crate::network::node::NodeType;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::Simulator;
+use std::collections::hash_map::Entry::Vacant;
+
+// Receive methods and associated functions:
+impl<C: DAGBasedConsensus, P: NetworkProtocol<C>> P2PNetwork<C, P> {
+    /// Adds a received block to the local ledger of the node. A block missing
+    /// some parents waits until they are received, and the parents not seen
+    /// yet are requested from the sender. Once connected to genesis, the block
+    /// and the blocks waiting for it are handled in topological order.
+    pub(crate) fn process_data_message(
+        &mut self,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+    ) {
+        let node = receive_event.node;
+        let block_index = receive_event.block_index;
+        if self.ecs.local_ledger[node].contains(block_index) {
+            return;
+        }
+        self.ecs.already_seen_blocks[node]
+            .0
+            .insert(block_index, true);
+
+        let download_delay = self.simulate_download_delay(simulator, receive_event);
+        let connected_blocks =
+            self.ecs.local_ledger[node].add_block(block_index, &self.resource.blocks);
+        if connected_blocks.is_empty() {
+            self.request_missing_parents(block_index, simulator, receive_event, download_delay);
+        }
+        for connected_block in connected_blocks {
+            self.handle_new_block(connected_block, simulator, receive_event, download_delay);
+        }
+    }
+
+    /// Requests an announced block from the announcing neighbor, unless it is
+    /// already received or requested.
+    pub(crate) fn process_inv_message(
+        &mut self,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+    ) {
+        let seen_blocks = &mut self.ecs.already_seen_blocks[receive_event.node].0;
+        if let Vacant(e) = seen_blocks.entry(receive_event.block_index) {
+            e.insert(false);
+            let download_delay = self.simulate_download_delay(simulator, receive_event);
+            self.propagate(
+                receive_event.block_index,
+                simulator,
+                RequestDataMessage(IsBlock),
+                receive_event,
+                download_delay,
+            );
+        }
+    }
+
+    pub(crate) fn process_request_data_message(
+        &mut self,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+    ) {
+        let seen_blocks = &self.ecs.already_seen_blocks[receive_event.node].0;
+        if *seen_blocks
+            .get(&receive_event.block_index)
+            .unwrap_or(&false)
+        {
+            // SPV clients request only the header of the block.
+            let reply = match self.ecs.node_type[receive_event.from] {
+                NodeType::SPVClient => HeadersMessage,
+                _ => DataMessage(IsBlock),
+            };
+            let download_delay = self.simulate_download_delay(simulator, receive_event);
+            self.propagate(
+                receive_event.block_index,
+                simulator,
+                reply,
+                receive_event,
+                download_delay,
+            );
+        }
+    }
+
+    /// Requests the parents of a block which are neither received nor
+    /// requested yet from the node which sent the block.
+    fn request_missing_parents(
+        &mut self,
+        block_index: usize,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+        download_delay: f64,
+    ) {
+        let node = receive_event.node;
+        let parents = self.resource.blocks[block_index].get_parents().clone();
+        for parent in parents {
+            if let Vacant(e) = self.ecs.already_seen_blocks[node].0.entry(parent) {
+                e.insert(false);
+                self.propagate(
+                    parent,
+                    simulator,
+                    RequestDataMessage(IsBlock),
+                    receive_event,
+                    download_delay,
+                );
+            }
+        }
+    }
+
+    /// Processes a block connected to genesis right away, or validates it
+    /// first if block validation is enabled. Only miners and relay nodes
+    /// relay the block, and SPV clients do not validate it.
+    fn handle_new_block(
+        &mut self,
+        block_index: usize,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+        download_delay: f64,
+    ) {
+        let node = receive_event.node;
+        let node_type = self.ecs.node_type[node];
+        match self.resource.validation_config {
+            Some(config) if node_type.validates_blocks() => self.start_block_validation(
+                block_index,
+                simulator,
+                receive_event,
+                download_delay,
+                &config,
+            ),
+            _ => {
+                P::accept_block(self, node, block_index, simulator);
+                if node_type.relays_blocks() {
+                    self.relay_block(
+                        block_index,
+                        simulator,
+                        receive_event.from,
+                        node,
+                        download_delay,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Forwards a block over the relay overlay and announces it to the
+    /// neighbors of the node, except the node `from` which had sent it,
+    /// `delay` seconds from now.
+    pub(crate) fn relay_block(
+        &mut self,
+        block_index: usize,
+        simulator: &mut Simulator,
+        from: usize,
+        node: usize,
+        delay: f64,
+    ) {
+        self.forward_to_relay_overlay(node, block_index, simulator);
+        let propagate_event =
+            Box::new(SendEvent::new(block_index, from, node, InvMessage(IsBlock)));
+        simulator.put_event(propagate_event, delay);
+    }
+
+    fn propagate(
+        &mut self,
+        block_index: usize,
+        simulator: &mut Simulator,
+        propagate_msg_type: MessageType,
+        receive_event: &ReceiveEvent,
+        delay: f64,
+    ) {
+        let propagate_event = Box::new(SendEvent::new(
+            block_index,
+            receive_event.from,
+            receive_event.node,
+            propagate_msg_type,
+        ));
+        simulator.put_event(propagate_event, delay);
+    }
+
+    /// Occupies the downlink of the receiving node with the received data and
+    /// returns the remaining time until it is downloaded. Data created by the
+    /// node itself or received over the relay overlay does not use the P2P
+    /// downlink.
+    pub(crate) fn simulate_download_delay(
+        &mut self,
+        simulator: &Simulator,
+        receive_event: &ReceiveEvent,
+    ) -> f64 {
+        if receive_event.from == receive_event.node || receive_event.relay_overlay {
+            return 0.0;
+        }
+        let size = self.message_size(receive_event.msg_type, receive_event.block_index);
+        remaining_time_to_load(
+            &mut self.ecs.downlink[receive_event.node].link,
+            simulator,
+            size,
+        )
+    }
+
+    /// Returns the size of a message in bytes. For a `VoteMessage`, `index` is
+    /// the index of the vote.
+    pub(crate) fn message_size(&self, msg_type: MessageType, index: usize) -> u64 {
+        match msg_type {
+            VoteMessage => P::vote_size(self, index),
+            _ => msg_type.get_size(index, &self.resource.blocks),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::ledger_data::block::Block;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::DataMessage;
+use crate::network::p2p_network::P2PNetwork;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::Simulator;
+
+// Relay overlay methods and associated functions:
+impl<C: DAGBasedConsensus, P> P2PNetwork<C, P> {
+    /// Forwards a new block of an overlay member unsolicited to all other
+    /// members of the relay overlay, if it is enabled. Only the first member
+    /// to have the block forwards it, so each member receives it once.
+    pub(crate) fn forward_to_relay_overlay(
+        &mut self,
+        node: usize,
+        block_index: usize,
+        simulator: &mut Simulator,
+    ) {
+        let Some(config) = &self.resource.relay_overlay_config else {
+            return;
+        };
+        if !self.ecs.relay_overlay_member[node]
+            || !self.resource.relay_overlay_blocks.insert(block_index)
+        {
+            return;
+        }
+
+        let delivery_delay = config.delivery_delay(
+            self.resource.blocks[block_index].get_size(),
+            &self.resource.transfer_model,
+        );
+        for (member, &is_member) in self.ecs.relay_overlay_member.iter().enumerate() {
+            if is_member && member != node {
+                let relay_event = ReceiveEvent::new_from_relay_overlay(
+                    block_index,
+                    node,
+                    member,
+                    DataMessage(IsBlock),
+                );
+                simulator.put_event(Box::new(relay_event), delivery_delay);
+            }
+        }
+    }
+}
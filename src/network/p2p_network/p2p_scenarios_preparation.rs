@@ -0,0 +1,54 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::network::node::link::assign_all_bandwidths;
+use crate::network::node::neighbors::{assign_random_neighbors, is_neighbors_bidirectional};
+use crate::network::p2p_network::P2PNetwork;
+use crate::simulator::randomness_engine::RandomnessEngine;
+
+// Preparation steps shared by the protocols:
+impl<C: DAGBasedConsensus, P> P2PNetwork<C, P> {
+    /// Applies the config to the consensus of every node.
+    pub(crate) fn configure_consensus(&mut self) {
+        for (node_index, consensus) in &mut self.ecs.consensus_algorithm.iter_mut().enumerate() {
+            consensus.initial_configuration(&self.resource.config, node_index);
+        }
+    }
+
+    /// Samples the region of every node from the node distribution of the
+    /// network stats.
+    pub(crate) fn sample_node_regions(&mut self, rand: &mut RandomnessEngine) {
+        for region in self.ecs.region.iter_mut() {
+            *region = self.resource.network_stats.sample_node_region(rand);
+        }
+    }
+
+    pub(crate) fn assign_neighbors(&mut self, rand: &mut RandomnessEngine, min_neighbors: usize) {
+        assign_random_neighbors(
+            &mut self.ecs.neighbors,
+            rand,
+            min_neighbors,
+            self.ecs.num_of_nodes,
+        );
+
+        assert!(is_neighbors_bidirectional(&self.ecs.neighbors));
+    }
+
+    /// Resets the local ledgers, samples the bandwidths of the links and adds
+    /// the genesis block.
+    pub(crate) fn assign_ledgers_and_links(&mut self, rand: &mut RandomnessEngine, genesis: C::B) {
+        for local_ledger in self.ecs.local_ledger.iter_mut() {
+            *local_ledger = C::L::default();
+        }
+
+        assign_all_bandwidths(
+            &mut self.ecs.uplink,
+            &mut self.ecs.downlink,
+            &self.ecs.region,
+            self.resource.network_stats.as_ref(),
+            rand,
+            self.ecs.num_of_nodes,
+        );
+
+        // Genesis must be always the first block in the blocks. (genesis_index=0)
+        self.resource.blocks.push(genesis);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::{DataMessage, HeadersMessage, InvMessage};
+use crate::network::node::link::{
+    remaining_time_to_load, remaining_time_to_transfer, TransferModel,
+};
+use crate::network::node::NodeType::SPVClient;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::send_event::SendEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+// Send methods and associated functions:
+impl<C: DAGBasedConsensus, P: NetworkProtocol<C>> P2PNetwork<C, P> {
+    /// For each neighbor connected to a node, create a ['ReceiveEvent'] and
+    /// push into the simulator. It will avoid forwarding the packet to the node
+    /// `send_event.from` which had sent the packet to this node. Blocks are
+    /// announced according to the relay protocol of the network, except that
+    /// SPV clients are pushed the header instead of the full block.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulator`: Mutable reference to [`Simulator`];
+    /// * `rand`: Mutable reference to [`RandomnessEngine`];
+    /// * `send_event`: Immutable reference to the [`SendEvent`].
+    ///
+    pub(crate) fn send_inv_to_neighbors(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        send_event: &SendEvent,
+    ) {
+        if let Some(neighbors) = self.ecs.neighbors.get(send_event.node) {
+            // remove the sender of the packet from the set of neighbors:
+            let filtered_neighbors: Vec<usize> = neighbors
+                .0
+                .iter()
+                .filter(|&neighbor| *neighbor != send_event.from)
+                .cloned()
+                .collect();
+
+            for (i, neighbor) in filtered_neighbors.into_iter().enumerate() {
+                if send_event.msg_type == InvMessage(IsBlock) {
+                    let msg_type = match self.resource.relay_protocol.block_announcement(i) {
+                        DataMessage(IsBlock) if self.ecs.node_type[neighbor] == SPVClient => {
+                            HeadersMessage
+                        }
+                        msg_type => msg_type,
+                    };
+                    let announcement = SendEvent {
+                        msg_type,
+                        ..send_event.clone()
+                    };
+                    self.simulate_upload(simulator, rand, neighbor, &announcement);
+                } else {
+                    self.simulate_upload(simulator, rand, neighbor, send_event);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn simulate_upload(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        to: usize,
+        send_event: &SendEvent,
+    ) {
+        let node = send_event.node;
+        let index = send_event.block_index;
+
+        let forward_event = Box::new(ReceiveEvent::new(index, node, to, send_event.msg_type));
+        let size = self.message_size(send_event.msg_type, index);
+        let uplink = &mut self.ecs.uplink[node];
+        let upload_delay = match self.resource.transfer_model {
+            TransferModel::TcpFlow(tcp) if send_event.msg_type == DataMessage(IsBlock) => {
+                let bandwidth = uplink
+                    .link
+                    .bandwidth
+                    .min(self.ecs.downlink[to].link.bandwidth);
+                let rtt = self
+                    .resource
+                    .network_stats
+                    .get_round_trip_time(self.ecs.region[node], self.ecs.region[to]);
+                let transfer_time = tcp.transfer_time(size, bandwidth, rtt);
+                remaining_time_to_transfer(&mut uplink.link, simulator, transfer_time)
+            }
+            _ => remaining_time_to_load(&mut uplink.link, simulator, size),
+        };
+        let delivery_delay = self.resource.network_stats.sample_latency(
+            self.ecs.region[node],
+            self.ecs.region[to],
+            rand,
+        );
+        simulator.put_event(forward_event, upload_delay + delivery_delay);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::ledger_data::block::Block;
+use crate::network::node::validation::ValidationConfig;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::block_validation_event::BlockValidationEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::Simulator;
+
+// Block validation methods and associated functions:
+impl<C: DAGBasedConsensus, P: NetworkProtocol<C>> P2PNetwork<C, P> {
+    /// Queues a received block for validation once it is downloaded and the
+    /// node has validated its previous blocks. The block is relayed either now
+    /// (after download) or after validation, depending on the config, if the
+    /// node relays blocks.
+    pub(crate) fn start_block_validation(
+        &mut self,
+        block_index: usize,
+        simulator: &mut Simulator,
+        receive_event: &ReceiveEvent,
+        download_delay: f64,
+        config: &ValidationConfig,
+    ) {
+        let node = receive_event.node;
+        let validation_time = config.validation_time(
+            self.ecs.node_type[node],
+            self.resource.blocks[block_index].get_full_size(),
+        );
+        let start_time =
+            (simulator.simulation_time + download_delay).max(self.ecs.validation_done_time[node]);
+        let done_time = start_time + validation_time;
+        self.ecs.validation_done_time[node] = done_time;
+
+        let relays_blocks = self.ecs.node_type[node].relays_blocks();
+        if relays_blocks && config.relay_before_validation {
+            self.relay_block(
+                block_index,
+                simulator,
+                receive_event.from,
+                node,
+                download_delay,
+            );
+        }
+
+        let validation_event = Box::new(BlockValidationEvent::new(
+            block_index,
+            receive_event.from,
+            node,
+            relays_blocks && !config.relay_before_validation,
+        ));
+        simulator.put_event(validation_event, done_time - simulator.simulation_time);
+    }
+
+    pub(crate) fn finish_block_validation(
+        &mut self,
+        simulator: &mut Simulator,
+        validation_event: &BlockValidationEvent,
+    ) {
+        let node = validation_event.node;
+        let block_index = validation_event.block_index;
+        P::accept_block(self, node, block_index, simulator);
+
+        if validation_event.relay {
+            self.relay_block(block_index, simulator, validation_event.from, node, 0.0);
+        }
+    }
+}
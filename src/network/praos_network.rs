@@ -0,0 +1,99 @@
+pub(crate) mod praos_scenarios_preparation;
+mod praos_slot;
+
+use crate::consensus::algorithm::praos_consensus::PraosConsensus;
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::consensus::config::praos_consensus_config::PraosConsensusConfig;
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::network::message::RelayProtocol;
+use crate::network::p2p_network::{NetworkProtocol, P2PNetwork};
+use crate::simulator::event::slot_event::SlotEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::any::Any;
+use std::collections::BTreeMap;
+
+/// A longest-chain Proof-of-Stake network running Ouroboros Praos. Every node
+/// is a stakeholder taking part in the slot leader lotteries; blocks are
+/// gossiped to all neighbors.
+pub type PraosNetwork = P2PNetwork<PraosConsensus, PraosProtocol>;
+
+/// The slot leader lotteries of Ouroboros Praos.
+pub struct PraosProtocol {
+    pub block_size: u64,
+    /// The blocks created in each slot with at least one leader, in creation
+    /// order: <slot, block_indices>
+    pub slot_blocks: BTreeMap<u64, Vec<usize>>,
+}
+
+impl NetworkProtocol<PraosConsensus> for PraosProtocol {
+    fn handle_event(
+        network: &mut PraosNetwork,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        event: &dyn Any,
+    ) {
+        if let Some(slot_event) = event.downcast_ref::<SlotEvent>() {
+            network.lead_slot(simulator, rand, slot_event);
+        }
+    }
+
+    fn canonical_chain_head(network: &PraosNetwork, node_index: usize) -> Option<usize> {
+        network
+            .ecs
+            .consensus_algorithm
+            .get(node_index)
+            .map(|consensus| consensus.get_canonical_chain_head_index())
+    }
+
+    /// Returns the first block created in the slot, if any.
+    fn block_of_slot(network: &PraosNetwork, slot: u64) -> Option<usize> {
+        network
+            .protocol
+            .slot_blocks
+            .get(&slot)
+            .and_then(|slot_blocks| slot_blocks.first())
+            .copied()
+    }
+
+    fn block_reorg_before(
+        network: &PraosNetwork,
+        reorg_logger: &mut BlockchainReorgLogger,
+        block_index: usize,
+        node_index: usize,
+    ) {
+        network.chain_reorg_before(reorg_logger, block_index, node_index);
+    }
+
+    fn block_reorg_after(network: &PraosNetwork, reorg_logger: &mut BlockchainReorgLogger) -> bool {
+        network.chain_reorg_after(reorg_logger)
+    }
+
+    fn block_reorg_output_length(
+        network: &PraosNetwork,
+        reorg_logger: &BlockchainReorgLogger,
+        previous_head: usize,
+        node_index: usize,
+    ) -> i32 {
+        network.chain_reorg_output_length(reorg_logger, previous_head, node_index)
+    }
+}
+
+impl PraosNetwork {
+    pub fn new_with_size(
+        num_of_nodes: usize,
+        average_num_of_blocks: usize,
+        config: PraosConsensusConfig,
+        block_size: u64,
+    ) -> Self {
+        let protocol = PraosProtocol {
+            block_size,
+            slot_blocks: BTreeMap::new(),
+        };
+        let mut network = Self::new(num_of_nodes, average_num_of_blocks, config, 0, protocol);
+        network.resource.relay_protocol = RelayProtocol::UnsolicitedPush {
+            num_of_push_peers: None,
+        };
+        network
+    }
+}
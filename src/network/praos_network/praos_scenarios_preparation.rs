@@ -0,0 +1,46 @@
+use crate::ledger_data::praos_block::PraosBlock;
+use crate::network::node::connection::set_all_nodes_connected;
+use crate::network::praos_network::PraosNetwork;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+/// Median stake of a stake pool (ADA).
+const STAKE_POOL_STAKE_MEDIAN: f64 = 10_000_000.0;
+const STAKE_POOL_STAKE_STDDEV: f64 = 1.5;
+
+impl PraosNetwork {
+    /// Prepares the network. If no stakes are set in the config, they are
+    /// sampled from a log-normal distribution.
+    pub fn prepare(&mut self, rand: &mut RandomnessEngine, min_neighbors: usize) {
+        let num_of_nodes = self.ecs.num_of_nodes;
+        if self.resource.config.stakes.len() != num_of_nodes {
+            let stakes = (0..num_of_nodes)
+                .map(|_| {
+                    rand.sample_log_normal_distribution(
+                        STAKE_POOL_STAKE_MEDIAN,
+                        STAKE_POOL_STAKE_STDDEV,
+                    )
+                })
+                .collect();
+            self.resource.config.set_stakes(stakes);
+        }
+
+        self.configure_consensus();
+        self.sample_node_regions(rand);
+        set_all_nodes_connected(&mut self.ecs.is_connected, num_of_nodes);
+        self.assign_neighbors(rand, min_neighbors);
+        self.assign_ledgers_and_links(rand, PraosBlock::generate_genesis_block());
+    }
+
+    /// Samples the first slot each node leads, as the genesis block belongs
+    /// to slot 0.
+    pub(crate) fn insert_initial_event(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        for node in 0..self.ecs.num_of_nodes {
+            self.schedule_next_leadership(simulator, rand, node, 0);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::ledger_data::praos_block::PraosBlock;
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::DataMessage;
+use crate::network::node::connection::node_is_connected;
+use crate::network::praos_network::PraosNetwork;
+use crate::network::Network;
+use crate::simulator::event::block_generation_event::BlockGenerationEvent;
+use crate::simulator::event::receive_event::ReceiveEvent;
+use crate::simulator::event::slot_event::SlotEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+// Slot methods and associated functions:
+impl PraosNetwork {
+    /// The node won the lottery of the slot: it creates a block on its head
+    /// (if online), and waits for the next slot it wins.
+    pub(crate) fn lead_slot(
+        &mut self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        slot_event: &SlotEvent,
+    ) {
+        let leader = slot_event.proposer;
+        if node_is_connected(&self.ecs.is_connected, leader) {
+            self.create_block(simulator, slot_event.slot, leader);
+        }
+
+        self.schedule_next_leadership(simulator, rand, leader, slot_event.slot);
+    }
+
+    /// Samples the next slot after `slot` the node wins, and puts its
+    /// [`SlotEvent`]. As the lottery of each slot is independent, the number
+    /// of slots until the next win is geometric.
+    pub(crate) fn schedule_next_leadership(
+        &self,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+        node: usize,
+        slot: u64,
+    ) {
+        let probability = self.resource.config.slot_leader_probability(node);
+        if probability <= 0.0 {
+            return;
+        }
+
+        let lost_slots =
+            (rand.sample_exponential_distribution_mean_1() / -(1.0 - probability).ln()).floor();
+        let next_slot = slot + 1 + lost_slots as u64;
+        let delay = (next_slot - slot) as f64 * self.resource.config.slot_duration;
+        simulator.put_event(Box::new(SlotEvent::new(next_slot, node)), delay);
+    }
+
+    fn create_block(&mut self, simulator: &mut Simulator, slot: u64, leader: usize) {
+        let parent = self.ecs.consensus_algorithm[leader].get_canonical_chain_head_index();
+        let new_block_index = self.resource.blocks.len();
+        let block = PraosBlock::new_with_parent(
+            simulator.simulation_time,
+            Some(leader),
+            self.resource.blocks[parent].height + 1,
+            parent,
+            self.protocol.block_size,
+            slot,
+        );
+        self.resource.blocks.push(block);
+        self.protocol
+            .slot_blocks
+            .entry(slot)
+            .or_default()
+            .push(new_block_index);
+        simulator.put_event(
+            Box::new(BlockGenerationEvent::new(new_block_index, leader)),
+            0.0,
+        );
+
+        let receive_at_this_node =
+            ReceiveEvent::new(new_block_index, leader, leader, DataMessage(IsBlock));
+        self.receive(simulator, &receive_at_this_node);
+    }
+}
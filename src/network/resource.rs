@@ -1,2 +1 @@
-pub mod bitcoin_resource;
-pub mod ethereum_resource;
+pub mod p2p_resource;
@@ -0,0 +1,45 @@
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::network::message::RelayProtocol;
+use crate::network::node::link::TransferModel;
+use crate::network::node::node_type::NodeTypeConfig;
+use crate::network::node::relay_overlay::RelayOverlayConfig;
+use crate::network::node::validation::ValidationConfig;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub struct P2PResource<C: DAGBasedConsensus> {
+    pub blocks: Vec<C::B>,
+    pub config: C::G,
+    pub miners: Vec<usize>,
+    pub transfer_model: TransferModel,
+    pub network_stats: Rc<dyn NetworkStats>,
+    pub relay_overlay_config: Option<RelayOverlayConfig>,
+    /// The blocks already forwarded over the relay overlay.
+    pub relay_overlay_blocks: HashSet<usize>,
+    pub relay_protocol: RelayProtocol,
+    pub validation_config: Option<ValidationConfig>,
+    pub node_type_config: NodeTypeConfig,
+}
+
+impl<C: DAGBasedConsensus> P2PResource<C> {
+    pub fn new(average_num_of_blocks: usize, config: C::G, num_of_miners: usize) -> Self {
+        Self {
+            blocks: Vec::with_capacity(average_num_of_blocks),
+            config,
+            miners: Vec::with_capacity(num_of_miners),
+            transfer_model: TransferModel::default(),
+            network_stats: Rc::new(BitcoinGlobalNetworkStats86Countries::default()),
+            relay_overlay_config: None,
+            relay_overlay_blocks: HashSet::new(),
+            relay_protocol: RelayProtocol::default(),
+            validation_config: None,
+            node_type_config: NodeTypeConfig::default(),
+        }
+    }
+
+    pub fn num_of_miners(&self) -> usize {
+        self.miners.len()
+    }
+}
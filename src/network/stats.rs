@@ -1,11 +1,69 @@
+//! Network statistics (regions, latencies, bandwidths and node distributions)
+//! used to place nodes in the simulated network.
+
+pub mod csv_network_stats;
 pub mod eighty_six_countries;
 
-use self::eighty_six_countries::{sample_random_country, Country};
 use crate::simulator::randomness_engine::RandomnessEngine;
 
-pub fn assign_random_countries(country: &mut [Country], rand: &mut RandomnessEngine, size: usize) {
-    assert_eq!(country.len(), size);
-    for c in country.iter_mut() {
-        *c = sample_random_country(rand);
+/// A dataset describing the geography of a network. Regions are denoted by
+/// their `usize` index in `0..get_num_of_regions()`.
+pub trait NetworkStats {
+    /// Name of the dataset, e.g. the path of the files it was loaded from.
+    fn get_name(&self) -> String;
+    fn get_num_of_regions(&self) -> usize;
+    fn get_region_name(&self, region: usize) -> String;
+    /// Average one-way latency between two regions in milliseconds.
+    fn get_average_latency(&self, from: usize, to: usize) -> f64;
+    fn get_latency_pareto_shape(&self) -> f64;
+    /// Median download bandwidth of the region in megabits per second.
+    fn get_download_bandwidth_average(&self, region: usize) -> f64;
+    fn get_download_bandwidth_shape(&self) -> f64;
+    /// Median upload bandwidth of the region in megabits per second.
+    fn get_upload_bandwidth_average(&self, region: usize) -> f64;
+    fn get_upload_bandwidth_shape(&self) -> f64;
+    /// Probability distribution of regions for normal nodes.
+    fn get_node_distribution(&self) -> &[f64];
+    /// Probability distribution of regions for miner nodes.
+    fn get_miner_distribution(&self) -> &[f64];
+
+    /// Samples the latency between two regions from a Pareto distribution
+    /// with the average latency of the regions as its mean. Returns seconds.
+    fn sample_latency(&self, from: usize, to: usize, rand: &mut RandomnessEngine) -> f64 {
+        let shape = self.get_latency_pareto_shape();
+        let mean: f64 = self.get_average_latency(from, to);
+        let scale: f64 = ((shape - 1.0) / shape) * mean;
+        rand.sample_pareto_distribution(scale, shape) / 1000.0
+    }
+
+    /// Returns the average round trip time between two regions in seconds.
+    fn get_round_trip_time(&self, from: usize, to: usize) -> f64 {
+        (self.get_average_latency(from, to) + self.get_average_latency(to, from)) / 1000.0
+    }
+
+    /// Samples a download bandwidth in bits per second for the region.
+    fn sample_download_bandwidth(&self, region: usize, rand: &mut RandomnessEngine) -> f64 {
+        let log_normal = rand.sample_log_normal_distribution(
+            self.get_download_bandwidth_average(region),
+            self.get_download_bandwidth_shape(),
+        );
+        log_normal * 1_000_000.0
+    }
+
+    /// Samples an upload bandwidth in bits per second for the region.
+    fn sample_upload_bandwidth(&self, region: usize, rand: &mut RandomnessEngine) -> f64 {
+        let log_normal = rand.sample_log_normal_distribution(
+            self.get_upload_bandwidth_average(region),
+            self.get_upload_bandwidth_shape(),
+        );
+        log_normal * 1_000_000.0
+    }
+
+    fn sample_node_region(&self, rand: &mut RandomnessEngine) -> usize {
+        rand.sample_from_distribution(self.get_node_distribution())
+    }
+
+    fn sample_miner_region(&self, rand: &mut RandomnessEngine) -> usize {
+        rand.sample_from_distribution(self.get_miner_distribution())
     }
 }
@@ -0,0 +1,364 @@
+//! Network statistics loaded at runtime from CSV files.
+//!
+//! A dataset consists of three files:
+//!
+//! * `regions.csv`: one row per region with the header
+//!   `Region,DownloadBandwidth,UploadBandwidth,NodeShare,MinerShare`. The
+//!   bandwidths are medians in megabits per second and the shares are the
+//!   (not necessarily normalized) weights of the node and miner distributions;
+//! * `latency.csv`: the average one-way latency matrix in milliseconds. The
+//!   header is `Region` followed by the region names, and each row starts with
+//!   the name of the sending region. Regions must be in the same order as in
+//!   `regions.csv`;
+//! * `parameters.csv`: one row per parameter with the header `Parameter,Value`
+//!   for `DownloadBandwidthShape`, `UploadBandwidthShape` and
+//!   `LatencyParetoShape`.
+//!
+//! Lines starting with `#` are ignored.
+
+use crate::network::stats::NetworkStats;
+use csv::{ReaderBuilder, StringRecord, Trim};
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind, Read};
+use std::path::Path;
+
+pub const REGIONS_FILE_NAME: &str = "regions.csv";
+pub const LATENCY_FILE_NAME: &str = "latency.csv";
+pub const PARAMETERS_FILE_NAME: &str = "parameters.csv";
+
+const REGIONS_HEADER: [&str; 5] = [
+    "Region",
+    "DownloadBandwidth",
+    "UploadBandwidth",
+    "NodeShare",
+    "MinerShare",
+];
+
+const PARAMETERS_HEADER: [&str; 2] = ["Parameter", "Value"];
+
+#[derive(Debug, Clone)]
+pub struct CSVNetworkStats {
+    /// The files the dataset was loaded from.
+    name: String,
+    region_names: Vec<String>,
+    latency: Vec<Vec<f64>>,
+    download_bandwidth_average: Vec<f64>,
+    download_bandwidth_shape: f64,
+    upload_bandwidth_average: Vec<f64>,
+    upload_bandwidth_shape: f64,
+    latency_pareto_shape: f64,
+    node_distribution: Vec<f64>,
+    miner_distribution: Vec<f64>,
+}
+
+impl CSVNetworkStats {
+    /// Loads the dataset from `regions.csv`, `latency.csv` and `parameters.csv`
+    /// in the given directory.
+    pub fn from_dir(dir: &Path) -> Result<Self, Error> {
+        let mut stats = Self::from_paths(
+            &dir.join(REGIONS_FILE_NAME),
+            &dir.join(LATENCY_FILE_NAME),
+            &dir.join(PARAMETERS_FILE_NAME),
+        )?;
+        stats.name = dir.display().to_string();
+        Ok(stats)
+    }
+
+    pub fn from_paths(
+        regions_path: &Path,
+        latency_path: &Path,
+        parameters_path: &Path,
+    ) -> Result<Self, Error> {
+        let mut stats = Self::from_readers(
+            std::fs::File::open(regions_path)?,
+            std::fs::File::open(latency_path)?,
+            std::fs::File::open(parameters_path)?,
+        )?;
+        stats.name = format!(
+            "{},{},{}",
+            regions_path.display(),
+            latency_path.display(),
+            parameters_path.display()
+        );
+        Ok(stats)
+    }
+
+    /// Loads the dataset from any readers with the format of the CSV files and
+    /// validates its dimensions and values. The dataset is named `csv`.
+    pub fn from_readers<R1: Read, R2: Read, R3: Read>(
+        regions: R1,
+        latency: R2,
+        parameters: R3,
+    ) -> Result<Self, Error> {
+        let mut stats = Self {
+            name: "csv".to_string(),
+            region_names: Vec::new(),
+            latency: Vec::new(),
+            download_bandwidth_average: Vec::new(),
+            download_bandwidth_shape: 0.0,
+            upload_bandwidth_average: Vec::new(),
+            upload_bandwidth_shape: 0.0,
+            latency_pareto_shape: 0.0,
+            node_distribution: Vec::new(),
+            miner_distribution: Vec::new(),
+        };
+        stats.read_regions(regions)?;
+        stats.read_latency(latency)?;
+        stats.read_parameters(parameters)?;
+        stats.validate()?;
+        Ok(stats)
+    }
+
+    fn read_regions<R: Read>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = csv_reader(reader);
+        let header = reader.headers()?.clone();
+        if header.iter().ne(REGIONS_HEADER.iter().copied()) {
+            return Err(invalid_data(format!(
+                "{}: expected header `{}`",
+                REGIONS_FILE_NAME,
+                REGIONS_HEADER.join(",")
+            )));
+        }
+
+        for (row, record) in reader.records().enumerate() {
+            let record = record?;
+            expect_len(&record, REGIONS_HEADER.len(), REGIONS_FILE_NAME, row)?;
+            self.region_names.push(record[0].to_string());
+            self.download_bandwidth_average
+                .push(parse_f64(&record[1], REGIONS_FILE_NAME, row)?);
+            self.upload_bandwidth_average
+                .push(parse_f64(&record[2], REGIONS_FILE_NAME, row)?);
+            self.node_distribution
+                .push(parse_f64(&record[3], REGIONS_FILE_NAME, row)?);
+            self.miner_distribution
+                .push(parse_f64(&record[4], REGIONS_FILE_NAME, row)?);
+        }
+        Ok(())
+    }
+
+    fn read_latency<R: Read>(&mut self, reader: R) -> Result<(), Error> {
+        let num_of_regions = self.region_names.len();
+        let mut reader = csv_reader(reader);
+        let header = reader.headers()?.clone();
+        expect_len(&header, num_of_regions + 1, LATENCY_FILE_NAME, 0)?;
+        if header.iter().skip(1).ne(self.region_names.iter()) {
+            return Err(invalid_data(format!(
+                "{}: header regions do not match {}",
+                LATENCY_FILE_NAME, REGIONS_FILE_NAME
+            )));
+        }
+
+        for (row, record) in reader.records().enumerate() {
+            let record = record?;
+            expect_len(&record, num_of_regions + 1, LATENCY_FILE_NAME, row)?;
+            if self.region_names.get(row).map(|name| name.as_str()) != Some(&record[0]) {
+                return Err(invalid_data(format!(
+                    "{}: row {} should belong to region `{}`",
+                    LATENCY_FILE_NAME,
+                    row,
+                    self.region_names.get(row).map_or("<none>", |name| name)
+                )));
+            }
+            let latencies = record
+                .iter()
+                .skip(1)
+                .map(|field| parse_f64(field, LATENCY_FILE_NAME, row))
+                .collect::<Result<Vec<f64>, Error>>()?;
+            self.latency.push(latencies);
+        }
+        Ok(())
+    }
+
+    fn read_parameters<R: Read>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = csv_reader(reader);
+        let header = reader.headers()?.clone();
+        if header.iter().ne(PARAMETERS_HEADER.iter().copied()) {
+            return Err(invalid_data(format!(
+                "{}: expected header `{}`",
+                PARAMETERS_FILE_NAME,
+                PARAMETERS_HEADER.join(",")
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        for (row, record) in reader.records().enumerate() {
+            let record = record?;
+            expect_len(&record, 2, PARAMETERS_FILE_NAME, row)?;
+            let value = parse_f64(&record[1], PARAMETERS_FILE_NAME, row)?;
+            match &record[0] {
+                "DownloadBandwidthShape" => self.download_bandwidth_shape = value,
+                "UploadBandwidthShape" => self.upload_bandwidth_shape = value,
+                "LatencyParetoShape" => self.latency_pareto_shape = value,
+                unknown => {
+                    return Err(invalid_data(format!(
+                        "{}: unknown parameter `{}`",
+                        PARAMETERS_FILE_NAME, unknown
+                    )))
+                }
+            }
+            seen.insert(record[0].to_string());
+        }
+        for parameter in [
+            "DownloadBandwidthShape",
+            "UploadBandwidthShape",
+            "LatencyParetoShape",
+        ] {
+            if !seen.contains(parameter) {
+                return Err(invalid_data(format!(
+                    "{}: missing parameter `{}`",
+                    PARAMETERS_FILE_NAME, parameter
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the dimensions of the latency matrix and that all values can be
+    /// used by the samplers.
+    fn validate(&self) -> Result<(), Error> {
+        let num_of_regions = self.region_names.len();
+        if num_of_regions == 0 {
+            return Err(invalid_data(format!(
+                "{}: no region is defined",
+                REGIONS_FILE_NAME
+            )));
+        }
+        let unique_names: HashSet<&String> = self.region_names.iter().collect();
+        if unique_names.len() != num_of_regions {
+            return Err(invalid_data(format!(
+                "{}: region names must be unique",
+                REGIONS_FILE_NAME
+            )));
+        }
+        if self.latency.len() != num_of_regions {
+            return Err(invalid_data(format!(
+                "{}: expected a {}x{} matrix but found {} rows",
+                LATENCY_FILE_NAME,
+                num_of_regions,
+                num_of_regions,
+                self.latency.len()
+            )));
+        }
+        if self.latency.iter().flatten().any(|&l| l < 0.0) {
+            return Err(invalid_data(format!(
+                "{}: latencies must not be negative",
+                LATENCY_FILE_NAME
+            )));
+        }
+        if self
+            .download_bandwidth_average
+            .iter()
+            .chain(self.upload_bandwidth_average.iter())
+            .any(|&b| b <= 0.0)
+        {
+            return Err(invalid_data(format!(
+                "{}: bandwidths must be positive",
+                REGIONS_FILE_NAME
+            )));
+        }
+        if self.download_bandwidth_shape <= 0.0 || self.upload_bandwidth_shape <= 0.0 {
+            return Err(invalid_data(format!(
+                "{}: bandwidth shapes must be positive",
+                PARAMETERS_FILE_NAME
+            )));
+        }
+        if self.latency_pareto_shape <= 1.0 {
+            return Err(invalid_data(format!(
+                "{}: LatencyParetoShape must be greater than 1",
+                PARAMETERS_FILE_NAME
+            )));
+        }
+        for (name, distribution) in [
+            ("NodeShare", &self.node_distribution),
+            ("MinerShare", &self.miner_distribution),
+        ] {
+            if distribution.iter().any(|&w| w < 0.0) || distribution.iter().sum::<f64>() <= 0.0 {
+                return Err(invalid_data(format!(
+                    "{}: {} must be non-negative with a positive sum",
+                    REGIONS_FILE_NAME, name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NetworkStats for CSVNetworkStats {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_num_of_regions(&self) -> usize {
+        self.region_names.len()
+    }
+
+    fn get_region_name(&self, region: usize) -> String {
+        self.region_names[region].clone()
+    }
+
+    fn get_average_latency(&self, from: usize, to: usize) -> f64 {
+        self.latency[from][to]
+    }
+
+    fn get_latency_pareto_shape(&self) -> f64 {
+        self.latency_pareto_shape
+    }
+
+    fn get_download_bandwidth_average(&self, region: usize) -> f64 {
+        self.download_bandwidth_average[region]
+    }
+
+    fn get_download_bandwidth_shape(&self) -> f64 {
+        self.download_bandwidth_shape
+    }
+
+    fn get_upload_bandwidth_average(&self, region: usize) -> f64 {
+        self.upload_bandwidth_average[region]
+    }
+
+    fn get_upload_bandwidth_shape(&self) -> f64 {
+        self.upload_bandwidth_shape
+    }
+
+    fn get_node_distribution(&self) -> &[f64] {
+        &self.node_distribution
+    }
+
+    fn get_miner_distribution(&self) -> &[f64] {
+        &self.miner_distribution
+    }
+}
+
+fn csv_reader<R: Read>(reader: R) -> csv::Reader<R> {
+    ReaderBuilder::new()
+        .comment(Some(b'#'))
+        .trim(Trim::All)
+        .from_reader(reader)
+}
+
+fn expect_len(record: &StringRecord, len: usize, file: &str, row: usize) -> Result<(), Error> {
+    if record.len() != len {
+        return Err(invalid_data(format!(
+            "{}: expected {} columns at row {} but found {}",
+            file,
+            len,
+            row,
+            record.len()
+        )));
+    }
+    Ok(())
+}
+
+fn parse_f64(field: &str, file: &str, row: usize) -> Result<f64, Error> {
+    match field.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(invalid_data(format!(
+            "{}: invalid number `{}` at row {}",
+            file, field, row
+        ))),
+    }
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
@@ -1,9 +1,8 @@
 pub mod bitcoin_stats;
 
-use crate::simulator::randomness_engine::RandomnessEngine;
 use strum_macros::FromRepr;
 
-const NUM_OF_COUNTRIES: usize = 86;
+pub const NUM_OF_COUNTRIES: usize = 86;
 
 #[derive(Copy, Clone, Debug, FromRepr, Default)]
 #[repr(usize)] // represents the enum variants as usize (needed for indexing)
@@ -97,7 +96,8 @@ pub enum Country {
     RestOfWorld, // 85
 }
 
-/// Average latency between countries in milliseconds.
+/// Average latency between countries in milliseconds. Only the first
+/// `NUM_OF_COUNTRIES` rows and columns are indexed by [`Country`].
 const GLOBAL_LATENCY_BY_REGION: [[f64; 91]; 91] = [
     [
         11.12, 28.26, 142.12, 149.93, 18.14, 107.09, 25.29, 123.81, 14.88, 117.2, 79.47, 130.7,
@@ -1016,7 +1016,7 @@ const GLOBAL_LATENCY_BY_REGION: [[f64; 91]; 91] = [
     ],
 ];
 
-/// Median download bandwidth of each country in megabytes per second MBps.
+/// Median download bandwidth of each country in megabits per second Mbps.
 pub const DOWNLOAD_BANDWIDTH_AVERAGE: [f64; NUM_OF_COUNTRIES] = [
     56.68, 11.04, 69.85, 79.47, 108.29, 48.79, 135.63, 141.49, 100.33, 31.23, 202.95, 260.92,
     237.96, 101.46, 70.33, 93.92, 66.99, 103.19, 243.01, 94.46, 161.12, 242.44, 28.09, 146.78,
@@ -1028,7 +1028,7 @@ pub const DOWNLOAD_BANDWIDTH_AVERAGE: [f64; NUM_OF_COUNTRIES] = [
 ];
 pub const DOWNLOAD_BANDWIDTH_SHAPE: f64 = 1.8;
 
-/// Median upload bandwidth of each country in megabytes per second MBps.
+/// Median upload bandwidth of each country in megabits per second Mbps.
 pub const UPLOAD_BANDWIDTH_AVERAGE: [f64; NUM_OF_COUNTRIES] = [
     27.66, 2.15, 31.57, 24.93, 27.1, 47.59, 25.82, 81.71, 78.97, 31.9, 92.17, 204.37, 53.56, 59.96,
     38.56, 47.08, 27.08, 52.03, 172.94, 75.42, 73.58, 171.96, 29.8, 35.84, 34.56, 9.52, 10.99,
@@ -1042,41 +1042,11 @@ pub const UPLOAD_BANDWIDTH_SHAPE: f64 = 1.8;
 
 pub const LATENCY_PARETO_SHAPE: f64 = 5.0;
 
-#[allow(unused)]
-fn get_country_delay(sender: Country, receiver: Country) -> f64 {
-    GLOBAL_LATENCY_BY_REGION[sender as usize][receiver as usize]
+/// Returns the average latency between two countries in milliseconds.
+pub(crate) fn get_country_delay(sender: usize, receiver: usize) -> f64 {
+    GLOBAL_LATENCY_BY_REGION[sender][receiver]
 }
 
-fn get_country_from_usize(index: usize) -> Country {
+pub(crate) fn get_country_from_usize(index: usize) -> Country {
     Country::from_repr(index).unwrap()
 }
-
-pub fn sample_random_country(rand: &mut RandomnessEngine) -> Country {
-    get_country_from_usize(rand.sample_usize(NUM_OF_COUNTRIES))
-}
-
-pub fn get_latency(
-    from_position: Country,
-    to_position: Country,
-    randomness_engine: &mut RandomnessEngine,
-) -> f64 {
-    let mean: f64 = GLOBAL_LATENCY_BY_REGION[from_position as usize][to_position as usize];
-    let scale: f64 = ((LATENCY_PARETO_SHAPE - 1.0) / LATENCY_PARETO_SHAPE) * mean;
-    randomness_engine.sample_pareto_distribution(scale, LATENCY_PARETO_SHAPE) / 1000.0
-}
-
-pub fn sample_download_bandwidth(region: Country, rand: &mut RandomnessEngine) -> f64 {
-    let log_normal = rand.sample_log_normal_distribution(
-        DOWNLOAD_BANDWIDTH_AVERAGE[region as usize],
-        DOWNLOAD_BANDWIDTH_SHAPE,
-    );
-    log_normal * 1_000_000.0
-}
-
-pub fn sample_upload_bandwidth(region: Country, rand: &mut RandomnessEngine) -> f64 {
-    let log_normal = rand.sample_log_normal_distribution(
-        UPLOAD_BANDWIDTH_AVERAGE[region as usize],
-        UPLOAD_BANDWIDTH_SHAPE,
-    );
-    log_normal * 1_000_000.0
-}
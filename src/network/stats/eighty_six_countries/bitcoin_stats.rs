@@ -1,10 +1,91 @@
 pub mod bitcoin_node_global_network_stats_86_countries;
 pub mod bitcoin_pow_global_network_stats_86_countries;
+pub mod bitcoin_profile;
 
-use crate::network::stats::eighty_six_countries::Country;
+use crate::network::stats::eighty_six_countries::{
+    get_country_delay, get_country_from_usize, DOWNLOAD_BANDWIDTH_AVERAGE,
+    DOWNLOAD_BANDWIDTH_SHAPE, LATENCY_PARETO_SHAPE, NUM_OF_COUNTRIES, UPLOAD_BANDWIDTH_AVERAGE,
+    UPLOAD_BANDWIDTH_SHAPE,
+};
+use crate::network::stats::NetworkStats;
 use crate::simulator::randomness_engine::RandomnessEngine;
-use bitcoin_node_global_network_stats_86_countries::BitcoinNodeGlobalNetworkStats86Countries;
-use bitcoin_pow_global_network_stats_86_countries::BitcoinProofOfWorkGlobalNetworkStats86Countries;
+use bitcoin_node_global_network_stats_86_countries::BITCOIN_COUNTRIES_DISTRIBUTION_2022;
+use bitcoin_pow_global_network_stats_86_countries::BITCOIN_MINER_REGION_DISTRIBUTION_2020;
+use bitcoin_profile::BitcoinProfile;
+
+/// The compiled-in dataset of 86 countries with the Bitcoin node (2022) and
+/// miner (2020 by default) distributions. Regions are the indices of
+/// [`Country`].
+///
+/// [`Country`]: crate::network::stats::eighty_six_countries::Country
+#[derive(Debug, Clone)]
+pub struct BitcoinGlobalNetworkStats86Countries {
+    miner_distribution: &'static [f64],
+}
+
+impl Default for BitcoinGlobalNetworkStats86Countries {
+    fn default() -> Self {
+        Self {
+            miner_distribution: &BITCOIN_MINER_REGION_DISTRIBUTION_2020,
+        }
+    }
+}
+
+impl BitcoinGlobalNetworkStats86Countries {
+    /// Creates the dataset with the miner geography of the profile.
+    pub fn from_profile(profile: &BitcoinProfile) -> Self {
+        assert_eq!(profile.miner_region_distribution.len(), NUM_OF_COUNTRIES);
+        Self {
+            miner_distribution: profile.miner_region_distribution,
+        }
+    }
+}
+
+impl NetworkStats for BitcoinGlobalNetworkStats86Countries {
+    fn get_name(&self) -> String {
+        "bitcoin-86-countries".to_string()
+    }
+
+    fn get_num_of_regions(&self) -> usize {
+        NUM_OF_COUNTRIES
+    }
+
+    fn get_region_name(&self, region: usize) -> String {
+        format!("{:?}", get_country_from_usize(region))
+    }
+
+    fn get_average_latency(&self, from: usize, to: usize) -> f64 {
+        get_country_delay(from, to)
+    }
+
+    fn get_latency_pareto_shape(&self) -> f64 {
+        LATENCY_PARETO_SHAPE
+    }
+
+    fn get_download_bandwidth_average(&self, region: usize) -> f64 {
+        DOWNLOAD_BANDWIDTH_AVERAGE[region]
+    }
+
+    fn get_download_bandwidth_shape(&self) -> f64 {
+        DOWNLOAD_BANDWIDTH_SHAPE
+    }
+
+    fn get_upload_bandwidth_average(&self, region: usize) -> f64 {
+        UPLOAD_BANDWIDTH_AVERAGE[region]
+    }
+
+    fn get_upload_bandwidth_shape(&self) -> f64 {
+        UPLOAD_BANDWIDTH_SHAPE
+    }
+
+    fn get_node_distribution(&self) -> &[f64] {
+        &BITCOIN_COUNTRIES_DISTRIBUTION_2022
+    }
+
+    fn get_miner_distribution(&self) -> &[f64] {
+        self.miner_distribution
+    }
+}
 
 pub fn sample_bitcoin_miner_nodes(
     miners: &mut Vec<usize>,
@@ -15,21 +96,22 @@ pub fn sample_bitcoin_miner_nodes(
     *miners = rand.sample_nodes(&(0..num_of_total_nodes).collect(), num_of_miners);
 }
 
-pub fn sample_bitcoin_node_countries(
-    country: &mut [Country],
+pub fn sample_bitcoin_node_regions(
+    region: &mut [usize],
     miners: &[usize],
+    network_stats: &dyn NetworkStats,
     rand: &mut RandomnessEngine,
     num_of_total_nodes: usize,
     num_of_miner: usize,
 ) {
-    assert_eq!(country.len(), num_of_total_nodes);
+    assert_eq!(region.len(), num_of_total_nodes);
     assert_eq!(miners.len(), num_of_miner);
 
-    for (node, country) in country.iter_mut().enumerate() {
+    for (node, region) in region.iter_mut().enumerate() {
         if miners.contains(&node) {
-            *country = BitcoinProofOfWorkGlobalNetworkStats86Countries::sample_miner_region(rand);
+            *region = network_stats.sample_miner_region(rand);
         } else {
-            *country = BitcoinNodeGlobalNetworkStats86Countries::sample_region(rand);
+            *region = network_stats.sample_node_region(rand);
         }
     }
 }
@@ -37,6 +119,7 @@ pub fn sample_bitcoin_node_countries(
 pub fn reset_and_sample_all_bitcoin_miners_hash_power(
     miners: &[usize],
     hash_power: &mut [Option<f64>],
+    profile: &BitcoinProfile,
     rand: &mut RandomnessEngine,
     average_block_mining_interval: f64,
     difficulty: f64,
@@ -47,7 +130,7 @@ pub fn reset_and_sample_all_bitcoin_miners_hash_power(
 
     // initial sampling hash-powers
     let sampled_hash_power: Vec<f64> = (0..miners.len())
-        .map(|_| BitcoinProofOfWorkGlobalNetworkStats86Countries::sample_miner_hash_power(rand))
+        .map(|_| profile.sample_miner_hash_power(rand))
         .collect();
 
     let total_hash_power: f64 = sampled_hash_power.iter().sum();
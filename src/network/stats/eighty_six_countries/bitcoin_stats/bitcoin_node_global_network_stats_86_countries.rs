@@ -1,7 +1,6 @@
-use crate::network::stats::eighty_six_countries::{get_country_from_usize, Country};
-use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::network::stats::eighty_six_countries::NUM_OF_COUNTRIES;
 
-const BITCOIN_COUNTRIES_DISTRIBUTION_2022: [f64; 86] = [
+pub(crate) const BITCOIN_COUNTRIES_DISTRIBUTION_2022: [f64; NUM_OF_COUNTRIES] = [
     0.0,
     0.0,
     0.00211889346674514,
@@ -91,11 +90,3 @@ const BITCOIN_COUNTRIES_DISTRIBUTION_2022: [f64; 86] = [
 ];
 
 pub const BITCOIN_NUM_NODES_2022: usize = 7983;
-
-pub struct BitcoinNodeGlobalNetworkStats86Countries {}
-
-impl BitcoinNodeGlobalNetworkStats86Countries {
-    pub fn sample_region(rand: &mut RandomnessEngine) -> Country {
-        get_country_from_usize(rand.sample_from_distribution(&BITCOIN_COUNTRIES_DISTRIBUTION_2022))
-    }
-}
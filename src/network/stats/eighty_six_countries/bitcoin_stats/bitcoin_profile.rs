@@ -0,0 +1,75 @@
+//! Named, versioned Bitcoin dataset profiles.
+//!
+//! A profile bundles everything that describes the Bitcoin network of a given
+//! year: block size distribution, number of nodes and miners, miner geography,
+//! hash power distribution and difficulty. The difficulty is expressed in the
+//! same unit as the hash power bins (ExaHash per second), so it equals the sum
+//! of the hash power bins.
+//!
+//! Only profiles backed by the measured datasets of the crate are provided,
+//! so only the 2022 one: the crate has no measured node, miner and hash power
+//! data of other years. A profile of another year can be built from sourced
+//! data, taking the unchanged fields from [`BITCOIN_2022`].
+
+use crate::ledger_data::block_factory::{BITCOIN_BLOCK_SIZE_2020, BITCOIN_BLOCK_SIZE_2020_BINS};
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_node_global_network_stats_86_countries::BITCOIN_NUM_NODES_2022;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_pow_global_network_stats_86_countries::{
+    BITCOIN_DIFFICULTY_2022, BITCOIN_HASH_POWER_DISTRIBUTION_2022,
+    BITCOIN_HASH_POWER_DISTRIBUTION_BIN_2022, BITCOIN_MINER_REGION_DISTRIBUTION_2020,
+    BITCOIN_NUM_MINERS_2022,
+};
+use crate::simulator::randomness_engine::RandomnessEngine;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitcoinProfile {
+    pub name: &'static str,
+    pub block_size_distribution: &'static [f64],
+    pub block_size_bins: &'static [f64],
+    pub num_of_nodes: usize,
+    pub num_of_miners: usize,
+    pub miner_region_distribution: &'static [f64],
+    pub hash_power_distribution: &'static [f64],
+    pub hash_power_bins: &'static [f64],
+    pub difficulty: f64,
+}
+
+/// The 2022 network. Block sizes and miner regions are the latest measured
+/// ones, which are from 2020.
+pub const BITCOIN_2022: BitcoinProfile = BitcoinProfile {
+    name: "bitcoin-2022",
+    block_size_distribution: &BITCOIN_BLOCK_SIZE_2020,
+    block_size_bins: &BITCOIN_BLOCK_SIZE_2020_BINS,
+    num_of_nodes: BITCOIN_NUM_NODES_2022,
+    num_of_miners: BITCOIN_NUM_MINERS_2022,
+    miner_region_distribution: &BITCOIN_MINER_REGION_DISTRIBUTION_2020,
+    hash_power_distribution: &BITCOIN_HASH_POWER_DISTRIBUTION_2022,
+    hash_power_bins: &BITCOIN_HASH_POWER_DISTRIBUTION_BIN_2022,
+    difficulty: BITCOIN_DIFFICULTY_2022,
+};
+
+pub const BITCOIN_PROFILES: [BitcoinProfile; 1] = [BITCOIN_2022];
+
+impl Default for BitcoinProfile {
+    fn default() -> Self {
+        BITCOIN_2022
+    }
+}
+
+impl BitcoinProfile {
+    /// Returns the profile with the given name, e.g. `"bitcoin-2022"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        BITCOIN_PROFILES
+            .iter()
+            .find(|profile| profile.name == name)
+            .copied()
+    }
+
+    pub fn sample_block_size(&self, rand: &mut RandomnessEngine) -> u64 {
+        rand.sample_from_distribution_with_bins(self.block_size_distribution, self.block_size_bins)
+            as u64
+    }
+
+    pub fn sample_miner_hash_power(&self, rand: &mut RandomnessEngine) -> f64 {
+        rand.sample_from_distribution_with_bins(self.hash_power_distribution, self.hash_power_bins)
+    }
+}
@@ -1,4 +1,17 @@
+pub mod bft_global_network_scenario;
 pub mod bitcoin_global_network_scenario;
+pub mod ethereum_global_network_scenario;
+pub mod ghostdag_global_network_scenario;
+pub mod praos_global_network_scenario;
+pub mod run_manifest;
+pub mod simulation_result;
+
+use crate::log::Logger;
+use crate::network::Network;
+use crate::simulator::event::sampling_event::SamplingEvent;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::time::{Duration, Instant};
 
 pub struct ScenarioData {
     pub name: String,
@@ -28,3 +41,72 @@ impl From<&ScenarioData> for Vec<String> {
         ]
     }
 }
+
+pub(crate) fn simulation_stop_condition(simulator: &Simulator, stop_time: f64) -> bool {
+    simulator.simulation_time > stop_time
+}
+
+/// Executes the events until the stop time, passing each event to the loggers
+/// before and after its execution. A progress message is printed every
+/// `progress_logger_seconds` of real time. The [`SamplingEvent`]s of the
+/// loggers sampling the network are put first, once per distinct interval.
+pub(crate) fn simulate_with_loggers<N: Network>(
+    network: &mut N,
+    simulator: &mut Simulator,
+    rand: &mut RandomnessEngine,
+    loggers: &mut [Box<dyn Logger>],
+    stop_time: f64,
+    progress_logger_seconds: u64,
+) -> Result<(), std::io::Error> {
+    let progress_message_intervals = Duration::from_secs(progress_logger_seconds).as_nanos();
+    let simulation_starting_time = Instant::now();
+    let mut last_progress_message_time = simulation_starting_time;
+    let mut sampling_intervals: Vec<f64> = Vec::new();
+    for interval in loggers
+        .iter()
+        .filter_map(|logger| logger.sampling_interval())
+    {
+        if !sampling_intervals.contains(&interval) {
+            sampling_intervals.push(interval);
+            simulator.put_event(Box::new(SamplingEvent::new(interval)), interval);
+        }
+    }
+    while simulator.is_there_more_events() && !simulation_stop_condition(simulator, stop_time) {
+        let logger_info = simulator
+            .peek_event()
+            .unwrap()
+            .logger_data(simulator.simulation_time);
+
+        for logger in loggers.iter_mut() {
+            logger.log_before_each_event(&logger_info, network)?;
+        }
+
+        simulator.execute_next_event(network, rand);
+
+        for logger in loggers.iter_mut() {
+            logger.log_after_each_event(&logger_info, network)?;
+        }
+
+        if Instant::now()
+            .duration_since(last_progress_message_time)
+            .as_nanos()
+            > progress_message_intervals
+        {
+            let real_time = Instant::now()
+                .duration_since(simulation_starting_time)
+                .as_secs();
+            let real_time_hour = (real_time / 3600) % 24;
+            let real_time_minute = (real_time / 60) % 60;
+            let real_time_second = real_time % 60;
+
+            let simulation_time = Duration::from_secs_f64(simulator.simulation_time).as_secs();
+            let simulation_time_hour = (simulation_time / 3600) % 24;
+            let simulation_time_minute = (simulation_time / 60) % 60;
+            let simulation_time_second = simulation_time % 60;
+
+            eprintln!("Simulation in progress... Elapsed Real Time: {:02}:{:02}:{:02}, Elapsed Simulation Time: {:02}:{:02}:{:02}", real_time_hour, real_time_minute, real_time_second, simulation_time_hour, simulation_time_minute, simulation_time_second);
+            last_progress_message_time = Instant::now();
+        }
+    }
+    Ok(())
+}
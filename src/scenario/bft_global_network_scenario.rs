@@ -0,0 +1,159 @@
+use crate::consensus::algorithm::BFTConsensus;
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::BFTConsensusConfig;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::block_factory::BFT_AVERAGE_BLOCK_SIZE;
+use crate::log::Logger;
+use crate::network::bft_network::BFTNetwork;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
+use crate::scenario::{simulate_with_loggers, ScenarioData};
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// A BFT consensus run by replicas placed with the same geography and
+/// bandwidth model as the Bitcoin scenario. The number of replicas is taken
+/// from the consensus config.
+pub struct BFTGlobalNetworkScenario<C: BFTConsensus<B = BFTBlock, L = LocalBlockTree>> {
+    loggers: Vec<Box<dyn Logger>>,
+    name: String,
+    seed: u64,
+    stop_time: f64,
+    config: C::G,
+    num_of_crashed_replicas: usize,
+    num_of_byzantine_replicas: usize,
+    block_size: u64,
+    node_regions: Option<Vec<usize>>,
+    progress_logger_seconds: u64,
+    network_stats: Rc<dyn NetworkStats>,
+}
+
+impl<C: BFTConsensus<B = BFTBlock, L = LocalBlockTree> + Clone> BFTGlobalNetworkScenario<C>
+where
+    C::G: BFTConsensusConfig + Clone,
+{
+    pub fn new(name: &str, seed: u64, stop_time: f64, config: C::G) -> Self {
+        Self {
+            loggers: Vec::new(),
+            name: name.to_string(),
+            seed,
+            stop_time,
+            config,
+            num_of_crashed_replicas: 0,
+            num_of_byzantine_replicas: 0,
+            block_size: BFT_AVERAGE_BLOCK_SIZE,
+            node_regions: None,
+            progress_logger_seconds: 2,
+            network_stats: Rc::new(BitcoinGlobalNetworkStats86Countries::default()),
+        }
+    }
+
+    pub fn add_new_logger(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+
+    /// Sets the number of crashed replicas, chosen at random. They stay
+    /// silent for the whole run (crash faults).
+    pub fn set_num_of_crashed_replicas(&mut self, num_of_crashed_replicas: usize) {
+        self.num_of_crashed_replicas = num_of_crashed_replicas;
+    }
+
+    /// Sets the number of Byzantine replicas, chosen at random among the ones
+    /// not crashed. When leading, they propose two conflicting blocks, and
+    /// they vote for both blocks of a Byzantine leader.
+    pub fn set_num_of_byzantine_replicas(&mut self, num_of_byzantine_replicas: usize) {
+        self.num_of_byzantine_replicas = num_of_byzantine_replicas;
+    }
+
+    /// Sets the size of every proposed block in bytes.
+    pub fn set_block_size(&mut self, block_size: u64) {
+        self.block_size = block_size;
+    }
+
+    /// Places the replicas in the given regions, e.g. the regions of the nodes
+    /// of another scenario. By default regions are sampled from the node
+    /// distribution of the network stats.
+    pub fn set_node_regions(&mut self, node_regions: Vec<usize>) {
+        self.node_regions = Some(node_regions);
+    }
+
+    /// Sets the dataset of regions, latencies and bandwidths. The default is
+    /// the 86 countries dataset with the Bitcoin node distribution.
+    pub fn set_network_stats(&mut self, network_stats: Rc<dyn NetworkStats>) {
+        self.network_stats = network_stats;
+    }
+
+    pub fn run(&mut self) -> Result<(), std::io::Error> {
+        let preparation_starting_time = Instant::now();
+
+        let num_of_nodes = self.config.get_num_of_replicas();
+        let scenario_data = ScenarioData::new(
+            self.name.to_string(),
+            num_of_nodes,
+            C::protocol_name().to_string(),
+        );
+
+        // create network, simulator, randomness_engine and prepare the network.
+        let mut network: BFTNetwork<C> =
+            BFTNetwork::new_with_size(num_of_nodes, 0, self.config.clone(), self.block_size);
+        network.resource.network_stats = Rc::clone(&self.network_stats);
+        let mut simulator = Simulator::new();
+        let mut rand = RandomnessEngine::new(self.seed);
+
+        network.prepare(
+            &mut rand,
+            self.num_of_crashed_replicas,
+            self.num_of_byzantine_replicas,
+            self.node_regions.as_deref(),
+        );
+        network.insert_initial_event(&mut simulator);
+
+        for logger in self.loggers.iter_mut() {
+            logger.initial_log(&scenario_data)?;
+        }
+
+        // running the simulation
+        eprintln!("Staring {}...", scenario_data.name);
+        let simulation_starting_time = Instant::now();
+        simulate_with_loggers(
+            &mut network,
+            &mut simulator,
+            &mut rand,
+            &mut self.loggers,
+            self.stop_time,
+            self.progress_logger_seconds,
+        )?;
+        for logger in self.loggers.iter_mut() {
+            logger.final_log(&scenario_data)?;
+        }
+        eprintln!("Finished {}.", self.name);
+
+        let simulation_ending_time = Instant::now();
+
+        println!(
+            "Total Created Blocks: {}",
+            network.resource.blocks.len() - 1
+        );
+        println!("Total Sent Votes: {}", network.protocol.messages.len());
+
+        let setup_duration = simulation_starting_time
+            .duration_since(preparation_starting_time)
+            .as_millis();
+        let propagate_duration = simulation_ending_time
+            .duration_since(simulation_starting_time)
+            .as_millis();
+        println!("Total Executed Events: {}", simulator.inserted_events);
+        println!("Final Simulation Time: {}", simulator.simulation_time);
+        println!(
+            "Setup Elapsed time: {:.3}sec.",
+            (setup_duration as f64) / 1000.0
+        );
+        println!(
+            "Propagation Elapsed time: {:.3}sec.",
+            (propagate_duration as f64) / 1000.0
+        );
+        Ok(())
+    }
+}
@@ -1,12 +1,30 @@
-use std::time::{Duration, Instant};
-use crate::log::Logger;
-use crate::network::bitcoin_network::BitcoinNetwork;
+use crate::consensus::algorithm::nakamoto_consensus::NakamotoConsensus;
+use crate::consensus::config::bitcoin_ng_config::BitcoinNGConfig;
+use crate::log::block_arrival_logger::BlockArrivalLogger;
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::log::in_memory_sink::InMemoryEvents;
+use crate::log::{EventLogger, Logger};
+use crate::network::bitcoin_network::{BitcoinConsensus, BitcoinNetwork};
+use crate::network::message::RelayProtocol;
+use crate::network::node::link::TransferModel;
+use crate::network::node::mining_pool::MiningPoolConfig;
+use crate::network::node::node_type::NodeTypeConfig;
+use crate::network::node::relay_overlay::RelayOverlayConfig;
+use crate::network::node::validation::ValidationConfig;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::{
+    BitcoinProfile, BITCOIN_PROFILES,
+};
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
 use crate::network::Network;
-use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_node_global_network_stats_86_countries::BITCOIN_NUM_NODES_2022;
-use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_pow_global_network_stats_86_countries::{BITCOIN_DIFFICULTY_2022, BITCOIN_NUM_MINERS_2022};
-use crate::scenario::ScenarioData;
+use crate::scenario::run_manifest::RunManifest;
+use crate::scenario::simulation_result::SimulationResult;
+use crate::scenario::{simulate_with_loggers, simulation_stop_condition, ScenarioData};
 use crate::simulator::randomness_engine::RandomnessEngine;
 use crate::simulator::Simulator;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Instant;
 
 //----------Functions----------//
 pub fn _simulate_propagation(
@@ -20,10 +38,6 @@ pub fn _simulate_propagation(
     }
 }
 
-fn simulation_stop_condition(simulator: &Simulator, stop_time: f64) -> bool {
-    simulator.simulation_time > stop_time
-}
-
 pub struct BitcoinGlobalNetworkScenario {
     average_block_mining_interval: f64,
     confirmation_depth: i32,
@@ -37,6 +51,25 @@ pub struct BitcoinGlobalNetworkScenario {
     num_of_neighbors: usize,
     num_of_nodes: usize,
     progress_logger_seconds: u64,
+    transfer_model: TransferModel,
+    network_stats: Option<Rc<dyn NetworkStats>>,
+    profile: BitcoinProfile,
+    mining_pool_config: Option<MiningPoolConfig>,
+    relay_overlay_config: Option<RelayOverlayConfig>,
+    relay_protocol: RelayProtocol,
+    validation_config: Option<ValidationConfig>,
+    bitcoin_ng_config: Option<BitcoinNGConfig>,
+    node_type_config: NodeTypeConfig,
+    run_manifest_path: Option<PathBuf>,
+}
+
+/// The totals of a Bitcoin-NG run, on the heaviest chain known to any node.
+struct BitcoinNGSummary {
+    num_of_key_blocks: usize,
+    num_of_microblocks: usize,
+    /// The microblock fees on the main chain, the number of leaders earning
+    /// fees and the fee share of the top leader.
+    fees: Option<(f64, usize, f64)>,
 }
 
 impl BitcoinGlobalNetworkScenario {
@@ -47,6 +80,7 @@ impl BitcoinGlobalNetworkScenario {
         seed: u64,
         stop_time: f64,
     ) -> Self {
+        let profile = BitcoinProfile::default();
         Self {
             average_block_mining_interval: average_block_interval,
             confirmation_depth,
@@ -55,11 +89,21 @@ impl BitcoinGlobalNetworkScenario {
             seed,
             stop_time,
             average_num_of_blocks: (stop_time / average_block_interval) as usize,
-            difficulty: BITCOIN_DIFFICULTY_2022,    // 225.0
-            num_of_miners: BITCOIN_NUM_MINERS_2022, // 30
+            difficulty: profile.difficulty,       // 225.0
+            num_of_miners: profile.num_of_miners, // 30
             num_of_neighbors: 8,
-            num_of_nodes: BITCOIN_NUM_NODES_2022 + BITCOIN_NUM_MINERS_2022, // 8013
+            num_of_nodes: profile.num_of_nodes + profile.num_of_miners, // 8013
             progress_logger_seconds: 2,
+            transfer_model: TransferModel::default(),
+            network_stats: None,
+            profile,
+            mining_pool_config: None,
+            relay_overlay_config: None,
+            relay_protocol: RelayProtocol::default(),
+            validation_config: None,
+            bitcoin_ng_config: None,
+            node_type_config: NodeTypeConfig::default(),
+            run_manifest_path: None,
         }
     }
 
@@ -67,21 +111,135 @@ impl BitcoinGlobalNetworkScenario {
         self.loggers.push(logger);
     }
 
-    pub fn run(&mut self) -> Result<(), std::io::Error> {
+    /// Sets the model used for the transfer time of blocks between nodes. The
+    /// default is [`TransferModel::Bandwidth`].
+    pub fn set_transfer_model(&mut self, transfer_model: TransferModel) {
+        self.transfer_model = transfer_model;
+    }
+
+    /// Sets the dataset of regions, latencies, bandwidths and node
+    /// distributions. The default is [`BitcoinGlobalNetworkStats86Countries`]
+    /// with the miner geography of the profile. This dataset is used whatever
+    /// the profile, also when [`Self::set_profile`] is called afterwards. Its
+    /// name is recorded in the run manifest.
+    pub fn set_network_stats(&mut self, network_stats: Rc<dyn NetworkStats>) {
+        self.network_stats = Some(network_stats);
+    }
+
+    /// Sets the Bitcoin dataset profile: block size distribution, number of
+    /// nodes and miners, miner geography, hash power distribution and
+    /// difficulty. The default is `bitcoin-2022`.
+    ///
+    /// Unless set with [`Self::set_network_stats`], the network stats are the
+    /// 86 countries dataset with the miner geography of the profile.
+    pub fn set_profile(&mut self, profile: BitcoinProfile) {
+        self.difficulty = profile.difficulty;
+        self.num_of_miners = profile.num_of_miners;
+        self.num_of_nodes = profile.num_of_nodes + profile.num_of_miners;
+        self.profile = profile;
+    }
+
+    /// Turns every miner into a mining pool operator with hashers that receive
+    /// new work with a stratum latency. By default miners mine directly on
+    /// their own chain head.
+    pub fn set_mining_pools(&mut self, mining_pool_config: MiningPoolConfig) {
+        self.mining_pool_config = Some(mining_pool_config);
+    }
+
+    /// Adds a dedicated relay overlay (FIBRE-like) among a subset of nodes in
+    /// parallel with the P2P gossip. By default there is no relay overlay.
+    pub fn set_relay_overlay(&mut self, relay_overlay_config: RelayOverlayConfig) {
+        self.relay_overlay_config = Some(relay_overlay_config);
+    }
+
+    /// Sets the protocol used to relay new blocks. The default is
+    /// [`RelayProtocol::Inv`].
+    pub fn set_relay_protocol(&mut self, relay_protocol: RelayProtocol) {
+        self.relay_protocol = relay_protocol;
+    }
+
+    /// Enables block validation delays. By default nodes process and relay
+    /// blocks right after download.
+    pub fn set_validation(&mut self, validation_config: ValidationConfig) {
+        self.validation_config = Some(validation_config);
+    }
+
+    /// Runs Bitcoin-NG: mined blocks become key blocks electing a leader,
+    /// which emits microblocks with the transactions until the next key
+    /// block. The confirmation depth is counted in key blocks. By default
+    /// every mined block carries transactions.
+    pub fn set_bitcoin_ng(&mut self, bitcoin_ng_config: BitcoinNGConfig) {
+        self.bitcoin_ng_config = Some(bitcoin_ng_config);
+    }
+
+    /// Sets the number of listening-only full nodes and SPV clients among the
+    /// non-mining nodes, see [`NodeTypeConfig`]. By default every non-mining
+    /// node is a relay node. Use a [`NodeTypeFilter`] to log the events of
+    /// some node types only.
+    ///
+    /// [`NodeTypeFilter`]: crate::log::node_type_filter::NodeTypeFilter
+    pub fn set_node_types(&mut self, node_type_config: NodeTypeConfig) {
+        self.node_type_config = node_type_config;
+    }
+
+    /// Writes a JSON [`RunManifest`] of the run to the path, e.g. in the output
+    /// directory of the loggers, next to their files. By default no manifest
+    /// is written.
+    pub fn set_run_manifest_path(&mut self, path: &Path) {
+        self.run_manifest_path = Some(path.to_path_buf());
+    }
+
+    /// Sets the Bitcoin dataset profile by its name, e.g. `"bitcoin-2022"`.
+    /// See [`Self::set_profile`].
+    pub fn set_profile_by_name(&mut self, name: &str) -> Result<(), String> {
+        match BitcoinProfile::from_name(name) {
+            Some(profile) => {
+                self.set_profile(profile);
+                Ok(())
+            }
+            None => {
+                let names: Vec<&str> = BITCOIN_PROFILES
+                    .iter()
+                    .map(|profile| profile.name)
+                    .collect();
+                Err(format!(
+                    "Unknown Bitcoin profile: {} (available: {})",
+                    name,
+                    names.join(", ")
+                ))
+            }
+        }
+    }
+
+    /// Runs the scenario and returns its [`SimulationResult`], with the
+    /// propagation of every block at the [`DEFAULT_PROPAGATION_SHARES`] and
+    /// the reorgs of all the nodes.
+    ///
+    /// [`DEFAULT_PROPAGATION_SHARES`]: crate::log::block_arrival_logger::DEFAULT_PROPAGATION_SHARES
+    pub fn run(&mut self) -> Result<SimulationResult, std::io::Error> {
+        self.run_with_consensus::<NakamotoConsensus>()
+    }
+
+    /// Runs the scenario with every node running the consensus `C` instead
+    /// of Nakamoto consensus.
+    pub fn run_with_consensus<C: BitcoinConsensus>(
+        &mut self,
+    ) -> Result<SimulationResult, std::io::Error> {
         const GENESIS_BLOCK_INDEX: usize = 0;
 
         let preparation_starting_time = Instant::now();
-        let progress_message_intervals =
-            Duration::from_secs(self.progress_logger_seconds).as_nanos();
 
         let scenario_data = ScenarioData::new(
             self.name.to_string(),
             self.num_of_nodes,
-            "1-day of bitcoin".to_string(),
+            match self.bitcoin_ng_config {
+                Some(_) => format!("1-day of Bitcoin-NG on {}", self.profile.name),
+                None => format!("1-day of {}", self.profile.name),
+            },
         );
 
         // create network, simulator, randomness_engine and prepare the network.
-        let mut network = BitcoinNetwork::new_with_size(
+        let mut network: BitcoinNetwork<C> = BitcoinNetwork::new_with_size(
             self.num_of_nodes,
             self.average_num_of_blocks,
             self.average_block_mining_interval,
@@ -90,75 +248,82 @@ impl BitcoinGlobalNetworkScenario {
             self.difficulty,
             self.num_of_miners,
         );
+        network.resource.transfer_model = self.transfer_model;
+        network.resource.network_stats = match &self.network_stats {
+            Some(network_stats) => Rc::clone(network_stats),
+            None => Rc::new(BitcoinGlobalNetworkStats86Countries::from_profile(
+                &self.profile,
+            )),
+        };
+        network.protocol.profile = self.profile;
+        network.protocol.mining_pool_config = self.mining_pool_config;
+        network.resource.relay_overlay_config = self.relay_overlay_config.clone();
+        network.resource.relay_protocol = self.relay_protocol;
+        network.resource.validation_config = self.validation_config;
+        network.protocol.bitcoin_ng_config = self.bitcoin_ng_config;
+        network.resource.node_type_config = self.node_type_config;
         let mut simulator = Simulator::new();
         let mut rand = RandomnessEngine::new(self.seed);
 
-        network.prepare(
-            &mut rand,
-            self.average_block_mining_interval,
-            self.num_of_neighbors,
-            self.num_of_miners,
-        );
+        network
+            .prepare(
+                &mut rand,
+                self.average_block_mining_interval,
+                self.num_of_neighbors,
+                self.num_of_miners,
+            )
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
         network.insert_initial_event(&mut simulator, &mut rand);
 
-        for logger in self.loggers.iter_mut() {
-            logger.initial_log(&scenario_data)?;
-        }
+        // the loggers of the simulation result run after the added ones, which
+        // are given back at the end, even if logging fails.
+        let mut loggers = std::mem::take(&mut self.loggers);
+        let num_of_added_loggers = loggers.len();
+        let block_propagations = InMemoryEvents::default();
+        let reorgs = InMemoryEvents::default();
+        loggers.push(Box::new(EventLogger::without_records(
+            BlockArrivalLogger::default().with_propagation_sink(block_propagations.clone()),
+        )));
+        loggers.push(Box::new(EventLogger::without_records(
+            BlockchainReorgLogger::new().with_reorg_sink(reorgs.clone()),
+        )));
 
-        // running the simulation
-        eprintln!("Staring {}...", scenario_data.name);
-        let simulation_starting_time = Instant::now();
-        let mut last_progress_message_time = simulation_starting_time;
-        while simulator.is_there_more_events()
-            && !simulation_stop_condition(&simulator, self.stop_time)
-        {
-            let logger_info = simulator
-                .peek_event()
-                .unwrap()
-                .logger_data(simulator.simulation_time);
-
-            for logger in self.loggers.iter_mut() {
-                logger.log_before_each_event(&logger_info, &network)?;
+        let simulation_result = (|| -> Result<Instant, std::io::Error> {
+            for logger in loggers.iter_mut() {
+                logger.initial_log(&scenario_data)?;
             }
 
-            simulator.execute_next_event(&mut network, &mut rand);
-
-            for logger in self.loggers.iter_mut() {
-                logger.log_after_each_event(&logger_info, &network)?;
-            }
-
-            if Instant::now()
-                .duration_since(last_progress_message_time)
-                .as_nanos()
-                > progress_message_intervals
-            {
-                let real_time = Instant::now()
-                    .duration_since(simulation_starting_time)
-                    .as_secs();
-                let real_time_hour = (real_time / 3600) % 24;
-                let real_time_minute = (real_time / 60) % 60;
-                let real_time_second = real_time % 60;
-
-                let simulation_time = Duration::from_secs_f64(simulator.simulation_time).as_secs();
-                let simulation_time_hour = (simulation_time / 3600) % 24;
-                let simulation_time_minute = (simulation_time / 60) % 60;
-                let simulation_time_second = simulation_time % 60;
-
-                eprintln!("Simulation in progress... Elapsed Real Time: {:02}:{:02}:{:02}, Elapsed Simulation Time: {:02}:{:02}:{:02}", real_time_hour, real_time_minute, real_time_second, simulation_time_hour, simulation_time_minute, simulation_time_second);
-                last_progress_message_time = Instant::now();
+            // running the simulation
+            eprintln!("Staring {}...", scenario_data.name);
+            let simulation_starting_time = Instant::now();
+            simulate_with_loggers(
+                &mut network,
+                &mut simulator,
+                &mut rand,
+                &mut loggers,
+                self.stop_time,
+                self.progress_logger_seconds,
+            )?;
+            for logger in loggers.iter_mut() {
+                logger.final_log(&scenario_data)?;
             }
-        }
-        for logger in self.loggers.iter_mut() {
-            logger.final_log(&scenario_data)?;
-        }
+            Ok(simulation_starting_time)
+        })();
+        loggers.truncate(num_of_added_loggers);
+        self.loggers = loggers;
+        let simulation_starting_time = simulation_result?;
         eprintln!("Finished {}.", self.name);
 
         let simulation_ending_time = Instant::now();
 
-        println!(
-            "Total Created Blocks: {}",
-            network.resource.blocks.len() - 1
-        );
+        let num_of_created_blocks = network.resource.blocks.len() - 1;
+        println!("Total Created Blocks: {}", num_of_created_blocks);
+        let bitcoin_ng_summary = self
+            .bitcoin_ng_config
+            .map(|_| Self::bitcoin_ng_summary(&network));
+        if let Some(summary) = &bitcoin_ng_summary {
+            summary.print();
+        }
 
         let setup_duration = simulation_starting_time
             .duration_since(preparation_starting_time)
@@ -176,6 +341,139 @@ impl BitcoinGlobalNetworkScenario {
             "Propagation Elapsed time: {:.3}sec.",
             (propagate_duration as f64) / 1000.0
         );
-        Ok(())
+
+        if let Some(path) = &self.run_manifest_path {
+            let mut manifest = self.run_manifest::<C>(&scenario_data);
+            manifest.setup_elapsed_seconds = (setup_duration as f64) / 1000.0;
+            manifest.propagation_elapsed_seconds = (propagate_duration as f64) / 1000.0;
+            manifest.inserted_events = simulator.inserted_events;
+            manifest.final_simulation_time = simulator.simulation_time;
+            manifest.add_summary_metric("TotalCreatedBlocks", num_of_created_blocks);
+            if let Some(summary) = &bitcoin_ng_summary {
+                summary.add_to_manifest(&mut manifest);
+            }
+            manifest.write(path)?;
+        }
+
+        Ok(SimulationResult {
+            name: self.name.clone(),
+            seed: self.seed,
+            num_of_created_blocks,
+            inserted_events: simulator.inserted_events,
+            final_simulation_time: simulator.simulation_time,
+            setup_elapsed_seconds: (setup_duration as f64) / 1000.0,
+            propagation_elapsed_seconds: (propagate_duration as f64) / 1000.0,
+            block_propagations: block_propagations.take(),
+            reorgs: reorgs.take(),
+        })
+    }
+
+    /// Returns the manifest of the run with the parameters of the scenario.
+    fn run_manifest<C: BitcoinConsensus>(&self, scenario_data: &ScenarioData) -> RunManifest {
+        let mut manifest = RunManifest::new(
+            "BitcoinGlobalNetworkScenario",
+            &self.name,
+            &scenario_data.network_type,
+            self.seed,
+        );
+        manifest.add_parameter("Consensus", std::any::type_name::<C>());
+        manifest.add_parameter(
+            "AverageBlockMiningInterval",
+            self.average_block_mining_interval,
+        );
+        manifest.add_parameter("ConfirmationDepth", self.confirmation_depth);
+        manifest.add_parameter("StopTime", self.stop_time);
+        manifest.add_parameter("AverageNumOfBlocks", self.average_num_of_blocks);
+        manifest.add_parameter("Difficulty", self.difficulty);
+        manifest.add_parameter("NumOfNodes", self.num_of_nodes);
+        manifest.add_parameter("NumOfMiners", self.num_of_miners);
+        manifest.add_parameter("NumOfNeighbors", self.num_of_neighbors);
+        manifest.add_parameter("Profile", self.profile.name);
+        manifest.add_parameter("ProfileConfig", format!("{:?}", self.profile));
+        manifest.add_parameter(
+            "NetworkStats",
+            self.network_stats
+                .as_ref()
+                .map(|network_stats| network_stats.get_name()),
+        );
+        manifest.add_parameter("TransferModel", format!("{:?}", self.transfer_model));
+        manifest.add_parameter(
+            "MiningPools",
+            self.mining_pool_config
+                .as_ref()
+                .map(|config| format!("{:?}", config)),
+        );
+        manifest.add_parameter(
+            "RelayOverlay",
+            self.relay_overlay_config
+                .as_ref()
+                .map(|config| format!("{:?}", config)),
+        );
+        manifest.add_parameter("RelayProtocol", format!("{:?}", self.relay_protocol));
+        manifest.add_parameter(
+            "Validation",
+            self.validation_config
+                .as_ref()
+                .map(|config| format!("{:?}", config)),
+        );
+        manifest.add_parameter(
+            "BitcoinNG",
+            self.bitcoin_ng_config
+                .as_ref()
+                .map(|config| format!("{:?}", config)),
+        );
+        manifest.add_parameter("NodeTypes", format!("{:?}", self.node_type_config));
+        manifest
+    }
+
+    fn bitcoin_ng_summary<C: BitcoinConsensus>(network: &BitcoinNetwork<C>) -> BitcoinNGSummary {
+        let blocks = &network.resource.blocks;
+        let num_of_microblocks = blocks.iter().filter(|block| block.is_microblock).count();
+
+        // the fees paid on the heaviest chain known to any node.
+        let fees = network
+            .ecs
+            .consensus_algorithm
+            .iter()
+            .map(|consensus| consensus.get_canonical_chain_head_index())
+            .max_by_key(|&head| (blocks[head].key_height, blocks[head].height))
+            .map(|head| {
+                let fees = network.microblock_fees_by_leader(head);
+                let total_fees: f64 = fees.values().sum();
+                let top_leader_fees = fees.values().copied().fold(0.0, f64::max);
+                (total_fees, fees.len(), top_leader_fees / total_fees)
+            });
+        BitcoinNGSummary {
+            num_of_key_blocks: blocks.len() - 1 - num_of_microblocks,
+            num_of_microblocks,
+            fees,
+        }
+    }
+}
+
+impl BitcoinNGSummary {
+    fn print(&self) {
+        println!("Total Key Blocks: {}", self.num_of_key_blocks);
+        println!("Total Microblocks: {}", self.num_of_microblocks);
+        let Some((total_fees, num_of_leaders, top_leader_share)) = self.fees else {
+            return;
+        };
+        println!("Microblock Fees on Main Chain: {}", total_fees);
+        println!("Number of Leaders Earning Fees: {}", num_of_leaders);
+        if total_fees > 0.0 {
+            println!("Fee Share of Top Leader: {:.3}", top_leader_share);
+        }
+    }
+
+    fn add_to_manifest(&self, manifest: &mut RunManifest) {
+        manifest.add_summary_metric("TotalKeyBlocks", self.num_of_key_blocks);
+        manifest.add_summary_metric("TotalMicroblocks", self.num_of_microblocks);
+        if let Some((total_fees, num_of_leaders, top_leader_share)) = self.fees {
+            manifest.add_summary_metric("MicroblockFeesOnMainChain", total_fees);
+            manifest.add_summary_metric("NumOfLeadersEarningFees", num_of_leaders);
+            if total_fees > 0.0 {
+                manifest.add_summary_metric("FeeShareOfTopLeader", top_leader_share);
+            }
+        }
     }
 }
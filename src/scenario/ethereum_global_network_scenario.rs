@@ -0,0 +1,173 @@
+use crate::consensus::config::gasper_consensus_config::GasperConsensusConfig;
+use crate::ledger_data::block_factory::ETHEREUM_AVERAGE_BLOCK_SIZE;
+use crate::log::Logger;
+use crate::network::ethereum_network::EthereumNetwork;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
+use crate::scenario::{simulate_with_loggers, ScenarioData};
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// A Proof-of-Stake (Gasper) network where every node is a validator, placed
+/// with the same geography and bandwidth model as the Bitcoin scenario.
+pub struct EthereumGlobalNetworkScenario {
+    loggers: Vec<Box<dyn Logger>>,
+    name: String,
+    seed: u64,
+    stop_time: f64,
+    slot_duration: f64,
+    slots_per_epoch: u64,
+    num_of_neighbors: usize,
+    num_of_nodes: usize,
+    offline_validator_share: f64,
+    block_size: u64,
+    validator_stakes: Option<Vec<f64>>,
+    progress_logger_seconds: u64,
+    network_stats: Rc<dyn NetworkStats>,
+}
+
+impl EthereumGlobalNetworkScenario {
+    pub fn new(name: &str, seed: u64, stop_time: f64) -> Self {
+        Self {
+            loggers: Vec::new(),
+            name: name.to_string(),
+            seed,
+            stop_time,
+            slot_duration: 12.0,
+            slots_per_epoch: 32,
+            num_of_neighbors: 8,
+            num_of_nodes: 512,
+            offline_validator_share: 0.0,
+            block_size: ETHEREUM_AVERAGE_BLOCK_SIZE,
+            validator_stakes: None,
+            progress_logger_seconds: 2,
+            network_stats: Rc::new(BitcoinGlobalNetworkStats86Countries::default()),
+        }
+    }
+
+    pub fn add_new_logger(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+
+    /// Sets the number of validator nodes. The default is 512.
+    pub fn set_num_of_nodes(&mut self, num_of_nodes: usize) {
+        self.num_of_nodes = num_of_nodes;
+    }
+
+    /// Sets the slot duration in seconds and the number of slots per epoch.
+    /// The defaults are 12 seconds and 32 slots.
+    pub fn set_slots(&mut self, slot_duration: f64, slots_per_epoch: u64) {
+        self.slot_duration = slot_duration;
+        self.slots_per_epoch = slots_per_epoch;
+    }
+
+    /// Sets the share of validators which are offline for the whole run. They
+    /// neither propose nor vote nor relay.
+    pub fn set_offline_validator_share(&mut self, offline_validator_share: f64) {
+        self.offline_validator_share = offline_validator_share;
+    }
+
+    /// Sets the size of every proposed block in bytes.
+    pub fn set_block_size(&mut self, block_size: u64) {
+        self.block_size = block_size;
+    }
+
+    /// Sets the stake of each validator node. By default stakes are sampled
+    /// from a log-normal distribution.
+    pub fn set_validator_stakes(&mut self, validator_stakes: Vec<f64>) {
+        self.validator_stakes = Some(validator_stakes);
+    }
+
+    /// Sets the dataset of regions, latencies and bandwidths. The default is
+    /// the 86 countries dataset with the Bitcoin node distribution.
+    pub fn set_network_stats(&mut self, network_stats: Rc<dyn NetworkStats>) {
+        self.network_stats = network_stats;
+    }
+
+    pub fn run(&mut self) -> Result<(), std::io::Error> {
+        const GENESIS_BLOCK_INDEX: usize = 0;
+
+        let preparation_starting_time = Instant::now();
+
+        let scenario_data = ScenarioData::new(
+            self.name.to_string(),
+            self.num_of_nodes,
+            "Gasper Proof-of-Stake".to_string(),
+        );
+
+        // create network, simulator, randomness_engine and prepare the network.
+        let mut config = GasperConsensusConfig::new(
+            self.slot_duration,
+            self.slots_per_epoch,
+            GENESIS_BLOCK_INDEX,
+        );
+        if let Some(validator_stakes) = &self.validator_stakes {
+            config.set_validator_stakes(validator_stakes.clone());
+        }
+        let average_num_of_blocks = (self.stop_time / self.slot_duration) as usize;
+        let mut network = EthereumNetwork::new_with_size(
+            self.num_of_nodes,
+            average_num_of_blocks,
+            config,
+            self.block_size,
+        );
+        network.resource.network_stats = Rc::clone(&self.network_stats);
+        let mut simulator = Simulator::new();
+        let mut rand = RandomnessEngine::new(self.seed);
+
+        network.prepare(
+            &mut rand,
+            self.num_of_neighbors,
+            self.offline_validator_share,
+        );
+        network.insert_initial_event(&mut simulator, &mut rand);
+
+        for logger in self.loggers.iter_mut() {
+            logger.initial_log(&scenario_data)?;
+        }
+
+        // running the simulation
+        eprintln!("Staring {}...", scenario_data.name);
+        let simulation_starting_time = Instant::now();
+        simulate_with_loggers(
+            &mut network,
+            &mut simulator,
+            &mut rand,
+            &mut self.loggers,
+            self.stop_time,
+            self.progress_logger_seconds,
+        )?;
+        for logger in self.loggers.iter_mut() {
+            logger.final_log(&scenario_data)?;
+        }
+        eprintln!("Finished {}.", self.name);
+
+        let simulation_ending_time = Instant::now();
+
+        println!(
+            "Total Created Blocks: {}",
+            network.resource.blocks.len() - 1
+        );
+        println!("Total Created Votes: {}", network.protocol.votes.len());
+
+        let setup_duration = simulation_starting_time
+            .duration_since(preparation_starting_time)
+            .as_millis();
+        let propagate_duration = simulation_ending_time
+            .duration_since(simulation_starting_time)
+            .as_millis();
+        println!("Total Executed Events: {}", simulator.inserted_events);
+        println!("Final Simulation Time: {}", simulator.simulation_time);
+        println!(
+            "Setup Elapsed time: {:.3}sec.",
+            (setup_duration as f64) / 1000.0
+        );
+        println!(
+            "Propagation Elapsed time: {:.3}sec.",
+            (propagate_duration as f64) / 1000.0
+        );
+        Ok(())
+    }
+}
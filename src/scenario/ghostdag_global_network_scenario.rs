@@ -0,0 +1,172 @@
+use crate::consensus::config::ghostdag_consensus_config::GhostDAGConsensusConfig;
+use crate::ledger_data::block_factory::GHOSTDAG_AVERAGE_BLOCK_SIZE;
+use crate::log::Logger;
+use crate::network::ghostdag_network::GhostDAGNetwork;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::BitcoinProfile;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
+use crate::scenario::{simulate_with_loggers, ScenarioData};
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// A Proof-of-Work block DAG running GHOSTDAG, with the miner geography and
+/// hash power distribution of the Bitcoin scenario.
+pub struct GhostDAGGlobalNetworkScenario {
+    average_block_mining_interval: f64,
+    k: usize,
+    confirmation_depth: u64,
+    loggers: Vec<Box<dyn Logger>>,
+    name: String,
+    seed: u64,
+    stop_time: f64,
+    num_of_miners: usize,
+    num_of_neighbors: usize,
+    num_of_nodes: usize,
+    block_size: u64,
+    progress_logger_seconds: u64,
+    network_stats: Rc<dyn NetworkStats>,
+    profile: BitcoinProfile,
+}
+
+impl GhostDAGGlobalNetworkScenario {
+    /// Creates the scenario with `k` as the anticone size bound of blue
+    /// blocks, and `confirmation_depth` in blue score.
+    pub fn new(
+        average_block_interval: f64,
+        k: usize,
+        confirmation_depth: u64,
+        name: &str,
+        seed: u64,
+        stop_time: f64,
+    ) -> Self {
+        let profile = BitcoinProfile::default();
+        Self {
+            average_block_mining_interval: average_block_interval,
+            k,
+            confirmation_depth,
+            loggers: Vec::new(),
+            name: name.to_string(),
+            seed,
+            stop_time,
+            num_of_miners: profile.num_of_miners, // 30
+            num_of_neighbors: 8,
+            num_of_nodes: 1000,
+            block_size: GHOSTDAG_AVERAGE_BLOCK_SIZE,
+            progress_logger_seconds: 2,
+            network_stats: Rc::new(BitcoinGlobalNetworkStats86Countries::from_profile(&profile)),
+            profile,
+        }
+    }
+
+    pub fn add_new_logger(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+
+    /// Sets the number of nodes, including the miners. The default is 1000.
+    pub fn set_num_of_nodes(&mut self, num_of_nodes: usize) {
+        self.num_of_nodes = num_of_nodes;
+    }
+
+    /// Sets the number of miners. The default is the number of miners of the
+    /// Bitcoin profile.
+    pub fn set_num_of_miners(&mut self, num_of_miners: usize) {
+        self.num_of_miners = num_of_miners;
+    }
+
+    /// Sets the size of the body of every block in bytes.
+    pub fn set_block_size(&mut self, block_size: u64) {
+        self.block_size = block_size;
+    }
+
+    /// Sets the dataset of regions, latencies and bandwidths. The default is
+    /// the 86 countries dataset with the Bitcoin node distribution.
+    pub fn set_network_stats(&mut self, network_stats: Rc<dyn NetworkStats>) {
+        self.network_stats = network_stats;
+    }
+
+    pub fn run(&mut self) -> Result<(), std::io::Error> {
+        const GENESIS_BLOCK_INDEX: usize = 0;
+
+        let preparation_starting_time = Instant::now();
+
+        let scenario_data = ScenarioData::new(
+            self.name.to_string(),
+            self.num_of_nodes,
+            format!("GHOSTDAG Block DAG (k = {})", self.k),
+        );
+
+        // create network, simulator, randomness_engine and prepare the network.
+        let config = GhostDAGConsensusConfig::new(
+            self.k,
+            self.confirmation_depth,
+            self.average_block_mining_interval,
+            GENESIS_BLOCK_INDEX,
+            self.profile.difficulty,
+        );
+        let average_num_of_blocks = (self.stop_time / self.average_block_mining_interval) as usize;
+        let mut network = GhostDAGNetwork::new_with_size(
+            self.num_of_nodes,
+            average_num_of_blocks,
+            config,
+            self.num_of_miners,
+            self.block_size,
+        );
+        network.resource.network_stats = Rc::clone(&self.network_stats);
+        network.protocol.profile = self.profile;
+        let mut simulator = Simulator::new();
+        let mut rand = RandomnessEngine::new(self.seed);
+
+        network.prepare(&mut rand, self.num_of_neighbors, self.num_of_miners);
+        network.insert_initial_event(&mut simulator, &mut rand);
+
+        for logger in self.loggers.iter_mut() {
+            logger.initial_log(&scenario_data)?;
+        }
+
+        // running the simulation
+        eprintln!("Staring {}...", scenario_data.name);
+        let simulation_starting_time = Instant::now();
+        simulate_with_loggers(
+            &mut network,
+            &mut simulator,
+            &mut rand,
+            &mut self.loggers,
+            self.stop_time,
+            self.progress_logger_seconds,
+        )?;
+        for logger in self.loggers.iter_mut() {
+            logger.final_log(&scenario_data)?;
+        }
+        eprintln!("Finished {}.", self.name);
+
+        let simulation_ending_time = Instant::now();
+
+        let blocks = &network.resource.blocks;
+        let total_parents: usize = blocks.iter().map(|block| block.parents.len()).sum();
+        println!("Total Created Blocks: {}", blocks.len() - 1);
+        println!(
+            "Average Parents per Block: {:.3}",
+            total_parents as f64 / (blocks.len() - 1).max(1) as f64
+        );
+
+        let setup_duration = simulation_starting_time
+            .duration_since(preparation_starting_time)
+            .as_millis();
+        let propagate_duration = simulation_ending_time
+            .duration_since(simulation_starting_time)
+            .as_millis();
+        println!("Total Executed Events: {}", simulator.inserted_events);
+        println!("Final Simulation Time: {}", simulator.simulation_time);
+        println!(
+            "Setup Elapsed time: {:.3}sec.",
+            (setup_duration as f64) / 1000.0
+        );
+        println!(
+            "Propagation Elapsed time: {:.3}sec.",
+            (propagate_duration as f64) / 1000.0
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use crate::consensus::algorithm::ChainBasedConsensus;
+use crate::consensus::config::praos_consensus_config::PraosConsensusConfig;
+use crate::consensus::config::ChainBasedConsensusConfig;
+use crate::ledger_data::block_factory::PRAOS_AVERAGE_BLOCK_SIZE;
+use crate::log::Logger;
+use crate::network::praos_network::PraosNetwork;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::BitcoinGlobalNetworkStats86Countries;
+use crate::network::stats::NetworkStats;
+use crate::scenario::{simulate_with_loggers, ScenarioData};
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// A longest-chain Proof-of-Stake (Ouroboros Praos) network where every node
+/// is a stake pool, placed with the same geography and bandwidth model as the
+/// Bitcoin scenario.
+pub struct PraosGlobalNetworkScenario {
+    loggers: Vec<Box<dyn Logger>>,
+    name: String,
+    seed: u64,
+    stop_time: f64,
+    slot_duration: f64,
+    active_slot_coefficient: f64,
+    settlement_depth: i32,
+    num_of_neighbors: usize,
+    num_of_nodes: usize,
+    block_size: u64,
+    stakes: Option<Vec<f64>>,
+    progress_logger_seconds: u64,
+    network_stats: Rc<dyn NetworkStats>,
+}
+
+impl PraosGlobalNetworkScenario {
+    pub fn new(name: &str, seed: u64, stop_time: f64) -> Self {
+        Self {
+            loggers: Vec::new(),
+            name: name.to_string(),
+            seed,
+            stop_time,
+            slot_duration: 1.0,
+            active_slot_coefficient: 0.05,
+            settlement_depth: 2160,
+            num_of_neighbors: 8,
+            num_of_nodes: 1000,
+            block_size: PRAOS_AVERAGE_BLOCK_SIZE,
+            stakes: None,
+            progress_logger_seconds: 2,
+            network_stats: Rc::new(BitcoinGlobalNetworkStats86Countries::default()),
+        }
+    }
+
+    pub fn add_new_logger(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+
+    /// Sets the number of stake pool nodes. The default is 1000.
+    pub fn set_num_of_nodes(&mut self, num_of_nodes: usize) {
+        self.num_of_nodes = num_of_nodes;
+    }
+
+    /// Sets the slot duration in seconds and the active slot coefficient,
+    /// the probability that a slot has at least one leader. The defaults are
+    /// 1 second and 0.05, as on Cardano.
+    pub fn set_slots(&mut self, slot_duration: f64, active_slot_coefficient: f64) {
+        self.slot_duration = slot_duration;
+        self.active_slot_coefficient = active_slot_coefficient;
+    }
+
+    /// Sets the settlement parameter `k`, in blocks. The default is 2160.
+    pub fn set_settlement_depth(&mut self, settlement_depth: i32) {
+        self.settlement_depth = settlement_depth;
+    }
+
+    /// Sets the size of every created block in bytes.
+    pub fn set_block_size(&mut self, block_size: u64) {
+        self.block_size = block_size;
+    }
+
+    /// Sets the stake of each node. By default stakes are sampled from a
+    /// log-normal distribution.
+    pub fn set_stakes(&mut self, stakes: Vec<f64>) {
+        self.stakes = Some(stakes);
+    }
+
+    /// Sets the dataset of regions, latencies and bandwidths. The default is
+    /// the 86 countries dataset with the Bitcoin node distribution.
+    pub fn set_network_stats(&mut self, network_stats: Rc<dyn NetworkStats>) {
+        self.network_stats = network_stats;
+    }
+
+    pub fn run(&mut self) -> Result<(), std::io::Error> {
+        const GENESIS_BLOCK_INDEX: usize = 0;
+
+        let preparation_starting_time = Instant::now();
+
+        let scenario_data = ScenarioData::new(
+            self.name.to_string(),
+            self.num_of_nodes,
+            "Ouroboros Praos Proof-of-Stake".to_string(),
+        );
+
+        // create network, simulator, randomness_engine and prepare the network.
+        let mut config = PraosConsensusConfig::new(
+            self.slot_duration,
+            self.active_slot_coefficient,
+            self.settlement_depth,
+            GENESIS_BLOCK_INDEX,
+        );
+        if let Some(stakes) = &self.stakes {
+            config.set_stakes(stakes.clone());
+        }
+        let average_num_of_blocks =
+            (self.stop_time / config.get_average_block_mining_interval()) as usize;
+        let mut network = PraosNetwork::new_with_size(
+            self.num_of_nodes,
+            average_num_of_blocks,
+            config,
+            self.block_size,
+        );
+        network.resource.network_stats = Rc::clone(&self.network_stats);
+        let mut simulator = Simulator::new();
+        let mut rand = RandomnessEngine::new(self.seed);
+
+        network.prepare(&mut rand, self.num_of_neighbors);
+        network.insert_initial_event(&mut simulator, &mut rand);
+
+        for logger in self.loggers.iter_mut() {
+            logger.initial_log(&scenario_data)?;
+        }
+
+        // running the simulation
+        eprintln!("Staring {}...", scenario_data.name);
+        let simulation_starting_time = Instant::now();
+        simulate_with_loggers(
+            &mut network,
+            &mut simulator,
+            &mut rand,
+            &mut self.loggers,
+            self.stop_time,
+            self.progress_logger_seconds,
+        )?;
+        for logger in self.loggers.iter_mut() {
+            logger.final_log(&scenario_data)?;
+        }
+        eprintln!("Finished {}.", self.name);
+
+        let simulation_ending_time = Instant::now();
+
+        println!(
+            "Total Created Blocks: {}",
+            network.resource.blocks.len() - 1
+        );
+        println!(
+            "Slots with Several Leaders: {}",
+            network
+                .protocol
+                .slot_blocks
+                .values()
+                .filter(|slot_blocks| slot_blocks.len() > 1)
+                .count()
+        );
+        let main_chain_len = network.ecs.consensus_algorithm[0].get_longest_chain_len();
+        println!(
+            "Blocks off the Main Chain: {}",
+            network.resource.blocks.len() - 1 - main_chain_len as usize
+        );
+
+        let setup_duration = simulation_starting_time
+            .duration_since(preparation_starting_time)
+            .as_millis();
+        let propagate_duration = simulation_ending_time
+            .duration_since(simulation_starting_time)
+            .as_millis();
+        println!("Total Executed Events: {}", simulator.inserted_events);
+        println!("Final Simulation Time: {}", simulator.simulation_time);
+        println!(
+            "Setup Elapsed time: {:.3}sec.",
+            (setup_duration as f64) / 1000.0
+        );
+        println!(
+            "Propagation Elapsed time: {:.3}sec.",
+            (propagate_duration as f64) / 1000.0
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::log::output_format::{push_json_field, push_json_string};
+use crate::log::record::Field;
+use std::fs;
+use std::path::Path;
+
+/// A machine-readable record of a run: the scenario with all its parameters
+/// and seed, the crate version, the wall-clock timings, the number of events
+/// and the summary metrics printed at the end, so result files can be traced
+/// back to their exact configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunManifest {
+    pub scenario: String,
+    pub name: String,
+    pub network_type: String,
+    pub seed: u64,
+    pub crate_version: String,
+    pub parameters: Vec<(String, Field)>,
+    pub setup_elapsed_seconds: f64,
+    pub propagation_elapsed_seconds: f64,
+    /// Events inserted in the event queue, executed or not.
+    pub inserted_events: i64,
+    pub final_simulation_time: f64,
+    pub summary: Vec<(String, Field)>,
+}
+
+impl RunManifest {
+    pub fn new(scenario: &str, name: &str, network_type: &str, seed: u64) -> Self {
+        Self {
+            scenario: scenario.to_string(),
+            name: name.to_string(),
+            network_type: network_type.to_string(),
+            seed,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            parameters: Vec::new(),
+            setup_elapsed_seconds: 0.0,
+            propagation_elapsed_seconds: 0.0,
+            inserted_events: 0,
+            final_simulation_time: 0.0,
+            summary: Vec::new(),
+        }
+    }
+
+    pub fn add_parameter(&mut self, name: &str, value: impl Into<Field>) {
+        self.parameters.push((name.to_string(), value.into()));
+    }
+
+    pub fn add_summary_metric(&mut self, name: &str, value: impl Into<Field>) {
+        self.summary.push((name.to_string(), value.into()));
+    }
+
+    pub fn to_json(&self) -> String {
+        let fields: Vec<(&str, Field)> = vec![
+            ("scenario", self.scenario.as_str().into()),
+            ("name", self.name.as_str().into()),
+            ("network_type", self.network_type.as_str().into()),
+            ("seed", self.seed.into()),
+            ("crate_version", self.crate_version.as_str().into()),
+        ];
+        let timings: Vec<(&str, Field)> = vec![
+            ("setup_elapsed_seconds", self.setup_elapsed_seconds.into()),
+            (
+                "propagation_elapsed_seconds",
+                self.propagation_elapsed_seconds.into(),
+            ),
+            ("inserted_events", self.inserted_events.into()),
+            ("final_simulation_time", self.final_simulation_time.into()),
+        ];
+
+        let mut json = String::from("{\n");
+        for (name, value) in fields.iter().chain(timings.iter()) {
+            json.push_str("  ");
+            push_json_string(&mut json, name);
+            json.push_str(": ");
+            push_json_field(&mut json, value);
+            json.push_str(",\n");
+        }
+        push_json_object(&mut json, "parameters", &self.parameters);
+        json.push_str(",\n");
+        push_json_object(&mut json, "summary", &self.summary);
+        json.push_str("\n}\n");
+        json
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
+
+fn push_json_object(json: &mut String, name: &str, fields: &[(String, Field)]) {
+    json.push_str("  ");
+    push_json_string(json, name);
+    json.push_str(": {");
+    for (i, (field_name, value)) in fields.iter().enumerate() {
+        json.push_str(if i > 0 { ",\n    " } else { "\n    " });
+        push_json_string(json, field_name);
+        json.push_str(": ");
+        push_json_field(json, value);
+    }
+    json.push_str(if fields.is_empty() { "}" } else { "\n  }" });
+}
@@ -0,0 +1,213 @@
+use crate::log::in_memory_sink::RecordTable;
+use crate::log::record::{Field, Record};
+
+/// The prefix of the columns of a [`BlockArrivalLogger`] with the propagation
+/// delay at a share of the nodes, followed by the share.
+///
+/// [`BlockArrivalLogger`]: crate::log::block_arrival_logger::BlockArrivalLogger
+pub const PROPAGATION_DELAY_COLUMN_PREFIX: &str = "PropagationDelay";
+
+/// The propagation of a block: its delay after creation when each share of
+/// the nodes had received it, or `None` if the share was never reached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockPropagation {
+    pub block_index: usize,
+    pub height: i32,
+    pub creator: Option<usize>,
+    pub size: u64,
+    pub creation_time: f64,
+    /// The share of the nodes and the propagation delay at this share.
+    pub propagation_delays: Vec<(f64, Option<f64>)>,
+}
+
+impl BlockPropagation {
+    /// Returns the propagation delay when the share of the nodes had received
+    /// the block, if it is one of the recorded shares and was reached.
+    pub fn delay_at_share(&self, share: f64) -> Option<f64> {
+        self.propagation_delays
+            .iter()
+            .find(|(recorded_share, _)| *recorded_share == share)
+            .and_then(|(_, delay)| *delay)
+    }
+
+    /// Returns the record of a [`BlockArrivalLogger`] for the propagation.
+    ///
+    /// [`BlockArrivalLogger`]: crate::log::block_arrival_logger::BlockArrivalLogger
+    pub fn to_record(&self) -> Record {
+        let mut record: Record = vec![
+            self.block_index.into(),
+            self.height.into(),
+            self.creator.into(),
+            self.size.into(),
+            self.creation_time.into(),
+        ];
+        record.extend(
+            self.propagation_delays
+                .iter()
+                .map(|(_, delay)| (*delay).into()),
+        );
+        record
+    }
+
+    /// Reads the propagations from the first table of a [`BlockArrivalLogger`],
+    /// by the names of its columns. The shares are read from the names of the
+    /// propagation delay columns.
+    ///
+    /// [`BlockArrivalLogger`]: crate::log::block_arrival_logger::BlockArrivalLogger
+    pub fn from_table(table: &RecordTable) -> Result<Vec<Self>, String> {
+        let block_index = column_index(table, "BlockIndex")?;
+        let height = column_index(table, "BlockHeight")?;
+        let creator = column_index(table, "BlockCreator")?;
+        let size = column_index(table, "BlockSize")?;
+        let creation_time = column_index(table, "CreationTime")?;
+        let mut delay_columns = Vec::new();
+        for (i, column) in table.header.iter().enumerate() {
+            if let Some(share) = column.strip_prefix(PROPAGATION_DELAY_COLUMN_PREFIX) {
+                let share = share
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid share in column: {}", column))?;
+                delay_columns.push((share, i));
+            }
+        }
+
+        table
+            .records
+            .iter()
+            .enumerate()
+            .map(|(row, record)| {
+                let field = |column: usize| field_at(table, record, row, column);
+                Ok(Self {
+                    block_index: parse(field(block_index)?, Field::as_u64)? as usize,
+                    height: parse(field(height)?, Field::as_i64)? as i32,
+                    creator: parse_optional(field(creator)?, Field::as_u64)?
+                        .map(|creator| creator as usize),
+                    size: parse(field(size)?, Field::as_u64)?,
+                    creation_time: parse(field(creation_time)?, Field::as_f64)?,
+                    propagation_delays: delay_columns
+                        .iter()
+                        .map(|&(share, i)| Ok((share, parse_optional(field(i)?, Field::as_f64)?)))
+                        .collect::<Result<_, String>>()?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A change of the canonical chain of a node to a chain not extending its
+/// previous head, after receiving a block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReorgEvent {
+    pub time: f64,
+    pub node_index: usize,
+    pub block_height: i32,
+    pub block_creation_time: f64,
+    pub block_creator: Option<usize>,
+    /// The number of blocks of the new chain after the common ancestor.
+    pub reorg_length: i32,
+}
+
+impl ReorgEvent {
+    /// Returns the record of a [`BlockchainReorgLogger`] for the reorg.
+    ///
+    /// [`BlockchainReorgLogger`]: crate::log::blockchain_reorg_logger::BlockchainReorgLogger
+    pub fn to_record(&self) -> Record {
+        vec![
+            self.time.into(),
+            self.node_index.into(),
+            self.block_height.into(),
+            self.block_creation_time.into(),
+            self.block_creator.into(),
+            self.reorg_length.into(),
+        ]
+    }
+
+    /// Reads the reorgs from the first table of a [`BlockchainReorgLogger`],
+    /// by the names of its columns.
+    ///
+    /// [`BlockchainReorgLogger`]: crate::log::blockchain_reorg_logger::BlockchainReorgLogger
+    pub fn from_table(table: &RecordTable) -> Result<Vec<Self>, String> {
+        let time = column_index(table, "Time")?;
+        let node_index = column_index(table, "NodeIndex")?;
+        let block_height = column_index(table, "BlockHeight")?;
+        let block_creation_time = column_index(table, "BlockCreationTime")?;
+        let block_creator = column_index(table, "BlockCreator")?;
+        let reorg_length = column_index(table, "ReorgLength")?;
+
+        table
+            .records
+            .iter()
+            .enumerate()
+            .map(|(row, record)| {
+                let field = |column: usize| field_at(table, record, row, column);
+                Ok(Self {
+                    time: parse(field(time)?, Field::as_f64)?,
+                    node_index: parse(field(node_index)?, Field::as_u64)? as usize,
+                    block_height: parse(field(block_height)?, Field::as_i64)? as i32,
+                    block_creation_time: parse(field(block_creation_time)?, Field::as_f64)?,
+                    block_creator: parse_optional(field(block_creator)?, Field::as_u64)?
+                        .map(|creator| creator as usize),
+                    reorg_length: parse(field(reorg_length)?, Field::as_i64)? as i32,
+                })
+            })
+            .collect()
+    }
+}
+
+fn column_index(table: &RecordTable, name: &str) -> Result<usize, String> {
+    table
+        .header
+        .iter()
+        .position(|column| column == name)
+        .ok_or_else(|| format!("Missing column: {}", name))
+}
+
+/// Returns the field of the record in the column, with the column and the row
+/// of the record for errors.
+fn field_at<'a>(
+    table: &'a RecordTable,
+    record: &'a Record,
+    row: usize,
+    column: usize,
+) -> Result<(&'a str, usize, &'a Field), String> {
+    let name = table.header[column].as_str();
+    record
+        .get(column)
+        .map(|field| (name, row, field))
+        .ok_or_else(|| format!("Missing {} in row {}", name, row))
+}
+
+fn parse<T>(
+    (name, row, field): (&str, usize, &Field),
+    as_type: impl Fn(&Field) -> Option<T>,
+) -> Result<T, String> {
+    as_type(field).ok_or_else(|| format!("Invalid {} in row {}: {}", name, row, field))
+}
+
+/// Parses a field which may be missing, i.e. [`Field::Null`].
+fn parse_optional<T>(
+    (name, row, field): (&str, usize, &Field),
+    as_type: impl Fn(&Field) -> Option<T>,
+) -> Result<Option<T>, String> {
+    match field {
+        Field::Null => Ok(None),
+        _ => parse((name, row, field), as_type).map(Some),
+    }
+}
+
+/// The outcome of the run of a scenario, for reading results without files,
+/// e.g. in tests or notebooks.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SimulationResult {
+    pub name: String,
+    pub seed: u64,
+    pub num_of_created_blocks: usize,
+    /// Events inserted in the event queue, executed or not.
+    pub inserted_events: i64,
+    pub final_simulation_time: f64,
+    pub setup_elapsed_seconds: f64,
+    pub propagation_elapsed_seconds: f64,
+    /// The propagation of every block received by a node, in block order.
+    pub block_propagations: Vec<BlockPropagation>,
+    /// The reorgs of all the nodes, in time order.
+    pub reorgs: Vec<ReorgEvent>,
+}
@@ -1,10 +1,18 @@
 //! Event trait including `execute()` method.
 
+pub mod attestation_event;
+pub mod bft_timeout_event;
 pub mod block_confirmation_event;
+pub mod block_generation_event;
 pub mod block_mining_process;
+pub mod block_validation_event;
 pub mod generate_block_event;
+pub mod generate_microblock_event;
 pub mod receive_event;
+pub mod sampling_event;
 pub mod send_event;
+pub mod slot_event;
+pub mod stratum_work_event;
 
 use crate::log::EventLoggerInfo;
 use crate::network::Network;
@@ -0,0 +1,29 @@
+//! Attestation deadline of a Proof-of-Stake slot, in which the committee of the
+//! slot votes for its head.
+
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug, Clone)]
+pub struct AttestationEvent {
+    pub slot: u64,
+}
+
+impl Event for AttestationEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+}
+
+impl AttestationEvent {
+    pub fn new(slot: u64) -> Self {
+        Self { slot }
+    }
+}
@@ -0,0 +1,37 @@
+//! Expiry of a timer of a BFT replica, e.g. the view change timeout.
+
+use crate::ledger_data::vote::BFTVoteType;
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug, Clone)]
+pub struct BFTTimeoutEvent {
+    pub node: usize,
+    pub view: u64,
+    pub height: u64,
+    pub step: BFTVoteType,
+}
+
+impl Event for BFTTimeoutEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+}
+
+impl BFTTimeoutEvent {
+    pub fn new(node: usize, view: u64, height: u64, step: BFTVoteType) -> Self {
+        Self {
+            node,
+            view,
+            height,
+            step,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::log::EventLoggerInfo;
+use crate::simulator::event::Event;
+
+/// Put when a node creates a new block, for the loggers.
+#[derive(Debug)]
+pub struct BlockGenerationEvent {
+    block_index: usize,
+    node_index: usize,
+}
+
+impl BlockGenerationEvent {
+    pub fn new(block_index: usize, node_index: usize) -> Self {
+        Self {
+            block_index,
+            node_index,
+        }
+    }
+}
+
+impl Event for BlockGenerationEvent {
+    fn logger_data(&self, time: f64) -> EventLoggerInfo {
+        EventLoggerInfo::IsBlockGenerationEvent(self.block_index, self.node_index, time)
+    }
+}
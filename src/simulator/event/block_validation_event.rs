@@ -0,0 +1,43 @@
+//! End of the validation of a received block by a node.
+
+use crate::log::EventLoggerInfo;
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug)]
+pub struct BlockValidationEvent {
+    pub block_index: usize,
+    pub from: usize,
+    pub node: usize,
+    /// Whether the block should be relayed after validation. It is `false` if
+    /// it was already relayed before validation.
+    pub relay: bool,
+}
+
+impl Event for BlockValidationEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+
+    fn logger_data(&self, time: f64) -> EventLoggerInfo {
+        EventLoggerInfo::IsBlockValidationEvent(self.block_index, self.node, time)
+    }
+}
+
+impl BlockValidationEvent {
+    pub fn new(block_index: usize, from: usize, node: usize, relay: bool) -> Self {
+        Self {
+            block_index,
+            from,
+            node,
+            relay,
+        }
+    }
+}
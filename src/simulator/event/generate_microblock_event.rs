@@ -0,0 +1,30 @@
+//! Generation event of a Bitcoin-NG microblock by the leader of a key block.
+
+use super::Event;
+use crate::network::Network;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug)]
+pub struct GenerateMicroblockEvent {
+    pub leader: usize,
+    /// The key block which made the node the leader.
+    pub key_block: usize,
+}
+
+impl Event for GenerateMicroblockEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+}
+
+impl GenerateMicroblockEvent {
+    pub(crate) fn new(leader: usize, key_block: usize) -> Self {
+        Self { leader, key_block }
+    }
+}
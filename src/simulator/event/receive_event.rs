@@ -12,6 +12,9 @@ pub struct ReceiveEvent {
     pub from: usize,
     pub node: usize,
     pub msg_type: MessageType,
+    /// Whether the message is delivered over the relay overlay instead of the
+    /// P2P links.
+    pub relay_overlay: bool,
 }
 
 impl Event for ReceiveEvent {
@@ -36,6 +39,19 @@ impl ReceiveEvent {
             from,
             node,
             msg_type,
+            relay_overlay: false,
+        }
+    }
+
+    pub(crate) fn new_from_relay_overlay(
+        block_index: usize,
+        from: usize,
+        node: usize,
+        msg_type: MessageType,
+    ) -> Self {
+        Self {
+            relay_overlay: true,
+            ..Self::new(block_index, from, node, msg_type)
         }
     }
 }
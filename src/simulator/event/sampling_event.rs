@@ -0,0 +1,40 @@
+use crate::log::EventLoggerInfo;
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+/// Put every `interval` seconds for the loggers which sample the state of the
+/// network periodically. Each execution puts the next one.
+#[derive(Debug)]
+pub struct SamplingEvent {
+    interval: f64,
+    /// The time the event is put at. It is given to the loggers instead of the
+    /// time of the previous event, so samples are exactly `interval` apart.
+    sampling_time: f64,
+}
+
+impl SamplingEvent {
+    /// Returns the first sampling event, to be put `interval` seconds after the
+    /// start of the simulation.
+    pub fn new(interval: f64) -> Self {
+        Self {
+            interval,
+            sampling_time: interval,
+        }
+    }
+}
+
+impl Event for SamplingEvent {
+    fn execute(&self, _: &mut dyn Network, simulator: &mut Simulator, _: &mut RandomnessEngine) {
+        let next_sampling_event = Self {
+            interval: self.interval,
+            sampling_time: self.sampling_time + self.interval,
+        };
+        simulator.put_event(Box::new(next_sampling_event), self.interval);
+    }
+
+    fn logger_data(&self, _: f64) -> EventLoggerInfo {
+        EventLoggerInfo::IsSamplingEvent(self.interval, self.sampling_time)
+    }
+}
@@ -0,0 +1,34 @@
+//! Start of a Proof-of-Stake slot, in which the proposer creates a new block.
+
+use crate::log::EventLoggerInfo;
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug, Clone)]
+pub struct SlotEvent {
+    pub slot: u64,
+    pub proposer: usize,
+}
+
+impl Event for SlotEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+
+    fn logger_data(&self, time: f64) -> EventLoggerInfo {
+        EventLoggerInfo::IsSlotEvent(self.slot, self.proposer, time)
+    }
+}
+
+impl SlotEvent {
+    pub fn new(slot: u64, proposer: usize) -> Self {
+        Self { slot, proposer }
+    }
+}
@@ -0,0 +1,39 @@
+//! Delivery of new work from a pool operator to one of its hashers.
+
+use crate::log::EventLoggerInfo;
+use crate::network::Network;
+use crate::simulator::event::Event;
+use crate::simulator::randomness_engine::RandomnessEngine;
+use crate::simulator::Simulator;
+
+#[derive(Debug)]
+pub struct StratumWorkEvent {
+    pub pool: usize,
+    pub hasher: usize,
+    pub block_index: usize,
+}
+
+impl Event for StratumWorkEvent {
+    fn execute(
+        &self,
+        network: &mut dyn Network,
+        simulator: &mut Simulator,
+        rand: &mut RandomnessEngine,
+    ) {
+        network.protocol_event(simulator, rand, self);
+    }
+
+    fn logger_data(&self, time: f64) -> EventLoggerInfo {
+        EventLoggerInfo::IsStratumWorkEvent(self.pool, self.hasher, self.block_index, time)
+    }
+}
+
+impl StratumWorkEvent {
+    pub fn new(pool: usize, hasher: usize, block_index: usize) -> Self {
+        Self {
+            pool,
+            hasher,
+            block_index,
+        }
+    }
+}
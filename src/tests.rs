@@ -1,2 +1,40 @@
 // #[cfg(test)]
 // mod simulator_engine_test;
+#[cfg(test)]
+mod bandwidth_logger_test;
+#[cfg(test)]
+mod bft_test_replicas;
+#[cfg(test)]
+mod bitcoin_ng_test;
+#[cfg(test)]
+mod bitcoin_profile_test;
+#[cfg(test)]
+mod chain_growth_logger_test;
+#[cfg(test)]
+mod confirmation_latency_logger_test;
+#[cfg(test)]
+mod csv_network_stats_test;
+#[cfg(test)]
+mod gasper_consensus_test;
+#[cfg(test)]
+mod ghostdag_consensus_test;
+#[cfg(test)]
+mod hotstuff_consensus_test;
+#[cfg(test)]
+mod link_test;
+#[cfg(test)]
+mod miner_fairness_logger_test;
+#[cfg(test)]
+mod node_type_filter_test;
+#[cfg(test)]
+mod output_format_test;
+#[cfg(test)]
+mod pbft_consensus_test;
+#[cfg(test)]
+mod praos_consensus_test;
+#[cfg(test)]
+mod run_manifest_test;
+#[cfg(test)]
+mod simulation_result_test;
+#[cfg(test)]
+mod tendermint_consensus_test;
@@ -0,0 +1,97 @@
+use crate::consensus::algorithm::pbft_consensus::PBFTConsensus;
+use crate::consensus::config::pbft_consensus_config::PBFTConsensusConfig;
+use crate::ledger_data::block_factory::{
+    BFT_VOTE_SIZE, BITCOIN_INV_SIZE, GET_DATA_OVERHEAD, INV_MESSAGE_OVERHEAD,
+};
+use crate::log::bandwidth_logger::BandwidthLogger;
+use crate::log::in_memory_sink::RecordTable;
+use crate::log::record::Field;
+use crate::log::EventLogger;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::{
+    BitcoinProfile, BITCOIN_2022,
+};
+use crate::scenario::bft_global_network_scenario::BFTGlobalNetworkScenario;
+use crate::scenario::bitcoin_global_network_scenario::BitcoinGlobalNetworkScenario;
+
+/// Returns the values of a column of the records per node.
+fn column(table: &RecordTable, name: &str) -> Vec<u64> {
+    let index = table
+        .header
+        .iter()
+        .position(|column| column == name)
+        .unwrap();
+    table
+        .records
+        .iter()
+        .map(|record| match record[index] {
+            Field::UInt(value) => value,
+            _ => panic!("{name} is not a count"),
+        })
+        .collect()
+}
+
+fn sum(table: &RecordTable, name: &str) -> u64 {
+    column(table, name).iter().sum()
+}
+
+#[test]
+fn bandwidth_logger_counts_the_bytes_of_the_bitcoin_messages() {
+    let mut scenario = BitcoinGlobalNetworkScenario::new(600.0, 6, "small", 1, 3600.0);
+    scenario.set_profile(BitcoinProfile {
+        num_of_nodes: 100,
+        ..BITCOIN_2022
+    });
+    let (logger, records) = EventLogger::in_memory(BandwidthLogger::new());
+    scenario.add_new_logger(Box::new(logger));
+
+    scenario.run().unwrap();
+
+    let table = &records.tables()[0];
+    assert!(!table.records.is_empty());
+    for (kind, size) in [
+        ("Inv", BITCOIN_INV_SIZE + INV_MESSAGE_OVERHEAD),
+        ("GetData", BITCOIN_INV_SIZE + GET_DATA_OVERHEAD),
+    ] {
+        let messages = sum(table, &format!("{kind}ReceivedMessages"));
+        assert!(messages > 0);
+        assert_eq!(sum(table, &format!("{kind}ReceivedBytes")), size * messages);
+    }
+    for kind in ["Inv", "GetData", "Block"] {
+        assert_eq!(
+            sum(table, &format!("{kind}SentBytes")),
+            sum(table, &format!("{kind}ReceivedBytes"))
+        );
+    }
+    assert!(sum(table, "BlockReceivedBytes") > 0);
+    assert_eq!(sum(table, "VoteReceivedBytes"), 0);
+}
+
+#[test]
+fn bandwidth_logger_counts_the_bft_votes_and_the_blocks_of_the_proposals() {
+    let block_size = 10_000;
+    let mut scenario = BFTGlobalNetworkScenario::<PBFTConsensus>::new(
+        "pbft",
+        1,
+        30.0,
+        PBFTConsensusConfig::new(4, 10.0, 0),
+    );
+    scenario.set_block_size(block_size);
+    let (logger, records) = EventLogger::in_memory(BandwidthLogger::new());
+    scenario.add_new_logger(Box::new(logger));
+
+    scenario.run().unwrap();
+
+    let table = &records.tables()[0];
+    let bytes = column(table, "VoteReceivedBytes");
+    let messages = column(table, "VoteReceivedMessages");
+    let mut num_of_proposals = 0;
+    for (bytes, messages) in bytes.iter().zip(messages) {
+        // each proposal carries a block on top of its vote.
+        let block_bytes = bytes - BFT_VOTE_SIZE * messages;
+        assert_eq!(block_bytes % block_size, 0);
+        num_of_proposals += block_bytes / block_size;
+    }
+    assert!(num_of_proposals > 0);
+    assert_eq!(sum(table, "VoteSentBytes"), sum(table, "VoteReceivedBytes"));
+    assert_eq!(sum(table, "BlockReceivedBytes"), 0);
+}
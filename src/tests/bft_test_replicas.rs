@@ -0,0 +1,233 @@
+use crate::consensus::algorithm::{BFTAction, BFTConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::BFTConsensusConfig;
+use crate::ledger_data::bft_block::BFTBlock;
+use crate::ledger_data::vote::{BFTVote, BFTVoteType};
+use crate::network::bft_network::{equivocation_recipients, Equivocations};
+use crate::simulator::Simulator;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Replicas of a BFT consensus exchanging their votes without any network
+/// delay, so that tests can choose which votes are delivered and when the
+/// timeouts expire.
+pub struct TestReplicas<C: BFTConsensus<B = BFTBlock, L = LocalBlockTree>> {
+    pub replicas: Vec<C>,
+    pub local_block_trees: Vec<LocalBlockTree>,
+    pub blocks: Vec<BFTBlock>,
+    pub config: C::G,
+    pub is_crashed: Vec<bool>,
+    /// Byzantine replicas behave as in the BFT network.
+    pub is_byzantine: Vec<bool>,
+    equivocations: Equivocations,
+    /// All the votes sent, in order.
+    pub sent_votes: Vec<BFTVote>,
+    simulator: Simulator,
+    /// The votes in flight: <(recipient, vote)>
+    messages: VecDeque<(usize, BFTVote)>,
+    /// The votes held back by [`Self::deliver`].
+    held_messages: Vec<(usize, BFTVote)>,
+    /// The scheduled timeouts: <(replica, view, height, step)>
+    timeouts: Vec<(usize, u64, u64, BFTVoteType)>,
+}
+
+impl<C: BFTConsensus<B = BFTBlock, L = LocalBlockTree>> TestReplicas<C>
+where
+    C::G: BFTConsensusConfig,
+{
+    /// Starts `config.get_num_of_replicas()` replicas, except the crashed ones.
+    pub fn start(config: C::G, crashed: &[usize]) -> Self {
+        Self::start_with_byzantine(config, crashed, &[])
+    }
+
+    /// Starts the replicas, some of them Byzantine.
+    pub fn start_with_byzantine(config: C::G, crashed: &[usize], byzantine: &[usize]) -> Self {
+        let num_of_replicas = config.get_num_of_replicas();
+        let replicas = (0..num_of_replicas)
+            .map(|node_index| {
+                let mut consensus = C::new(&config);
+                consensus.initial_configuration(&config, node_index);
+                consensus
+            })
+            .collect();
+        let mut test_replicas = Self {
+            replicas,
+            local_block_trees: vec![LocalBlockTree::new(); num_of_replicas],
+            blocks: vec![BFTBlock::generate_genesis_block()],
+            config,
+            is_crashed: (0..num_of_replicas)
+                .map(|node| crashed.contains(&node))
+                .collect(),
+            is_byzantine: (0..num_of_replicas)
+                .map(|node| byzantine.contains(&node))
+                .collect(),
+            equivocations: Equivocations::default(),
+            sent_votes: Vec::new(),
+            simulator: Simulator::new(),
+            messages: VecDeque::new(),
+            held_messages: Vec::new(),
+            timeouts: Vec::new(),
+        };
+        for node in 0..num_of_replicas {
+            if !test_replicas.is_crashed[node] {
+                let actions = test_replicas.replicas[node].start(&test_replicas.config);
+                test_replicas.apply_actions(node, actions);
+            }
+        }
+        test_replicas
+    }
+
+    /// Delivers the votes in flight, and the ones they cause, until none is
+    /// left. The votes for which `deliver_now(recipient, vote)` is false are
+    /// held back until [`Self::release_held_messages`].
+    pub fn deliver(&mut self, deliver_now: impl Fn(usize, &BFTVote) -> bool) {
+        while let Some((node, vote)) = self.messages.pop_front() {
+            if self.is_crashed[node] {
+                continue;
+            }
+            if !deliver_now(node, &vote) {
+                self.held_messages.push((node, vote));
+                continue;
+            }
+            if let (BFTVoteType::Proposal, Some(block_index)) = (vote.vote_type, vote.block_index) {
+                self.local_block_trees[node].add(block_index, &self.blocks);
+            }
+            let actions = self.replicas[node].new_incoming_vote(
+                &vote,
+                &self.blocks,
+                &self.config,
+                &self.local_block_trees[node],
+                &mut self.simulator,
+            );
+            self.apply_actions(node, actions);
+        }
+    }
+
+    /// Puts the votes held back in flight again, in the order they were sent.
+    pub fn release_held_messages(&mut self) {
+        self.messages.extend(self.held_messages.drain(..));
+    }
+
+    /// Drops the votes held back, as if they were lost.
+    pub fn drop_held_messages(&mut self) {
+        self.held_messages.clear();
+    }
+
+    /// Expires all the timeouts scheduled so far.
+    pub fn expire_timeouts(&mut self) {
+        for (node, view, height, step) in std::mem::take(&mut self.timeouts) {
+            if self.is_crashed[node] {
+                continue;
+            }
+            let actions = self.replicas[node].timeout(
+                view,
+                height,
+                step,
+                &self.blocks,
+                &self.config,
+                &self.local_block_trees[node],
+                &mut self.simulator,
+            );
+            self.apply_actions(node, actions);
+        }
+    }
+
+    /// Returns the blocks committed by the replica, with their height.
+    pub fn committed_blocks(&mut self, node: usize) -> HashSet<(i32, usize)> {
+        self.replicas[node]
+            .get_mut_confirmed_blocks()
+            .iter()
+            .map(|&block_index| (self.blocks[block_index].height, block_index))
+            .collect()
+    }
+
+    /// Returns whether no two correct replicas committed different blocks of
+    /// the same height.
+    pub fn correct_replicas_agree(&mut self) -> bool {
+        let mut committed_at_height: HashMap<i32, usize> = HashMap::new();
+        for node in 0..self.replicas.len() {
+            if self.is_crashed[node] || self.is_byzantine[node] {
+                continue;
+            }
+            for (height, block) in self.committed_blocks(node) {
+                if *committed_at_height.entry(height).or_insert(block) != block {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the number of blocks created, besides genesis.
+    pub fn num_of_created_blocks(&self) -> usize {
+        self.blocks.len() - 1
+    }
+
+    fn apply_actions(&mut self, node: usize, actions: Vec<BFTAction>) {
+        let num_of_replicas = self.replicas.len();
+        let is_byzantine = self.is_byzantine[node];
+        for action in actions {
+            match action {
+                BFTAction::Propose { vote, parent } if is_byzantine => {
+                    let blocks: Vec<usize> =
+                        (0..2).map(|_| self.create_block(&vote, parent)).collect();
+                    self.equivocations.add(&vote, blocks.clone());
+                    let recipients = equivocation_recipients(&self.is_byzantine);
+                    for (block_index, recipients) in blocks.into_iter().zip(recipients) {
+                        let vote = BFTVote {
+                            block_index: Some(block_index),
+                            ..vote
+                        };
+                        self.sent_votes.push(vote);
+                        self.messages
+                            .extend(recipients.into_iter().map(|to| (to, vote)));
+                    }
+                }
+                BFTAction::Propose { vote, parent } => {
+                    let vote = BFTVote {
+                        block_index: Some(self.create_block(&vote, parent)),
+                        ..vote
+                    };
+                    self.sent_votes.push(vote);
+                    self.messages
+                        .extend((0..num_of_replicas).map(|to| (to, vote)));
+                }
+                BFTAction::Broadcast(vote) => {
+                    for vote in self.votes_of(node, vote) {
+                        self.sent_votes.push(vote);
+                        self.messages
+                            .extend((0..num_of_replicas).map(|to| (to, vote)));
+                    }
+                }
+                BFTAction::SendTo { vote, to } => {
+                    for vote in self.votes_of(node, vote) {
+                        self.sent_votes.push(vote);
+                        self.messages.push_back((to, vote));
+                    }
+                }
+                BFTAction::ScheduleTimeout {
+                    view, height, step, ..
+                } => self.timeouts.push((node, view, height, step)),
+            }
+        }
+    }
+
+    fn votes_of(&self, node: usize, vote: BFTVote) -> Vec<BFTVote> {
+        if self.is_byzantine[node] {
+            self.equivocations.conflicting_votes(vote)
+        } else {
+            vec![vote]
+        }
+    }
+
+    fn create_block(&mut self, vote: &BFTVote, parent: usize) -> usize {
+        self.blocks.push(BFTBlock::new_with_parent(
+            0.0,
+            Some(vote.voter),
+            self.blocks[parent].height + 1,
+            parent,
+            1000,
+            vote.view,
+        ));
+        self.blocks.len() - 1
+    }
+}
@@ -0,0 +1,159 @@
+use crate::consensus::algorithm::nakamoto_consensus::NakamotoConsensus;
+use crate::consensus::algorithm::{ChainBasedConsensus, DAGBasedConsensus};
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::bitcoin_ng_config::BitcoinNGConfig;
+use crate::consensus::config::nakamoto_consensus_config::NakamotoConsensusConfig;
+use crate::ledger_data::bitcoin_block::BitcoinBlock;
+use crate::ledger_data::block_factory::BITCOIN_NG_MICROBLOCK_HEADER_SIZE;
+use crate::network::bitcoin_network::BitcoinNetwork;
+use crate::simulator::event::generate_microblock_event::GenerateMicroblockEvent;
+use crate::simulator::Simulator;
+use std::collections::HashMap;
+
+/// The fees of a microblock: its transaction bytes.
+const FEE: f64 = 1000.0;
+
+/// A Bitcoin-NG network of 4 nodes with a 40% leader fee share, whose blocks
+/// are added by hand.
+fn network() -> BitcoinNetwork {
+    let mut network = BitcoinNetwork::new_with_size(4, 0, 600.0, 6, 0, 1.0, 4);
+    network.protocol.bitcoin_ng_config = Some(BitcoinNGConfig::new(10.0, FEE as u64));
+    network
+        .resource
+        .blocks
+        .push(BitcoinBlock::generate_genesis_block());
+    network
+}
+
+fn add_key_block(blocks: &mut Vec<BitcoinBlock>, parent: usize, miner: usize) -> usize {
+    let mut key_block = BitcoinBlock::new_with_parents(
+        0.0,
+        Some(miner),
+        blocks[parent].height + 1,
+        vec![parent],
+        80,
+        1.0,
+        1.0,
+    );
+    key_block.key_height = blocks[parent].key_height + 1;
+    blocks.push(key_block);
+    blocks.len() - 1
+}
+
+fn add_microblocks(
+    blocks: &mut Vec<BitcoinBlock>,
+    parent: usize,
+    num_of_microblocks: usize,
+) -> usize {
+    let mut parent = parent;
+    for _ in 0..num_of_microblocks {
+        let leader = blocks[parent].creator.unwrap();
+        let microblock = BitcoinBlock::new_microblock(
+            0.0,
+            leader,
+            parent,
+            &blocks[parent],
+            BITCOIN_NG_MICROBLOCK_HEADER_SIZE + FEE as u64,
+        );
+        blocks.push(microblock);
+        parent = blocks.len() - 1;
+    }
+    parent
+}
+
+#[test]
+fn bitcoin_ng_splits_the_fees_between_the_leader_and_the_next_one() {
+    assert_eq!(BitcoinNGConfig::default().split_fee(FEE), (400.0, 600.0));
+
+    // 2 microblocks of leader 0, 1 of leader 1, then the key block of 2.
+    let mut network = network();
+    let blocks = &mut network.resource.blocks;
+    let key_block_1 = add_key_block(blocks, 0, 0);
+    let epoch_1 = add_microblocks(blocks, key_block_1, 2);
+    let key_block_2 = add_key_block(blocks, epoch_1, 1);
+    let epoch_2 = add_microblocks(blocks, key_block_2, 1);
+    let key_block_3 = add_key_block(blocks, epoch_2, 2);
+
+    assert_eq!(
+        network.microblock_fees_by_leader(key_block_3),
+        HashMap::from([(0, 800.0), (1, 1200.0 + 400.0), (2, 600.0)])
+    );
+}
+
+#[test]
+fn bitcoin_ng_does_not_pay_the_next_leader_share_of_the_last_epoch() {
+    let mut network = network();
+    let blocks = &mut network.resource.blocks;
+    let key_block_1 = add_key_block(blocks, 0, 0);
+    let epoch_1 = add_microblocks(blocks, key_block_1, 2);
+    let key_block_2 = add_key_block(blocks, epoch_1, 1);
+    let epoch_2 = add_microblocks(blocks, key_block_2, 1);
+
+    let fees = network.microblock_fees_by_leader(epoch_2);
+
+    assert_eq!(fees, HashMap::from([(0, 800.0), (1, 1200.0 + 400.0)]));
+    // 600 of the 3000 of fees wait for the next key block.
+    assert_eq!(fees.values().sum::<f64>(), 3.0 * FEE - 600.0);
+    // the microblocks of genesis have no leader.
+    assert!(network.microblock_fees_by_leader(0).is_empty());
+}
+
+#[test]
+fn bitcoin_ng_microblock_chain_of_a_key_block_fork_loses_to_a_heavier_chain() {
+    let mut network = network();
+    let blocks = &mut network.resource.blocks;
+    // the fork of leader 0 has more blocks but a single key block.
+    let key_block_1 = add_key_block(blocks, 0, 0);
+    let microblock_chain = add_microblocks(blocks, key_block_1, 5);
+    let fork_key_block_1 = add_key_block(blocks, 0, 1);
+    let fork_key_block_2 = add_key_block(blocks, fork_key_block_1, 2);
+
+    let config = NakamotoConsensusConfig::new(600.0, 6, 0, 1.0);
+    let mut consensus = NakamotoConsensus::new(&config);
+    let mut local_block_tree = LocalBlockTree::new();
+    let mut simulator = Simulator::new();
+    for block in 1..network.resource.blocks.len() {
+        local_block_tree.add(block, &network.resource.blocks);
+        consensus.new_incoming_block(
+            block,
+            &network.resource.blocks,
+            &config,
+            &local_block_tree,
+            &mut simulator,
+        );
+        if block == microblock_chain {
+            assert_eq!(consensus.get_canonical_chain_head_index(), microblock_chain);
+        }
+    }
+
+    assert!(network.resource.blocks[microblock_chain].height > 2);
+    assert_eq!(consensus.get_canonical_chain_head_index(), fork_key_block_2);
+    // the microblocks of leader 0 are not paid on the heavier chain.
+    assert_eq!(
+        network.microblock_fees_by_leader(fork_key_block_2),
+        HashMap::new()
+    );
+}
+
+#[test]
+fn bitcoin_ng_leader_stops_its_microblocks_at_a_newer_key_block() {
+    let mut network = network();
+    let blocks = &mut network.resource.blocks;
+    let key_block_1 = add_key_block(blocks, 0, 0);
+    let epoch_1 = add_microblocks(blocks, key_block_1, 1);
+    let key_block_2 = add_key_block(blocks, epoch_1, 1);
+    assert_eq!(network.latest_key_block(epoch_1), key_block_1);
+    assert_eq!(network.latest_key_block(key_block_2), key_block_2);
+
+    // the head of leader 0 is in the epoch of key block 2.
+    network.ecs.consensus_algorithm[0].set_canonical_chain_head_index(key_block_2);
+    let num_of_blocks = network.resource.blocks.len();
+    let mut simulator = Simulator::new();
+    network.generate_microblock_and_receive_it(
+        &mut simulator,
+        &GenerateMicroblockEvent::new(0, key_block_1),
+    );
+
+    assert_eq!(network.resource.blocks.len(), num_of_blocks);
+    assert!(!simulator.is_there_more_events());
+}
@@ -0,0 +1,40 @@
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::{
+    BitcoinProfile, BITCOIN_2022, BITCOIN_PROFILES,
+};
+use crate::scenario::bitcoin_global_network_scenario::BitcoinGlobalNetworkScenario;
+
+#[test]
+fn bitcoin_profile_is_found_by_its_name() {
+    assert_eq!(
+        BitcoinProfile::from_name("bitcoin-2022"),
+        Some(BITCOIN_2022)
+    );
+    for profile in BITCOIN_PROFILES {
+        assert_eq!(BitcoinProfile::from_name(profile.name), Some(profile));
+    }
+}
+
+#[test]
+fn bitcoin_profile_of_an_unknown_name_is_none() {
+    assert_eq!(BitcoinProfile::from_name("bitcoin-2017"), None);
+    assert_eq!(BitcoinProfile::from_name("Bitcoin-2022"), None);
+    assert_eq!(BitcoinProfile::from_name(""), None);
+
+    let mut scenario = BitcoinGlobalNetworkScenario::new(600.0, 6, "unknown", 1, 0.0);
+    assert_eq!(
+        scenario.set_profile_by_name("bitcoin-2017"),
+        Err("Unknown Bitcoin profile: bitcoin-2017 (available: bitcoin-2022)".to_string())
+    );
+}
+
+#[test]
+fn bitcoin_profile_difficulty_is_the_sum_of_the_hash_power_bins() {
+    for profile in BITCOIN_PROFILES {
+        let total_hash_power: f64 = profile.hash_power_bins.iter().sum();
+        assert!((total_hash_power - profile.difficulty).abs() < 1e-6);
+        assert_eq!(
+            profile.block_size_distribution.len(),
+            profile.block_size_bins.len()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::log::chain_growth_logger::ChainGrowthLogger;
+use crate::log::record::Field;
+use crate::log::EventLoggerInfo::{IsBlockGenerationEvent, IsSamplingEvent};
+use crate::log::{EventLogger, EventLoggerInfo, Logger, NetworkLogHandler};
+use crate::scenario::ScenarioData;
+
+/// A chain of 4 blocks above the genesis block, where the height of a block
+/// is its index, and the chain heads of 5 nodes.
+struct TestNetwork {
+    parents: Vec<Vec<usize>>,
+    heads: Vec<Option<usize>>,
+}
+
+impl NetworkLogHandler for TestNetwork {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        10.0 * block_index as f64
+    }
+    fn get_block_creator(&self, _: usize) -> Option<usize> {
+        Some(0)
+    }
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        block_index as i32
+    }
+    fn get_block_size(&self, _: usize) -> u64 {
+        1000
+    }
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        &self.parents[block_index]
+    }
+    fn get_num_of_nodes(&self) -> usize {
+        self.heads.len()
+    }
+    fn get_canonical_chain_head(&self, node_index: usize) -> Option<usize> {
+        self.heads[node_index]
+    }
+}
+
+#[test]
+fn chain_growth_logger_samples_the_chain_heads_of_the_nodes() {
+    let mut network = TestNetwork {
+        parents: vec![vec![], vec![0], vec![1], vec![2], vec![3]],
+        heads: vec![None; 5],
+    };
+    let (mut logger, records) = EventLogger::in_memory(ChainGrowthLogger::new(10.0));
+    let scenario = ScenarioData::new("chain-growth".to_string(), 5, "Test".to_string());
+    logger.initial_log(&scenario).unwrap();
+    assert_eq!(logger.sampling_interval(), Some(10.0));
+    let mut log = |info: EventLoggerInfo, network: &TestNetwork| {
+        logger.log_before_each_event(&info, network).unwrap();
+        logger.log_after_each_event(&info, network).unwrap();
+    };
+
+    // node 4 has no chain-based consensus yet.
+    network.heads = vec![Some(1), Some(2), Some(2), Some(0), None];
+    log(IsSamplingEvent(10.0, 10.0), &network);
+    // only the samples of the interval of the logger are written.
+    log(IsSamplingEvent(5.0, 15.0), &network);
+    log(IsBlockGenerationEvent(3, 0, 15.0), &network);
+    network.heads = vec![Some(3), Some(3), Some(2), Some(2), Some(1)];
+    log(IsSamplingEvent(10.0, 20.0), &network);
+    network.heads = vec![None; 5];
+    log(IsSamplingEvent(10.0, 30.0), &network);
+    logger.final_log(&scenario).unwrap();
+
+    let tables = records.tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(
+        tables[0].header,
+        [
+            "Time",
+            "NumOfHeads",
+            "MaxHeadHeight",
+            "MedianHeadHeight",
+            "MajorityHeadFraction",
+            "LeastSyncedNodeLag",
+        ]
+    );
+    let null = Field::Null;
+    assert_eq!(
+        tables[0].records,
+        [
+            // heights 0, 1, 2 and 2.
+            vec![
+                10.0.into(),
+                3usize.into(),
+                2.into(),
+                1.5.into(),
+                0.5.into(),
+                2.into()
+            ],
+            // heights 1, 2, 2, 3 and 3.
+            vec![
+                20.0.into(),
+                3usize.into(),
+                3.into(),
+                2.0.into(),
+                0.4.into(),
+                2.into()
+            ],
+            vec![
+                30.0.into(),
+                0usize.into(),
+                null.clone(),
+                null.clone(),
+                null.clone(),
+                null
+            ],
+        ]
+    );
+}
@@ -0,0 +1,129 @@
+use crate::log::confirmation_latency_logger::ConfirmationLatencyLogger;
+use crate::log::record::{Field, FinalRow};
+use crate::log::EventLoggerInfo::IsBlockConfirmationEvent;
+use crate::log::{CSVLogger, NetworkLogHandler};
+
+/// A chain-based network of 4 nodes where block `i` is created by node 0 at
+/// time `10 * i`. Blocks 1 and 2 and blocks 3 and 4 are two forks of the
+/// genesis block.
+struct TestNetwork {
+    parents: Vec<Vec<usize>>,
+}
+
+impl TestNetwork {
+    fn new() -> Self {
+        Self {
+            parents: vec![vec![], vec![0], vec![1], vec![0], vec![3]],
+        }
+    }
+}
+
+impl NetworkLogHandler for TestNetwork {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        10.0 * block_index as f64
+    }
+    fn get_block_creator(&self, _: usize) -> Option<usize> {
+        Some(0)
+    }
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        match self.parents[block_index].first() {
+            Some(parent) => self.get_block_height(*parent) + 1,
+            None => 0,
+        }
+    }
+    fn get_block_size(&self, _: usize) -> u64 {
+        1000
+    }
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        &self.parents[block_index]
+    }
+    fn get_num_of_nodes(&self) -> usize {
+        4
+    }
+    fn get_canonical_chain_head(&self, _: usize) -> Option<usize> {
+        Some(0)
+    }
+}
+
+/// Node 0 confirms the fork of block 2, switches to the fork of block 4
+/// and then back to block 2, while nodes 1 and 2 only confirm block 1.
+fn confirmation_rows() -> Vec<FinalRow> {
+    let network = TestNetwork::new();
+    let mut logger = ConfirmationLatencyLogger::new(5.0);
+    for event in [
+        IsBlockConfirmationEvent(1, 0, 15.0),
+        IsBlockConfirmationEvent(1, 1, 17.0),
+        IsBlockConfirmationEvent(1, 2, 19.0),
+        IsBlockConfirmationEvent(2, 0, 26.0),
+        IsBlockConfirmationEvent(4, 0, 50.0),
+        IsBlockConfirmationEvent(2, 0, 60.0),
+    ] {
+        logger.csv_output_condition_after_event(&event, &network);
+    }
+    logger.csv_final_output()
+}
+
+/// Returns the records of the table with the given first column.
+fn table(rows: &[FinalRow], first_column: &str) -> Vec<Vec<Field>> {
+    rows.iter()
+        .skip_while(|row| !matches!(row, FinalRow::Header(header) if header[0] == first_column))
+        .skip(1)
+        .map_while(|row| match row {
+            FinalRow::Record(record) => Some(record.clone()),
+            FinalRow::Header(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn confirmation_latency_logger_counts_each_node_once_in_the_median_of_all_nodes() {
+    let rows = confirmation_rows();
+    let blocks: Vec<Vec<Field>> = rows
+        .iter()
+        .map_while(|row| match row {
+            FinalRow::Record(record) => Some(record[4..].to_vec()),
+            FinalRow::Header(_) => None,
+        })
+        .collect();
+
+    // node 0 confirming blocks 1 and 2 again after the reorg is not counted.
+    assert_eq!(
+        blocks,
+        [
+            // 3 of the 4 nodes confirmed block 1.
+            vec![3usize.into(), 5.0.into(), 8.0.into(), 9.0.into()],
+            vec![1usize.into(), 6.0.into(), Field::Null, 6.0.into()],
+            vec![1usize.into(), 20.0.into(), Field::Null, 20.0.into()],
+            vec![1usize.into(), 10.0.into(), Field::Null, 10.0.into()],
+        ]
+    );
+}
+
+#[test]
+fn confirmation_latency_logger_counts_the_latencies_in_the_histogram() {
+    let rows = confirmation_rows();
+
+    assert_eq!(
+        table(&rows, "LatencyBinStart"),
+        [
+            vec![5.0.into(), 4usize.into()],
+            vec![10.0.into(), 1usize.into()],
+            vec![20.0.into(), 1usize.into()],
+        ]
+    );
+}
+
+#[test]
+fn confirmation_latency_logger_detects_the_confirmed_blocks_reorged_out() {
+    let rows = confirmation_rows();
+
+    assert_eq!(
+        table(&rows, "ReorgedConfirmedBlock"),
+        [
+            vec![2usize.into(), 0usize.into(), 50.0.into()],
+            vec![1usize.into(), 0usize.into(), 50.0.into()],
+            vec![4usize.into(), 0usize.into(), 60.0.into()],
+            vec![3usize.into(), 0usize.into(), 60.0.into()],
+        ]
+    );
+}
@@ -0,0 +1,172 @@
+use crate::network::stats::csv_network_stats::CSVNetworkStats;
+use crate::network::stats::NetworkStats;
+use std::io::ErrorKind;
+
+const REGIONS: &str = "\
+# a comment line
+Region,DownloadBandwidth,UploadBandwidth,NodeShare,MinerShare
+Europe, 50.0, 20.0, 3, 1
+Asia, 40.0, 10.0, 1, 0
+";
+
+const LATENCY: &str = "\
+Region,Europe,Asia
+Europe,10,100
+Asia,110,20
+";
+
+const PARAMETERS: &str = "\
+Parameter,Value
+DownloadBandwidthShape,2.0
+UploadBandwidthShape,3.0
+LatencyParetoShape,5.0
+";
+
+fn load(regions: &str, latency: &str, parameters: &str) -> std::io::Result<CSVNetworkStats> {
+    CSVNetworkStats::from_readers(
+        regions.as_bytes(),
+        latency.as_bytes(),
+        parameters.as_bytes(),
+    )
+}
+
+fn load_error(regions: &str, latency: &str, parameters: &str) -> String {
+    let error = load(regions, latency, parameters).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    error.to_string()
+}
+
+#[test]
+fn csv_network_stats_loads_a_dataset() {
+    let stats = load(REGIONS, LATENCY, PARAMETERS).unwrap();
+
+    assert_eq!(stats.get_num_of_regions(), 2);
+    assert_eq!(stats.get_region_name(1), "Asia");
+    assert_eq!(stats.get_average_latency(0, 1), 100.0);
+    assert_eq!(stats.get_average_latency(1, 0), 110.0);
+    assert_eq!(stats.get_download_bandwidth_average(0), 50.0);
+    assert_eq!(stats.get_upload_bandwidth_average(1), 10.0);
+    assert_eq!(stats.get_download_bandwidth_shape(), 2.0);
+    assert_eq!(stats.get_upload_bandwidth_shape(), 3.0);
+    assert_eq!(stats.get_latency_pareto_shape(), 5.0);
+    assert_eq!(stats.get_node_distribution(), [3.0, 1.0]);
+    assert_eq!(stats.get_miner_distribution(), [1.0, 0.0]);
+}
+
+#[test]
+fn csv_network_stats_rejects_a_latency_matrix_of_other_dimensions() {
+    assert_eq!(
+        load_error(REGIONS, "Region,Europe,Asia\nEurope,10,100\n", PARAMETERS),
+        "latency.csv: expected a 2x2 matrix but found 1 rows"
+    );
+    assert_eq!(
+        load_error(REGIONS, "Region,Europe\nEurope,10\n", PARAMETERS),
+        "latency.csv: expected 3 columns at row 0 but found 2"
+    );
+    assert_eq!(
+        load_error(
+            REGIONS,
+            "Region,Europe,Asia\nEurope,10,100\nAsia,110,20\nAfrica,1,1\n",
+            PARAMETERS
+        ),
+        "latency.csv: row 2 should belong to region `<none>`"
+    );
+}
+
+#[test]
+fn csv_network_stats_rejects_regions_in_another_order() {
+    assert_eq!(
+        load_error(
+            REGIONS,
+            "Region,Asia,Europe\nAsia,20,110\nEurope,100,10\n",
+            PARAMETERS
+        ),
+        "latency.csv: header regions do not match regions.csv"
+    );
+    assert_eq!(
+        load_error(
+            REGIONS,
+            "Region,Europe,Asia\nAsia,110,20\nEurope,10,100\n",
+            PARAMETERS
+        ),
+        "latency.csv: row 0 should belong to region `Europe`"
+    );
+}
+
+#[test]
+fn csv_network_stats_rejects_invalid_regions() {
+    assert_eq!(
+        load_error(
+            "Region,Download,Upload,NodeShare,MinerShare\n",
+            LATENCY,
+            PARAMETERS
+        ),
+        "regions.csv: expected header \
+         `Region,DownloadBandwidth,UploadBandwidth,NodeShare,MinerShare`"
+    );
+    assert_eq!(
+        load_error(
+            "Region,DownloadBandwidth,UploadBandwidth,NodeShare,MinerShare\n",
+            "Region\n",
+            PARAMETERS
+        ),
+        "regions.csv: no region is defined"
+    );
+    assert_eq!(
+        load_error(
+            &REGIONS.replace("Asia", "Europe"),
+            &LATENCY.replace("Asia", "Europe"),
+            PARAMETERS
+        ),
+        "regions.csv: region names must be unique"
+    );
+    assert_eq!(
+        load_error(&REGIONS.replace("40.0", "fast"), LATENCY, PARAMETERS),
+        "regions.csv: invalid number `fast` at row 1"
+    );
+    assert_eq!(
+        load_error(&REGIONS.replace("40.0", "0"), LATENCY, PARAMETERS),
+        "regions.csv: bandwidths must be positive"
+    );
+    assert_eq!(
+        load_error(&REGIONS.replace(", 1, 0", ", 1, -1"), LATENCY, PARAMETERS),
+        "regions.csv: MinerShare must be non-negative with a positive sum"
+    );
+}
+
+#[test]
+fn csv_network_stats_rejects_invalid_latencies_and_parameters() {
+    assert_eq!(
+        load_error(REGIONS, &LATENCY.replace("110", "-110"), PARAMETERS),
+        "latency.csv: latencies must not be negative"
+    );
+    assert_eq!(
+        load_error(
+            REGIONS,
+            LATENCY,
+            &PARAMETERS.replace("LatencyParetoShape,5.0\n", "")
+        ),
+        "parameters.csv: missing parameter `LatencyParetoShape`"
+    );
+    // without its header, the first parameter would be read as the header.
+    assert_eq!(
+        load_error(
+            REGIONS,
+            LATENCY,
+            &PARAMETERS.replace("Parameter,Value\n", "")
+        ),
+        "parameters.csv: expected header `Parameter,Value`"
+    );
+    assert_eq!(
+        load_error(REGIONS, LATENCY, &format!("{PARAMETERS}LatencyShape,1.0\n")),
+        "parameters.csv: unknown parameter `LatencyShape`"
+    );
+    assert_eq!(
+        load_error(REGIONS, LATENCY, &PARAMETERS.replace("5.0", "1.0")),
+        "parameters.csv: LatencyParetoShape must be greater than 1"
+    );
+    assert_eq!(
+        load_error(REGIONS, LATENCY, &PARAMETERS.replace("3.0", "0.0")),
+        "parameters.csv: bandwidth shapes must be positive"
+    );
+}
@@ -0,0 +1,171 @@
+use crate::consensus::algorithm::gasper_consensus::GasperConsensus;
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::gasper_consensus_config::GasperConsensusConfig;
+use crate::ledger_data::ethereum_block::EthereumBlock;
+use crate::ledger_data::vote::{Checkpoint, Vote};
+use crate::simulator::Simulator;
+use std::collections::HashSet;
+
+const GENESIS: Checkpoint = Checkpoint {
+    epoch: 0,
+    block_index: 0,
+};
+
+/// A validator of 4 with equal stakes, on a chain with a block per slot and
+/// epochs of 2 slots, so the checkpoint of epoch `e` is block `2 * e`.
+struct TestValidator {
+    consensus: GasperConsensus,
+    blocks: Vec<EthereumBlock>,
+    config: GasperConsensusConfig,
+    local_block_tree: LocalBlockTree,
+    simulator: Simulator,
+}
+
+impl TestValidator {
+    fn new(num_of_blocks: usize) -> Self {
+        let mut config = GasperConsensusConfig::new(1.0, 2, 0);
+        config.set_validator_stakes(vec![1.0; 4]);
+        let mut blocks = vec![EthereumBlock::generate_genesis_block()];
+        let mut local_block_tree = LocalBlockTree::new();
+        for slot in 1..=num_of_blocks {
+            blocks.push(EthereumBlock::new_with_parent(
+                slot as f64,
+                Some(slot % 4),
+                slot as i32,
+                slot - 1,
+                1000,
+                slot as u64,
+            ));
+            local_block_tree.add(slot, &blocks);
+        }
+        Self {
+            consensus: GasperConsensus::new(&config),
+            blocks,
+            config,
+            local_block_tree,
+            simulator: Simulator::new(),
+        }
+    }
+
+    fn checkpoint(&self, epoch: u64) -> Checkpoint {
+        let last_block = self.blocks.len() - 1;
+        GasperConsensus::epoch_boundary_checkpoint(last_block, epoch, &self.blocks, &self.config)
+    }
+
+    /// Receives the FFG votes of the voters for the link.
+    fn receive_votes(&mut self, voters: &[usize], source: Checkpoint, target: Checkpoint) {
+        for &voter in voters {
+            let vote = Vote::new(
+                voter,
+                self.config.first_slot_of_epoch(target.epoch),
+                target.block_index,
+                source,
+                target,
+            );
+            self.consensus.new_incoming_vote(
+                &vote,
+                &self.blocks,
+                &self.config,
+                &self.local_block_tree,
+                &mut self.simulator,
+            );
+        }
+    }
+}
+
+#[test]
+fn gasper_checkpoints_are_the_epoch_boundary_blocks() {
+    let validator = TestValidator::new(5);
+    assert_eq!(validator.checkpoint(1), Checkpoint::new(1, 2));
+    assert_eq!(validator.checkpoint(2), Checkpoint::new(2, 4));
+    // the epoch boundary block is the latest block not after the first slot.
+    let mut skipped_slot = TestValidator::new(0);
+    skipped_slot
+        .blocks
+        .push(EthereumBlock::new_with_parent(1.0, None, 1, 0, 1000, 1));
+    skipped_slot
+        .blocks
+        .push(EthereumBlock::new_with_parent(3.0, None, 2, 1, 1000, 3));
+    assert_eq!(skipped_slot.checkpoint(1), Checkpoint::new(1, 1));
+}
+
+#[test]
+fn gasper_justifies_a_checkpoint_with_a_supermajority_link() {
+    let mut validator = TestValidator::new(5);
+    let epoch_1 = validator.checkpoint(1);
+
+    validator.receive_votes(&[0, 1], GENESIS, epoch_1);
+    assert_eq!(validator.consensus.justified_checkpoint, GENESIS);
+
+    validator.receive_votes(&[2], GENESIS, epoch_1);
+    assert_eq!(validator.consensus.justified_checkpoint, epoch_1);
+    // genesis was already finalized.
+    assert_eq!(validator.consensus.finalized_checkpoint, GENESIS);
+    assert_eq!(validator.consensus.confirmed_blocks, HashSet::from([0]));
+}
+
+#[test]
+fn gasper_counts_a_single_ffg_vote_per_validator_and_target_epoch() {
+    let mut validator = TestValidator::new(5);
+    let epoch_1 = validator.checkpoint(1);
+
+    validator.receive_votes(&[0, 1, 1], GENESIS, epoch_1);
+    assert_eq!(validator.consensus.justified_checkpoint, GENESIS);
+    // an equivocating vote for another target of the same epoch is ignored too.
+    validator.receive_votes(&[0], GENESIS, Checkpoint::new(1, 1));
+    validator.receive_votes(&[1, 2], GENESIS, Checkpoint::new(1, 1));
+    assert_eq!(validator.consensus.justified_checkpoint, GENESIS);
+}
+
+#[test]
+fn gasper_finalizes_the_source_of_a_link_between_consecutive_epochs() {
+    let mut validator = TestValidator::new(5);
+    let epoch_1 = validator.checkpoint(1);
+    let epoch_2 = validator.checkpoint(2);
+    validator.receive_votes(&[0, 1, 2], GENESIS, epoch_1);
+    let inserted_events = validator.simulator.inserted_events;
+
+    validator.receive_votes(&[0, 1, 2], epoch_1, epoch_2);
+
+    assert_eq!(validator.consensus.justified_checkpoint, epoch_2);
+    assert_eq!(validator.consensus.finalized_checkpoint, epoch_1);
+    assert_eq!(
+        validator.consensus.confirmed_blocks,
+        HashSet::from([0, 1, 2])
+    );
+    // a confirmation for each newly finalized block.
+    assert_eq!(validator.simulator.inserted_events - inserted_events, 2);
+}
+
+#[test]
+fn gasper_does_not_finalize_the_source_of_a_link_skipping_an_epoch() {
+    let mut validator = TestValidator::new(5);
+    let epoch_2 = validator.checkpoint(2);
+
+    validator.receive_votes(&[0, 1, 2], GENESIS, epoch_2);
+
+    assert_eq!(validator.consensus.justified_checkpoint, epoch_2);
+    assert_eq!(validator.consensus.finalized_checkpoint, GENESIS);
+}
+
+#[test]
+fn gasper_processes_the_links_received_before_their_source_is_justified() {
+    let mut validator = TestValidator::new(7);
+    let epoch_1 = validator.checkpoint(1);
+    let epoch_2 = validator.checkpoint(2);
+    let epoch_3 = validator.checkpoint(3);
+
+    validator.receive_votes(&[0, 1, 2], epoch_2, epoch_3);
+    validator.receive_votes(&[0, 1, 2], epoch_1, epoch_2);
+    assert_eq!(validator.consensus.justified_checkpoint, GENESIS);
+
+    validator.receive_votes(&[0, 1, 2], GENESIS, epoch_1);
+
+    assert_eq!(validator.consensus.justified_checkpoint, epoch_3);
+    assert_eq!(validator.consensus.finalized_checkpoint, epoch_2);
+    assert_eq!(
+        validator.consensus.confirmed_blocks,
+        HashSet::from([0, 1, 2, 3, 4])
+    );
+}
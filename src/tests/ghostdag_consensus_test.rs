@@ -0,0 +1,170 @@
+use crate::consensus::algorithm::ghostdag_consensus::GhostDAGConsensus;
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::consensus::blockchain::local_block_dag::LocalBlockDAG;
+use crate::consensus::config::ghostdag_consensus_config::GhostDAGConsensusConfig;
+use crate::ledger_data::ghostdag_block::GhostDAGBlock;
+use crate::simulator::Simulator;
+use std::collections::{BTreeMap, HashSet};
+
+const GENESIS: usize = 0;
+
+/// A block DAG built by hand, each block colored with the same `k`.
+struct TestDAG {
+    blocks: Vec<GhostDAGBlock>,
+    k: usize,
+}
+
+impl TestDAG {
+    fn new(k: usize) -> Self {
+        Self {
+            blocks: vec![GhostDAGBlock::generate_genesis_block()],
+            k,
+        }
+    }
+
+    /// Adds a block on the parents and returns its index.
+    fn add(&mut self, parents: &[usize]) -> usize {
+        let ghostdag_data = GhostDAGConsensus::ghostdag_data(parents, &self.blocks, self.k);
+        self.blocks.push(GhostDAGBlock::new_with_parents(
+            0.0,
+            None,
+            parents.to_vec(),
+            1000,
+            ghostdag_data,
+            &self.blocks,
+        ));
+        self.blocks.len() - 1
+    }
+
+    fn blues(&self, block: usize) -> &[usize] {
+        &self.blocks[block].ghostdag_data.mergeset_blues
+    }
+
+    fn reds(&self, block: usize) -> &[usize] {
+        &self.blocks[block].ghostdag_data.mergeset_reds
+    }
+
+    fn blue_score(&self, block: usize) -> u64 {
+        self.blocks[block].blue_score()
+    }
+}
+
+#[test]
+fn ghostdag_colors_a_chain_blue() {
+    let mut dag = TestDAG::new(0);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[a]);
+
+    assert_eq!(dag.blues(b), [a]);
+    assert!(dag.reds(b).is_empty());
+    assert_eq!(dag.blue_score(a), 1);
+    assert_eq!(dag.blue_score(b), 2);
+}
+
+#[test]
+fn ghostdag_colors_a_parallel_block_red_with_k_0() {
+    let mut dag = TestDAG::new(0);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[GENESIS]);
+    let c = dag.add(&[b, a]);
+
+    // the parents have the same blue score, so the smallest index is selected.
+    assert_eq!(dag.blocks[c].ghostdag_data.selected_parent, Some(a));
+    assert_eq!(dag.blues(c), [a]);
+    assert_eq!(dag.reds(c), [b]);
+    assert_eq!(dag.blue_score(c), 2);
+}
+
+#[test]
+fn ghostdag_colors_a_parallel_block_blue_within_k() {
+    let mut dag = TestDAG::new(1);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[GENESIS]);
+    let c = dag.add(&[a, b]);
+
+    assert_eq!(dag.blues(c), [a, b]);
+    assert!(dag.reds(c).is_empty());
+    assert_eq!(dag.blue_score(c), 3);
+    // a and b are in the anticone of each other.
+    assert_eq!(
+        dag.blocks[c].ghostdag_data.blues_anticone_sizes,
+        BTreeMap::from([(a, 1), (b, 1)])
+    );
+}
+
+#[test]
+fn ghostdag_colors_at_most_k_plus_1_blues_per_mergeset() {
+    let mut dag = TestDAG::new(1);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[GENESIS]);
+    let c = dag.add(&[GENESIS]);
+    let d = dag.add(&[a, b, c]);
+
+    assert_eq!(dag.blues(d), [a, b]);
+    assert_eq!(dag.reds(d), [c]);
+    assert_eq!(dag.blue_score(d), 3);
+}
+
+#[test]
+fn ghostdag_colors_red_a_block_which_would_break_the_k_cluster() {
+    let mut dag = TestDAG::new(1);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[GENESIS]);
+    let x = dag.add(&[a, b]);
+    // c is in the anticone of the blues a, b and x.
+    let c = dag.add(&[GENESIS]);
+    let y = dag.add(&[x, c]);
+
+    assert_eq!(dag.blocks[y].ghostdag_data.selected_parent, Some(x));
+    assert_eq!(dag.blues(y), [x]);
+    assert_eq!(dag.reds(y), [c]);
+    assert_eq!(dag.blue_score(y), 4);
+}
+
+#[test]
+fn ghostdag_colors_blue_a_block_with_k_blues_in_its_anticone() {
+    let mut dag = TestDAG::new(1);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[a]);
+    // c only misses b, so its blue anticone is b alone.
+    let c = dag.add(&[a]);
+    let d = dag.add(&[b, c]);
+
+    assert_eq!(dag.blues(d), [b, c]);
+    assert!(dag.reds(d).is_empty());
+    assert!(GhostDAGConsensus::is_in_past(a, d, &dag.blocks));
+    assert!(!GhostDAGConsensus::is_in_past(c, b, &dag.blocks));
+}
+
+#[test]
+fn ghostdag_confirms_the_merged_blocks_with_their_colors() {
+    let mut dag = TestDAG::new(0);
+    let a = dag.add(&[GENESIS]);
+    let b = dag.add(&[GENESIS]);
+    let c = dag.add(&[a, b]);
+    let d = dag.add(&[c]);
+    let e = dag.add(&[d]);
+
+    let config = GhostDAGConsensusConfig::new(0, 1, 1.0, GENESIS, 1.0);
+    let mut consensus = GhostDAGConsensus::new(&config);
+    let mut simulator = Simulator::new();
+    for block in [a, b, c, d, e] {
+        consensus.new_incoming_block(
+            block,
+            &dag.blocks,
+            &config,
+            &LocalBlockDAG::new(),
+            &mut simulator,
+        );
+    }
+
+    assert_eq!(consensus.virtual_selected_parent, e);
+    // d is the chain block one blue score below e.
+    assert_eq!(
+        consensus.confirmed_blocks,
+        HashSet::from([GENESIS, a, b, c, d])
+    );
+    assert!(consensus.colors[&a]);
+    assert!(!consensus.colors[&b]);
+    assert!(consensus.colors[&c]);
+}
@@ -0,0 +1,85 @@
+use crate::consensus::algorithm::hotstuff_consensus::HotStuffConsensus;
+use crate::consensus::config::hotstuff_consensus_config::HotStuffConsensusConfig;
+use crate::ledger_data::vote::BFTVoteType::{NewView, Proposal};
+use crate::tests::bft_test_replicas::TestReplicas;
+
+fn start_replicas(num_of_replicas: usize, crashed: &[usize]) -> TestReplicas<HotStuffConsensus> {
+    TestReplicas::start(
+        HotStuffConsensusConfig::new(num_of_replicas, 1.0, 0),
+        crashed,
+    )
+}
+
+#[test]
+fn hotstuff_commits_the_head_of_a_three_chain_of_consecutive_views() {
+    let mut replicas = start_replicas(4, &[]);
+    // the proposal of view 4 carries the QC of view 3, completing the chain
+    // of views 1, 2 and 3.
+    replicas.deliver(|_, vote| vote.view <= 3);
+    for node in 0..4 {
+        assert!(replicas.committed_blocks(node).is_empty());
+    }
+
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.view <= 4);
+
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+        assert_eq!(replicas.replicas[node].view, 5);
+    }
+    // the leader rotates with the view.
+    for block in 1..=4 {
+        assert_eq!(replicas.blocks[block].creator, Some(block % 4));
+        assert_eq!(replicas.blocks[block].view, block as u64);
+    }
+}
+
+#[test]
+fn hotstuff_next_leader_proposes_on_a_quorum_of_votes_without_the_proposal() {
+    // the leader of view 2 receives the votes of view 1 before its proposal.
+    let mut replicas = start_replicas(4, &[]);
+    replicas.deliver(|node, vote| vote.view <= 1 && !(node == 2 && vote.vote_type == Proposal));
+
+    assert_eq!(replicas.num_of_created_blocks(), 2);
+    assert_eq!(replicas.blocks[2].creator, Some(2));
+    assert_eq!(replicas.blocks[2].parents, [1]);
+
+    // the late proposal does not make it propose again.
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.view <= 2);
+    assert_eq!(replicas.num_of_created_blocks(), 3);
+    assert_eq!(replicas.blocks[3].view, 3);
+}
+
+#[test]
+fn hotstuff_changes_the_view_of_a_crashed_leader() {
+    // the leader of view 2 crashes, so the QC of the block of view 1 is lost.
+    let mut replicas = start_replicas(7, &[2]);
+    replicas.deliver(|_, vote| vote.view <= 1);
+    replicas.expire_timeouts();
+
+    // the leader of view 3 proposes on the genesis block after a quorum of
+    // new views.
+    replicas.deliver(|_, vote| vote.view <= 3);
+    let new_views = replicas
+        .sent_votes
+        .iter()
+        .filter(|vote| vote.vote_type == NewView && vote.view == 3)
+        .count();
+    assert_eq!(new_views, 6);
+    assert_eq!(replicas.blocks[2].creator, Some(3));
+    assert_eq!(replicas.blocks[2].view, 3);
+    assert_eq!(replicas.blocks[2].parents, [0]);
+
+    // views 1 and 3 are not consecutive, so block 1 is never committed.
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.view <= 5);
+    for node in (0..7).filter(|&node| node != 2) {
+        assert!(replicas.committed_blocks(node).is_empty());
+    }
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.view <= 6);
+    for node in (0..7).filter(|&node| node != 2) {
+        assert_eq!(replicas.committed_blocks(node), [(1, 2)].into());
+    }
+}
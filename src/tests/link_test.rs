@@ -0,0 +1,71 @@
+use crate::network::node::link::{TcpFlowConfig, TransferModel};
+
+const MSS: u64 = 1460;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.max(1.0),
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn bandwidth_model_only_serializes_the_data() {
+    assert_close(
+        TransferModel::Bandwidth.transfer_time(1000, 8000.0, 0.5),
+        1.0,
+    );
+}
+
+#[test]
+fn tcp_flow_sends_the_initial_window_without_waiting() {
+    let tcp = TcpFlowConfig::default();
+    // 5 segments fit in the initial window of 10 segments.
+    assert_close(
+        tcp.transfer_time(5 * MSS, 1e8, 0.1),
+        (5 * MSS * 8) as f64 / 1e8,
+    );
+}
+
+#[test]
+fn tcp_flow_doubles_the_window_each_round_trip_in_slow_start() {
+    let tcp = TcpFlowConfig::default();
+    // windows of 10 and 20 segments, then the last 100 bytes.
+    let size = 10 * MSS + 20 * MSS + 100;
+    assert_close(tcp.transfer_time(size, 1e8, 0.1), 0.2 + 800.0 / 1e8);
+}
+
+#[test]
+fn tcp_flow_waits_for_the_handshake_round_trips() {
+    let tcp = TcpFlowConfig {
+        handshake_rtts: 1.5,
+        ..TcpFlowConfig::default()
+    };
+    assert_close(
+        tcp.transfer_time(5 * MSS, 1e8, 0.1),
+        0.15 + (5 * MSS * 8) as f64 / 1e8,
+    );
+}
+
+#[test]
+fn tcp_flow_is_limited_by_the_max_window() {
+    let tcp = TcpFlowConfig {
+        max_window: 10 * MSS,
+        ..TcpFlowConfig::default()
+    };
+    // 5 windows of 10 segments, one per round trip.
+    let window_time = (10 * MSS * 8) as f64 / 1e9;
+    assert_close(tcp.transfer_time(50 * MSS, 1e9, 0.1), 0.4 + window_time);
+}
+
+#[test]
+fn tcp_flow_is_limited_by_the_bandwidth_on_a_small_bandwidth_delay_product() {
+    // the bandwidth-delay product is less than a segment, so the link is never
+    // idle and the transfer takes as long as with the bandwidth model.
+    let tcp = TcpFlowConfig::default();
+    let size = 1_000_000;
+    assert_close(
+        TransferModel::TcpFlow(tcp).transfer_time(size, 1e6, 0.01),
+        TransferModel::Bandwidth.transfer_time(size, 1e6, 0.01),
+    );
+}
@@ -0,0 +1,114 @@
+use crate::log::miner_fairness_logger::{gini_coefficient, MinerFairnessLogger};
+use crate::log::record::{Field, FinalRow};
+use crate::log::stale_block_logger::MainChainView;
+use crate::log::EventLoggerInfo::IsBlockGenerationEvent;
+use crate::log::{CSVLogger, NetworkLogHandler};
+
+/// A Bitcoin-NG chain of 2 miners of equal hash power: the key block of
+/// miner 0 and its 2 microblocks, then the key block of miner 1 and its
+/// microblock.
+struct TestNetwork {
+    /// The creator of each block and whether it is a microblock.
+    blocks: Vec<(Option<usize>, bool)>,
+    parents: Vec<Vec<usize>>,
+}
+
+impl TestNetwork {
+    fn new() -> Self {
+        let blocks = vec![
+            (None, false),
+            (Some(0), false),
+            (Some(0), true),
+            (Some(0), true),
+            (Some(1), false),
+            (Some(1), true),
+        ];
+        let parents = (0..blocks.len())
+            .map(|block| if block == 0 { vec![] } else { vec![block - 1] })
+            .collect();
+        Self { blocks, parents }
+    }
+}
+
+impl NetworkLogHandler for TestNetwork {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        10.0 * block_index as f64
+    }
+    fn get_block_creator(&self, block_index: usize) -> Option<usize> {
+        self.blocks[block_index].0
+    }
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        block_index as i32
+    }
+    fn get_block_size(&self, _: usize) -> u64 {
+        1000
+    }
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        &self.parents[block_index]
+    }
+    fn get_num_of_nodes(&self) -> usize {
+        2
+    }
+    fn get_node_hash_power(&self, _: usize) -> Option<f64> {
+        Some(1.0)
+    }
+    fn is_microblock(&self, block_index: usize) -> bool {
+        self.blocks[block_index].1
+    }
+}
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() < 1e-12, "{value} != {expected}");
+}
+
+#[test]
+fn gini_coefficient_is_zero_for_equal_values() {
+    assert_eq!(gini_coefficient(&[]), 0.0);
+    assert_eq!(gini_coefficient(&[0.0, 0.0]), 0.0);
+    assert_close(gini_coefficient(&[0.25; 4]), 0.0);
+}
+
+#[test]
+fn gini_coefficient_is_n_minus_one_over_n_for_a_single_nonzero_value() {
+    for n in [2, 4, 10] {
+        let mut values = vec![0.0; n];
+        values[n / 2] = 3.0;
+        assert_close(gini_coefficient(&values), (n - 1) as f64 / n as f64);
+    }
+}
+
+#[test]
+fn gini_coefficient_is_the_mean_absolute_difference_over_twice_the_mean() {
+    // the absolute differences of the ordered pairs of 1, 2 and 3 sum to 8,
+    // so the Gini coefficient is 8 / (2 * 3^2 * 2).
+    assert_close(gini_coefficient(&[3.0, 1.0, 2.0]), 2.0 / 9.0);
+}
+
+#[test]
+fn miner_fairness_logger_counts_the_key_blocks_of_bitcoin_ng() {
+    let network = TestNetwork::new();
+    let mut logger = MinerFairnessLogger::new(MainChainView::Omniscient);
+    for block in 1..network.blocks.len() {
+        let creator = network.blocks[block].0.unwrap();
+        let event = IsBlockGenerationEvent(block, creator, 10.0 * block as f64);
+        logger.csv_output_condition_after_event(&event, &network);
+    }
+
+    let rows = logger.csv_final_output();
+
+    let block_shares: Vec<&[Field]> = rows
+        .iter()
+        .map_while(|row| match row {
+            FinalRow::Record(record) => Some(&record[3..5]),
+            FinalRow::Header(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        block_shares,
+        [[1usize.into(), 0.5.into()], [1usize.into(), 0.5.into()]]
+    );
+    assert_eq!(
+        rows.last(),
+        Some(&FinalRow::Record(vec![0.0.into(), 0.0.into()]))
+    );
+}
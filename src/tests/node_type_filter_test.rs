@@ -0,0 +1,136 @@
+use crate::log::block_arrival_logger::BlockArrivalLogger;
+use crate::log::node_type_filter::NodeTypeFilter;
+use crate::log::record::{Field, FinalRow};
+use crate::log::stale_block_logger::{MainChainView, StaleBlockLogger};
+use crate::log::EventLoggerInfo::{IsBlockGenerationEvent, IsReceiveEvent};
+use crate::log::{CSVLogger, EventLoggerInfo, NetworkLogHandler};
+use crate::network::message::DataType::IsBlock;
+use crate::network::message::MessageType::{DataMessage, HeadersMessage};
+use crate::network::node::NodeType;
+use crate::network::node::NodeType::{ListeningNode, Miner, RelayNode, SPVClient};
+
+/// A network of nodes of the given types, where block `i` is created by the
+/// given node at time `10 * i`.
+struct TestNetwork {
+    node_types: Vec<NodeType>,
+    /// The parents and the creator of each block, the genesis block first.
+    blocks: Vec<(Vec<usize>, Option<usize>)>,
+}
+
+impl NetworkLogHandler for TestNetwork {
+    fn get_block_creation_time(&self, block_index: usize) -> f64 {
+        10.0 * block_index as f64
+    }
+    fn get_block_creator(&self, block_index: usize) -> Option<usize> {
+        self.blocks[block_index].1
+    }
+    fn get_block_height(&self, block_index: usize) -> i32 {
+        match self.blocks[block_index].0.first() {
+            Some(parent) => self.get_block_height(*parent) + 1,
+            None => 0,
+        }
+    }
+    fn get_block_size(&self, _: usize) -> u64 {
+        1000
+    }
+    fn get_block_parents(&self, block_index: usize) -> &Vec<usize> {
+        &self.blocks[block_index].0
+    }
+    fn get_num_of_nodes(&self) -> usize {
+        self.node_types.len()
+    }
+    fn get_node_type(&self, node_index: usize) -> Option<NodeType> {
+        Some(self.node_types[node_index])
+    }
+}
+
+fn run<C: CSVLogger>(
+    logger: &mut C,
+    network: &TestNetwork,
+    events: &[EventLoggerInfo],
+) -> Vec<FinalRow> {
+    for event in events {
+        logger.csv_output_condition_after_event(event, network);
+    }
+    logger.csv_final_output()
+}
+
+fn metric(rows: &[FinalRow], metric: &str) -> Field {
+    rows.iter()
+        .find_map(|row| match row {
+            FinalRow::Record(record) if record[0] == metric.into() => Some(record[2].clone()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn node_type_filter_aggregates_the_arrivals_at_the_nodes_of_the_types() {
+    let network = TestNetwork {
+        node_types: vec![Miner, RelayNode, RelayNode, SPVClient],
+        blocks: vec![(vec![], None), (vec![0], Some(0))],
+    };
+    let events = [
+        IsBlockGenerationEvent(1, 0, 10.0),
+        IsReceiveEvent(1, 0, 1, DataMessage(IsBlock), 12.0),
+        IsReceiveEvent(1, 0, 3, HeadersMessage, 12.0),
+        IsReceiveEvent(1, 1, 2, DataMessage(IsBlock), 13.0),
+    ];
+    let delays = |rows: &[FinalRow]| match &rows[0] {
+        FinalRow::Record(record) => record[5..].to_vec(),
+        FinalRow::Header(_) => unreachable!(),
+    };
+
+    // the creator has the block first, and the SPV client never has it.
+    let mut logger = BlockArrivalLogger::new(vec![0.0, 1.0]);
+    let rows = run(&mut logger, &network, &events);
+    assert_eq!(delays(&rows), [Field::Float(0.0), Field::Float(3.0)]);
+
+    // without the miner, the first of the relay nodes has the block first.
+    let mut filter = NodeTypeFilter::new(BlockArrivalLogger::new(vec![0.0, 1.0]), vec![RelayNode]);
+    let rows = run(&mut filter, &network, &events);
+    assert_eq!(delays(&rows), [Field::Float(2.0), Field::Float(3.0)]);
+
+    // SPV clients never have the full block.
+    let mut filter = NodeTypeFilter::new(BlockArrivalLogger::new(vec![0.0, 1.0]), vec![SPVClient]);
+    assert!(run(&mut filter, &network, &events).is_empty());
+}
+
+#[test]
+fn node_type_filter_counts_the_stale_blocks_of_the_miners_of_the_types() {
+    // block 2 of the listening node is stale.
+    let network = TestNetwork {
+        node_types: vec![Miner, ListeningNode],
+        blocks: vec![
+            (vec![], None),
+            (vec![0], Some(0)),
+            (vec![0], Some(1)),
+            (vec![1], Some(0)),
+        ],
+    };
+    let events = [
+        IsBlockGenerationEvent(1, 0, 10.0),
+        IsBlockGenerationEvent(2, 1, 20.0),
+        IsBlockGenerationEvent(3, 0, 30.0),
+    ];
+
+    let mut filter = NodeTypeFilter::new(
+        StaleBlockLogger::new(MainChainView::Omniscient),
+        vec![Miner],
+    );
+    let rows = run(&mut filter, &network, &events);
+    assert_eq!(metric(&rows, "TotalBlocks"), Field::UInt(2));
+    assert_eq!(metric(&rows, "MainChainBlocks"), Field::UInt(2));
+    assert_eq!(metric(&rows, "StaleBlocks"), Field::UInt(0));
+    // the forks are of all the blocks.
+    assert_eq!(metric(&rows, "ForkLength"), Field::UInt(1));
+
+    let mut filter = NodeTypeFilter::new(
+        StaleBlockLogger::new(MainChainView::Omniscient),
+        vec![ListeningNode],
+    );
+    let rows = run(&mut filter, &network, &events);
+    assert_eq!(metric(&rows, "TotalBlocks"), Field::UInt(1));
+    assert_eq!(metric(&rows, "StaleBlocks"), Field::UInt(1));
+    assert_eq!(metric(&rows, "StaleRate"), Field::Float(1.0));
+}
@@ -0,0 +1,99 @@
+use crate::log::output_format::{push_json_field, push_json_string, OutputFormat};
+use crate::log::record::Field;
+use std::path::PathBuf;
+
+fn json_string(value: &str) -> String {
+    let mut json = String::new();
+    push_json_string(&mut json, value);
+    json
+}
+
+fn json_field(field: &Field) -> String {
+    let mut json = String::new();
+    push_json_field(&mut json, field);
+    json
+}
+
+/// Writes a comment, a header and two records, then a second table, and
+/// returns the content of the file.
+fn write_tables(output_format: OutputFormat, file_name: &str) -> String {
+    let path: PathBuf = std::env::temp_dir().join(format!(
+        "{}-{}-{}",
+        env!("CARGO_PKG_NAME"),
+        std::process::id(),
+        file_name
+    ));
+    let mut writer = output_format.create_writer(&path).unwrap();
+    writer.write_comment(&["# comment".to_string()]).unwrap();
+    writer
+        .write_header(&["block".to_string(), "creator".to_string()])
+        .unwrap();
+    writer
+        .write_record(&vec![Field::UInt(1), Field::Str("a\"b".to_string())])
+        .unwrap();
+    writer
+        .write_record(&vec![Field::UInt(2), Field::Null])
+        .unwrap();
+    writer.write_header(&["total".to_string()]).unwrap();
+    writer.write_record(&vec![Field::Float(0.5)]).unwrap();
+    writer.finish().unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    content
+}
+
+#[test]
+fn push_json_string_escapes_quotes_backslashes_and_control_characters() {
+    assert_eq!(json_string("block"), r#""block""#);
+    assert_eq!(json_string(r#"a"b\c"#), r#""a\"b\\c""#);
+    assert_eq!(json_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+    assert_eq!(json_string("\u{1}\u{1f}"), r#""\u0001\u001f""#);
+    assert_eq!(json_string("é"), "\"é\"");
+}
+
+#[test]
+fn push_json_field_keeps_the_types_of_the_fields() {
+    assert_eq!(json_field(&Field::Null), "null");
+    assert_eq!(json_field(&Field::Bool(true)), "true");
+    assert_eq!(json_field(&Field::Int(-3)), "-3");
+    assert_eq!(json_field(&Field::UInt(3)), "3");
+    assert_eq!(json_field(&Field::Float(1.5)), "1.5");
+    assert_eq!(json_field(&Field::Str("3".to_string())), r#""3""#);
+    // JSON has no infinite or NaN numbers.
+    assert_eq!(json_field(&Field::Float(f64::INFINITY)), "null");
+    assert_eq!(json_field(&Field::Float(f64::NAN)), "null");
+}
+
+#[test]
+fn json_lines_writes_an_object_per_record() {
+    assert_eq!(
+        write_tables(OutputFormat::JsonLines, "tables.jsonl"),
+        concat!(
+            r#"{"block":1,"creator":"a\"b"}"#,
+            "\n",
+            r#"{"block":2,"creator":null}"#,
+            "\n",
+            r#"{"total":0.5}"#,
+            "\n"
+        )
+    );
+}
+
+#[test]
+fn columnar_json_writes_an_object_per_table() {
+    assert_eq!(
+        write_tables(OutputFormat::ColumnarJson, "tables.json"),
+        concat!(
+            r#"[{"block":[1,2],"creator":["a\"b",null]},{"total":[0.5]}]"#,
+            "\n"
+        )
+    );
+}
+
+#[test]
+fn csv_writes_the_comment_and_the_headers_as_rows() {
+    assert_eq!(
+        write_tables(OutputFormat::Csv, "tables.csv"),
+        "# comment\nblock,creator\n1,\"a\"\"b\"\n2,None\ntotal\n0.5\n"
+    );
+}
@@ -0,0 +1,183 @@
+use crate::consensus::algorithm::pbft_consensus::PBFTConsensus;
+use crate::consensus::config::pbft_consensus_config::PBFTConsensusConfig;
+use crate::ledger_data::vote::BFTVoteType::{Commit, Prepare, Proposal, ViewChange};
+use crate::tests::bft_test_replicas::TestReplicas;
+
+fn start_replicas(crashed: &[usize]) -> TestReplicas<PBFTConsensus> {
+    TestReplicas::start(PBFTConsensusConfig::new(4, 1.0, 0), crashed)
+}
+
+fn num_of_blocks_of_height(replicas: &TestReplicas<PBFTConsensus>, height: i32) -> usize {
+    replicas
+        .blocks
+        .iter()
+        .filter(|block| block.height == height)
+        .count()
+}
+
+#[test]
+fn pbft_commits_the_proposals_of_the_leader() {
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|_, vote| vote.height <= 2);
+
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1), (2, 2)].into());
+        assert_eq!(replicas.replicas[node].height, 3);
+        assert_eq!(replicas.replicas[node].view, 0);
+    }
+}
+
+#[test]
+fn pbft_changes_the_view_of_a_crashed_leader() {
+    let mut replicas = start_replicas(&[0]);
+    replicas.deliver(|_, vote| vote.height <= 1);
+    assert_eq!(replicas.num_of_created_blocks(), 0);
+
+    replicas.expire_timeouts();
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    for node in 1..4 {
+        assert_eq!(replicas.replicas[node].view, 1);
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+    }
+    assert_eq!(replicas.blocks[1].creator, Some(1));
+}
+
+#[test]
+fn pbft_proposes_the_prepared_block_again_after_a_view_change() {
+    // the block of view 0 is prepared by all replicas, but the commits are
+    // lost.
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|_, vote| vote.height <= 1 && vote.vote_type != Commit);
+    replicas.drop_held_messages();
+    assert_eq!(replicas.num_of_created_blocks(), 1);
+    for node in 0..4 {
+        assert!(replicas.committed_blocks(node).is_empty());
+    }
+
+    replicas.expire_timeouts();
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    // the leader of view 1 proposes the prepared block instead of a new one.
+    assert_eq!(num_of_blocks_of_height(&replicas, 1), 1);
+    for node in 0..4 {
+        assert_eq!(replicas.replicas[node].view, 1);
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+    }
+}
+
+#[test]
+fn pbft_proposes_again_a_block_prepared_by_some_replicas() {
+    // only replicas 0 and 1 receive the prepares, and they commit the block
+    // of view 0, but their commits are lost.
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|node, vote| {
+        vote.height <= 1 && vote.vote_type != Commit && (vote.vote_type != Prepare || node < 2)
+    });
+    replicas.drop_held_messages();
+
+    replicas.expire_timeouts();
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    assert_eq!(num_of_blocks_of_height(&replicas, 1), 1);
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+    }
+}
+
+#[test]
+fn pbft_replica_does_not_prepare_another_block_than_its_prepared_one() {
+    // only replica 0 prepares the block of view 0, and the leader of view 1
+    // does not receive its view change vote, so it proposes a new block.
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|node, vote| {
+        vote.height <= 1 && vote.vote_type != Commit && (vote.vote_type != Prepare || node == 0)
+    });
+    replicas.drop_held_messages();
+
+    replicas.expire_timeouts();
+    replicas.deliver(|node, vote| {
+        vote.height <= 1 && !(node == 1 && vote.vote_type == ViewChange && vote.voter == 0)
+    });
+
+    assert_eq!(num_of_blocks_of_height(&replicas, 1), 2);
+    assert_eq!(replicas.blocks[2].creator, Some(1));
+    let prepared_new_block = |voter: usize| {
+        replicas.sent_votes.iter().any(|vote| {
+            vote.vote_type == Prepare && vote.voter == voter && vote.block_index == Some(2)
+        })
+    };
+    assert!(!prepared_new_block(0));
+    assert!((1..4).all(prepared_new_block));
+    // the block of view 0 was not committed, so the new block can be.
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 2)].into());
+    }
+}
+
+#[test]
+fn pbft_commits_the_next_block_committed_before_the_current_one() {
+    // replica 3 receives the commits of height 2 before the ones of height 1.
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|node, vote| {
+        vote.height <= 2 && !(node == 3 && vote.height == 1 && vote.vote_type == Commit)
+    });
+    assert!(replicas.committed_blocks(3).is_empty());
+    assert_eq!(replicas.committed_blocks(0), [(1, 1), (2, 2)].into());
+
+    // it commits height 2 right after height 1, without its own votes.
+    replicas.release_held_messages();
+    replicas.deliver(|node, vote| vote.height <= 2 && !(node == 3 && vote.voter == 3));
+
+    assert_eq!(replicas.committed_blocks(3), [(1, 1), (2, 2)].into());
+}
+
+#[test]
+fn pbft_accepts_the_proposal_received_before_the_view_change() {
+    // the leader of view 1 proposes before replica 2 enters the view.
+    let mut replicas = start_replicas(&[0]);
+    replicas.expire_timeouts();
+    replicas.deliver(|node, vote| node != 2 || vote.vote_type == Proposal);
+    assert_eq!(replicas.replicas[2].view, 0);
+
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    assert_eq!(replicas.replicas[2].view, 1);
+    assert_eq!(replicas.committed_blocks(2), [(1, 1)].into());
+}
+
+#[test]
+fn pbft_correct_replicas_commit_the_same_blocks_with_f_byzantine_replicas() {
+    for (num_of_replicas, byzantine) in [(4, vec![0]), (7, vec![0, 1]), (7, vec![1, 4])] {
+        let mut replicas = TestReplicas::<PBFTConsensus>::start_with_byzantine(
+            PBFTConsensusConfig::new(num_of_replicas, 1.0, 0),
+            &[],
+            &byzantine,
+        );
+        for _ in 0..5 {
+            replicas.deliver(|_, vote| vote.height <= 3);
+            replicas.expire_timeouts();
+        }
+
+        // the Byzantine leader of view 0 proposed two blocks of height 1.
+        assert_eq!(
+            num_of_blocks_of_height(&replicas, 1) >= 2,
+            byzantine.contains(&0)
+        );
+        assert!(replicas.correct_replicas_agree());
+        let correct_replicas = (0..num_of_replicas).filter(|node| !byzantine.contains(node));
+        for node in correct_replicas {
+            assert!(!replicas.committed_blocks(node).is_empty());
+        }
+    }
+
+    // with more than f, the quorums of both blocks of height 1 are reached.
+    let mut replicas = TestReplicas::<PBFTConsensus>::start_with_byzantine(
+        PBFTConsensusConfig::new(4, 1.0, 0),
+        &[],
+        &[0, 2],
+    );
+    replicas.deliver(|_, vote| vote.height <= 1);
+    assert!(!replicas.correct_replicas_agree());
+}
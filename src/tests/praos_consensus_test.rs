@@ -0,0 +1,133 @@
+use crate::consensus::algorithm::praos_consensus::PraosConsensus;
+use crate::consensus::algorithm::DAGBasedConsensus;
+use crate::consensus::blockchain::local_block_tree::LocalBlockTree;
+use crate::consensus::config::praos_consensus_config::PraosConsensusConfig;
+use crate::ledger_data::praos_block::PraosBlock;
+use crate::simulator::Simulator;
+use std::collections::HashSet;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+}
+
+fn config_with_stakes(stakes: Vec<f64>) -> PraosConsensusConfig {
+    let mut config = PraosConsensusConfig::new(1.0, 0.05, 2, 0);
+    config.set_stakes(stakes);
+    config
+}
+
+/// A node with a settlement depth of 2, receiving hand-built blocks.
+struct TestNode {
+    consensus: PraosConsensus,
+    blocks: Vec<PraosBlock>,
+    config: PraosConsensusConfig,
+    local_block_tree: LocalBlockTree,
+    simulator: Simulator,
+}
+
+impl TestNode {
+    fn new() -> Self {
+        let config = config_with_stakes(vec![1.0]);
+        Self {
+            consensus: PraosConsensus::new(&config),
+            blocks: vec![PraosBlock::generate_genesis_block()],
+            config,
+            local_block_tree: LocalBlockTree::new(),
+            simulator: Simulator::new(),
+        }
+    }
+
+    /// Receives a chain of `length` new blocks on `parent` and returns the
+    /// last one.
+    fn receive_chain(&mut self, parent: usize, length: usize) -> usize {
+        let mut parent = parent;
+        for _ in 0..length {
+            let block_index = self.blocks.len();
+            self.blocks.push(PraosBlock::new_with_parent(
+                block_index as f64,
+                Some(0),
+                self.blocks[parent].height + 1,
+                parent,
+                1000,
+                block_index as u64,
+            ));
+            self.local_block_tree.add(block_index, &self.blocks);
+            self.consensus.new_incoming_block(
+                block_index,
+                &self.blocks,
+                &self.config,
+                &self.local_block_tree,
+                &mut self.simulator,
+            );
+            parent = block_index;
+        }
+        parent
+    }
+
+    fn head(&self) -> usize {
+        self.consensus.current_main_chain_head_index
+    }
+}
+
+#[test]
+fn praos_slot_leader_probability_follows_the_relative_stake() {
+    let config = config_with_stakes(vec![3.0, 1.0, 0.0]);
+    assert_close(config.slot_leader_probability(0), 1.0 - 0.95_f64.powf(0.75));
+    assert_close(config.slot_leader_probability(1), 1.0 - 0.95_f64.powf(0.25));
+    assert_eq!(config.slot_leader_probability(2), 0.0);
+    // a single stakeholder wins the slots with the active slot coefficient.
+    assert_close(
+        config_with_stakes(vec![5.0]).slot_leader_probability(0),
+        0.05,
+    );
+}
+
+#[test]
+fn praos_slot_has_a_leader_with_the_active_slot_coefficient_however_the_stake_is_split() {
+    for stakes in [vec![1.0, 1.0], vec![3.0, 1.0], vec![1.0, 2.0, 3.0, 4.0]] {
+        let config = config_with_stakes(stakes);
+        let no_leader: f64 = (0..config.stakes.len())
+            .map(|node| 1.0 - config.slot_leader_probability(node))
+            .product();
+        assert_close(1.0 - no_leader, config.active_slot_coefficient);
+    }
+}
+
+#[test]
+fn praos_settles_the_blocks_settlement_depth_below_the_head() {
+    let mut node = TestNode::new();
+    node.receive_chain(0, 2);
+    assert!(node.consensus.confirmed_blocks.is_empty());
+
+    let head = node.receive_chain(2, 2);
+
+    assert_eq!(node.head(), head);
+    assert_eq!(node.consensus.longest_chain_len, 4);
+    assert_eq!(node.consensus.confirmed_blocks, HashSet::from([0, 1, 2]));
+}
+
+#[test]
+fn praos_switches_to_a_longer_chain_forking_within_the_settlement_depth() {
+    let mut node = TestNode::new();
+    node.receive_chain(0, 4);
+
+    // a chain of the same length is not preferred.
+    let fork_head = node.receive_chain(2, 2);
+    assert_eq!(node.head(), 4);
+
+    node.receive_chain(fork_head, 1);
+    assert_eq!(node.head(), fork_head + 1);
+    assert_eq!(node.consensus.longest_chain_len, 5);
+}
+
+#[test]
+fn praos_does_not_roll_back_more_than_the_settlement_depth() {
+    let mut node = TestNode::new();
+    node.receive_chain(0, 4);
+
+    // the fork from block 1 would roll back 3 blocks.
+    node.receive_chain(1, 10);
+
+    assert_eq!(node.head(), 4);
+    assert_eq!(node.consensus.longest_chain_len, 4);
+}
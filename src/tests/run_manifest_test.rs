@@ -0,0 +1,105 @@
+use crate::network::stats::csv_network_stats::{
+    CSVNetworkStats, LATENCY_FILE_NAME, PARAMETERS_FILE_NAME, REGIONS_FILE_NAME,
+};
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::{
+    BitcoinProfile, BITCOIN_2022,
+};
+use crate::scenario::bitcoin_global_network_scenario::BitcoinGlobalNetworkScenario;
+use crate::scenario::run_manifest::RunManifest;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// Returns the keys of the JSON object lines at the given indentation.
+fn keys(json: &str, indentation: usize) -> Vec<String> {
+    json.lines()
+        .filter_map(|line| line.strip_prefix(&" ".repeat(indentation)))
+        .filter_map(|line| line.strip_prefix('"'))
+        .map(|line| line.split('"').next().unwrap().to_string())
+        .collect()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "{}-{}-{}",
+        env!("CARGO_PKG_NAME"),
+        std::process::id(),
+        name
+    ))
+}
+
+#[test]
+fn run_manifest_to_json_writes_every_key() {
+    let mut manifest = RunManifest::new("Scenario", "a \"name\"", "Network", 7);
+    manifest.add_parameter("StopTime", 3600.0);
+    manifest.add_parameter("MiningPools", None::<String>);
+    manifest.add_summary_metric("TotalCreatedBlocks", 6usize);
+
+    let json = manifest.to_json();
+
+    assert!(json.starts_with("{\n") && json.ends_with("\n}\n"));
+    assert_eq!(
+        keys(&json, 2),
+        [
+            "scenario",
+            "name",
+            "network_type",
+            "seed",
+            "crate_version",
+            "setup_elapsed_seconds",
+            "propagation_elapsed_seconds",
+            "inserted_events",
+            "final_simulation_time",
+            "parameters",
+            "summary",
+        ]
+    );
+    assert_eq!(
+        keys(&json, 4),
+        ["StopTime", "MiningPools", "TotalCreatedBlocks"]
+    );
+    assert!(json.contains("\"name\": \"a \\\"name\\\"\","));
+    assert!(json.contains("\"seed\": 7,"));
+    assert!(json.contains("\"MiningPools\": null"));
+    assert!(json.contains("\"summary\": {\n    \"TotalCreatedBlocks\": 6\n  }"));
+}
+
+#[test]
+fn run_manifest_records_the_path_of_custom_network_stats() {
+    let dir = temp_path("network-stats");
+    std::fs::create_dir_all(&dir).unwrap();
+    for (file_name, content) in [
+        (
+            REGIONS_FILE_NAME,
+            "Region,DownloadBandwidth,UploadBandwidth,NodeShare,MinerShare\n\
+             Europe,50,20,3,1\nAsia,40,10,1,1\n",
+        ),
+        (
+            LATENCY_FILE_NAME,
+            "Region,Europe,Asia\nEurope,10,100\nAsia,110,20\n",
+        ),
+        (
+            PARAMETERS_FILE_NAME,
+            "Parameter,Value\nDownloadBandwidthShape,2\nUploadBandwidthShape,3\n\
+             LatencyParetoShape,5\n",
+        ),
+    ] {
+        std::fs::write(dir.join(file_name), content).unwrap();
+    }
+    let manifest_path = temp_path("manifest.json");
+
+    let mut scenario = BitcoinGlobalNetworkScenario::new(600.0, 6, "custom", 1, 60.0);
+    scenario.set_profile(BitcoinProfile {
+        num_of_nodes: 100,
+        ..BITCOIN_2022
+    });
+    scenario.set_network_stats(Rc::new(CSVNetworkStats::from_dir(&dir).unwrap()));
+    scenario.set_run_manifest_path(&manifest_path);
+    scenario.run().unwrap();
+
+    let json = std::fs::read_to_string(&manifest_path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    std::fs::remove_file(&manifest_path).unwrap();
+    let mut expected = String::new();
+    crate::log::output_format::push_json_string(&mut expected, &dir.display().to_string());
+    assert!(json.contains(&format!("\"NetworkStats\": {expected}")));
+}
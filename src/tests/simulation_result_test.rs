@@ -0,0 +1,244 @@
+use crate::log::block_arrival_logger::{BlockArrivalLogger, DEFAULT_PROPAGATION_SHARES};
+use crate::log::block_propagation_delay_logger::BlockPropagationDelayLogger;
+use crate::log::blockchain_reorg_logger::BlockchainReorgLogger;
+use crate::log::in_memory_sink::{InMemoryEvents, RecordTable};
+use crate::log::record::Field;
+use crate::log::EventLogger;
+use crate::network::node::node_type::NodeTypeConfig;
+use crate::network::stats::eighty_six_countries::bitcoin_stats::bitcoin_profile::{
+    BitcoinProfile, BITCOIN_2022,
+};
+use crate::scenario::bitcoin_global_network_scenario::BitcoinGlobalNetworkScenario;
+use crate::scenario::simulation_result::{BlockPropagation, ReorgEvent};
+
+fn table(header: &[&str], records: Vec<Vec<Field>>) -> RecordTable {
+    RecordTable {
+        header: header.iter().map(|column| column.to_string()).collect(),
+        records,
+    }
+}
+
+#[test]
+fn bitcoin_scenario_returns_the_propagations_and_reorgs_of_its_loggers() {
+    let mut scenario = BitcoinGlobalNetworkScenario::new(600.0, 6, "small", 1, 7200.0);
+    scenario.set_profile(BitcoinProfile {
+        num_of_nodes: 100,
+        ..BITCOIN_2022
+    });
+    let (block_arrival_logger, block_arrivals) =
+        EventLogger::in_memory(BlockArrivalLogger::default());
+    let (reorg_logger, reorgs) = EventLogger::in_memory(BlockchainReorgLogger::new());
+    scenario.add_new_logger(Box::new(block_arrival_logger));
+    scenario.add_new_logger(Box::new(reorg_logger));
+
+    let result = scenario.run().unwrap();
+
+    assert_eq!(result.name, "small");
+    assert_eq!(result.seed, 1);
+    assert!(result.final_simulation_time >= 7200.0);
+    assert!(result.num_of_created_blocks > 0);
+    assert!(!result.block_propagations.is_empty());
+    assert!(result.block_propagations.len() <= result.num_of_created_blocks);
+    for propagation in &result.block_propagations {
+        let shares: Vec<f64> = propagation
+            .propagation_delays
+            .iter()
+            .map(|(share, _)| *share)
+            .collect();
+        assert_eq!(shares, DEFAULT_PROPAGATION_SHARES);
+        let delays: Vec<f64> = propagation
+            .propagation_delays
+            .iter()
+            .filter_map(|(_, delay)| *delay)
+            .collect();
+        assert!(delays.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+    assert!(result.reorgs.iter().all(|reorg| reorg.reorg_length > 0));
+
+    // the typed events are the ones written by the same loggers.
+    assert_eq!(
+        BlockPropagation::from_table(&block_arrivals.tables()[0]),
+        Ok(result.block_propagations)
+    );
+    assert_eq!(
+        ReorgEvent::from_table(&reorgs.tables()[0]),
+        Ok(result.reorgs)
+    );
+}
+
+#[test]
+fn block_propagations_reach_every_node_receiving_full_blocks() {
+    let mut scenario = BitcoinGlobalNetworkScenario::new(600.0, 6, "small", 1, 7200.0);
+    scenario.set_profile(BitcoinProfile {
+        num_of_nodes: 100,
+        ..BITCOIN_2022
+    });
+    // the SPV clients only receive the headers of the blocks.
+    scenario.set_node_types(NodeTypeConfig::new(0, 10));
+    let propagations = InMemoryEvents::default();
+    scenario.add_new_logger(Box::new(EventLogger::without_records(
+        BlockArrivalLogger::new(vec![0.0, 1.0]).with_propagation_sink(propagations.clone()),
+    )));
+    let (delay_logger, delays) = EventLogger::in_memory(BlockPropagationDelayLogger::new(1.0));
+    scenario.add_new_logger(Box::new(delay_logger));
+
+    scenario.run().unwrap();
+
+    let propagations = propagations.take();
+    assert!(!propagations.is_empty());
+    // the first node to have a block is its creator, at its creation.
+    for propagation in &propagations {
+        assert_eq!(propagation.propagation_delays[0], (0.0, Some(0.0)));
+    }
+    let num_of_fully_propagated = propagations
+        .iter()
+        .filter(|propagation| propagation.propagation_delays[1].1.is_some())
+        .count();
+    assert!(2 * num_of_fully_propagated > propagations.len());
+    assert_eq!(delays.tables()[0].records.len(), num_of_fully_propagated);
+}
+
+#[test]
+fn block_propagations_are_read_by_column_name() {
+    let table = table(
+        &[
+            "CreationTime",
+            "BlockIndex",
+            "PropagationDelay0.5",
+            "BlockSize",
+            "BlockCreator",
+            "BlockHeight",
+            "PropagationDelay1",
+        ],
+        vec![vec![
+            Field::Float(10.0),
+            Field::UInt(3),
+            Field::Float(1.5),
+            Field::UInt(1000),
+            Field::Null,
+            Field::Int(2),
+            Field::Null,
+        ]],
+    );
+
+    assert_eq!(
+        BlockPropagation::from_table(&table),
+        Ok(vec![BlockPropagation {
+            block_index: 3,
+            height: 2,
+            creator: None,
+            size: 1000,
+            creation_time: 10.0,
+            propagation_delays: vec![(0.5, Some(1.5)), (1.0, None)],
+        }])
+    );
+}
+
+#[test]
+fn block_propagations_are_not_read_from_invalid_tables() {
+    let header = [
+        "BlockIndex",
+        "BlockHeight",
+        "BlockCreator",
+        "BlockSize",
+        "CreationTime",
+        "PropagationDelay0.5",
+    ];
+    let record = vec![
+        Field::UInt(3),
+        Field::Int(2),
+        Field::UInt(1),
+        Field::UInt(1000),
+        Field::Float(10.0),
+        Field::Float(1.5),
+    ];
+
+    assert_eq!(
+        BlockPropagation::from_table(&table(&header[1..], vec![record[1..].to_vec()])),
+        Err("Missing column: BlockIndex".to_string())
+    );
+    let mut invalid_header = header;
+    invalid_header[5] = "PropagationDelayAll";
+    assert_eq!(
+        BlockPropagation::from_table(&table(&invalid_header, vec![record.clone()])),
+        Err("Invalid share in column: PropagationDelayAll".to_string())
+    );
+    let mut invalid_record = record.clone();
+    invalid_record[3] = Field::Str("large".to_string());
+    assert_eq!(
+        BlockPropagation::from_table(&table(&header, vec![record.clone(), invalid_record])),
+        Err("Invalid BlockSize in row 1: large".to_string())
+    );
+    // only the creator and the delays may be missing.
+    let mut missing_height = record.clone();
+    missing_height[1] = Field::Null;
+    assert_eq!(
+        BlockPropagation::from_table(&table(&header, vec![missing_height])),
+        Err("Invalid BlockHeight in row 0: None".to_string())
+    );
+    assert_eq!(
+        BlockPropagation::from_table(&table(&header, vec![record[..5].to_vec()])),
+        Err("Missing PropagationDelay0.5 in row 0".to_string())
+    );
+}
+
+#[test]
+fn reorgs_are_read_by_column_name() {
+    let reorg = ReorgEvent {
+        time: 20.0,
+        node_index: 4,
+        block_height: 7,
+        block_creation_time: 19.0,
+        block_creator: Some(1),
+        reorg_length: 2,
+    };
+    let header = [
+        "Time",
+        "NodeIndex",
+        "BlockHeight",
+        "BlockCreationTime",
+        "BlockCreator",
+        "ReorgLength",
+    ];
+    assert_eq!(
+        ReorgEvent::from_table(&table(&header, vec![reorg.to_record()])),
+        Ok(vec![reorg.clone()])
+    );
+
+    let mut reversed_record = reorg.to_record();
+    reversed_record.reverse();
+    let mut reversed_header = header;
+    reversed_header.reverse();
+    assert_eq!(
+        ReorgEvent::from_table(&table(&reversed_header, vec![reversed_record])),
+        Ok(vec![reorg])
+    );
+}
+
+#[test]
+fn reorgs_are_not_read_from_invalid_tables() {
+    let header = [
+        "Time",
+        "NodeIndex",
+        "BlockHeight",
+        "BlockCreationTime",
+        "BlockCreator",
+    ];
+    assert_eq!(
+        ReorgEvent::from_table(&table(&header, Vec::new())),
+        Err("Missing column: ReorgLength".to_string())
+    );
+    let header = [&header[..], &["ReorgLength"]].concat();
+    let record = vec![
+        Field::Float(20.0),
+        Field::Int(-1),
+        Field::Int(7),
+        Field::Float(19.0),
+        Field::Null,
+        Field::Int(2),
+    ];
+    assert_eq!(
+        ReorgEvent::from_table(&table(&header, vec![record])),
+        Err("Invalid NodeIndex in row 0: -1".to_string())
+    );
+}
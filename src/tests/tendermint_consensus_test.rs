@@ -0,0 +1,91 @@
+use crate::consensus::algorithm::tendermint_consensus::TendermintConsensus;
+use crate::consensus::config::tendermint_consensus_config::TendermintConsensusConfig;
+use crate::ledger_data::vote::BFTVote;
+use crate::ledger_data::vote::BFTVoteType::{Prevote, Proposal};
+use crate::tests::bft_test_replicas::TestReplicas;
+
+fn start_replicas(crashed: &[usize]) -> TestReplicas<TendermintConsensus> {
+    TestReplicas::start(
+        TendermintConsensusConfig::new(4, 1.0, 1.0, 1.0, 0.5, 0),
+        crashed,
+    )
+}
+
+#[test]
+fn tendermint_commits_the_proposals_of_the_proposers() {
+    let mut replicas = start_replicas(&[]);
+    replicas.deliver(|_, vote| vote.height <= 2);
+
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1), (2, 2)].into());
+        assert_eq!(replicas.replicas[node].height, 3);
+    }
+    // the proposer rotates with the height.
+    assert_eq!(replicas.blocks[1].creator, Some(1));
+    assert_eq!(replicas.blocks[2].creator, Some(2));
+}
+
+#[test]
+fn tendermint_starts_a_new_round_without_proposal() {
+    // the proposer of height 1, round 0 is replica 1.
+    let mut replicas = start_replicas(&[1]);
+    for _ in 0..3 {
+        replicas.deliver(|_, vote| vote.height <= 1);
+        replicas.expire_timeouts();
+    }
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    for node in [0, 2, 3] {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+    }
+    // the block is proposed by the proposer of round 1.
+    assert_eq!(replicas.blocks[1].creator, Some(2));
+    assert_eq!(replicas.blocks[1].view, 1);
+}
+
+#[test]
+fn tendermint_proposes_the_valid_value_again_with_its_valid_round() {
+    // replica 3 does not receive the proposal of round 0, and the prevote of
+    // replica 2 reaches neither replica 0 nor replica 3, so only replicas 1
+    // and 2 see the quorum of prevotes and precommit the block.
+    let is_held = |node: usize, vote: &BFTVote| {
+        vote.view == 0
+            && ((vote.vote_type == Proposal && node == 3)
+                || (vote.vote_type == Prevote && vote.voter == 2 && (node == 0 || node == 3)))
+    };
+    let mut replicas = start_replicas(&[]);
+    for _ in 0..3 {
+        replicas.deliver(|node, vote| vote.height <= 1 && !is_held(node, vote));
+        replicas.expire_timeouts();
+    }
+    replicas.deliver(|node, vote| vote.height <= 1 && !is_held(node, vote));
+
+    // the proposer of round 1 proposes the block of round 0 again.
+    let proposal = replicas
+        .sent_votes
+        .iter()
+        .find(|vote| vote.vote_type == Proposal && vote.view == 1)
+        .copied()
+        .unwrap();
+    assert_eq!(proposal.voter, 2);
+    assert_eq!(proposal.block_index, Some(1));
+    assert_eq!(proposal.valid_view, Some(0));
+    // replicas 0 and 3 wait for the quorum of prevotes of round 0.
+    let prevoted_in_round_1 = |replicas: &TestReplicas<TendermintConsensus>, voter: usize| {
+        replicas
+            .sent_votes
+            .iter()
+            .any(|vote| vote.vote_type == Prevote && vote.view == 1 && vote.voter == voter)
+    };
+    assert!(!prevoted_in_round_1(&replicas, 0));
+    assert!(!prevoted_in_round_1(&replicas, 3));
+
+    replicas.release_held_messages();
+    replicas.deliver(|_, vote| vote.height <= 1);
+
+    assert!(prevoted_in_round_1(&replicas, 0));
+    assert_eq!(replicas.num_of_created_blocks(), 2);
+    for node in 0..4 {
+        assert_eq!(replicas.committed_blocks(node), [(1, 1)].into());
+    }
+}